@@ -0,0 +1,190 @@
+/// Compression diagnostics.
+///
+/// `analyze::<E>` splits the input into the blocks `E::encode` writes and reports for each block the bit widths
+/// chosen by the library (`_p4bits32` etc. on the integers the encoding stores, see `block`), the number of
+/// exceptions, how they are patched and the size of the encoded block. `analyze_all` runs it for the four p4
+/// encodings of a type, so that the transform best suited to the data can be picked.
+///
+/// The integers of a block that don't fit into `b` bits are exceptions. Their high bits are stored either
+/// bitpacked with `bx` bits, located by a bitmap, or as variable bytes; blocks of equal integers store just one.
+use crate::block::*;
+use crate::codec::*;
+use crate::generic::*;
+use crate::sample::*;
+use num_traits::{PrimInt, WrappingSub};
+use std::fmt;
+use std::mem::size_of;
+use rand::{
+    prelude::Distribution,
+    distributions::Standard,
+};
+
+/// How the exceptions of a block are stored
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Patching {
+    /// No exceptions, all integers fit into `b` bits
+    None,
+    /// Exception bitmap and exceptions bitpacked with `bx` bits (header `0x80`)
+    Bitmap,
+    /// Exceptions stored as variable bytes (header `0x40`)
+    VByte,
+    /// All integers are equal (header `0xc0`)
+    Constant,
+}
+
+/// Statistics of a single block
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockReport {
+    /// Index of the first integer of the block
+    pub offset: usize,
+    /// Number of integers in the block
+    pub len: usize,
+    /// Bit width of the integers
+    pub b: u32,
+    /// Bit width of the exceptions, see `BlockBits`
+    pub bx: u32,
+    /// Number of integers that don't fit into `b` bits
+    pub exceptions: usize,
+    pub patching: Patching,
+    /// Size of the encoded block in bytes, with header
+    pub size: usize,
+}
+
+/// Statistics of an input encoded with one encoding
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodingReport {
+    /// Name of the encoding, e.g. `IncreasingEncoding`
+    pub encoding: &'static str,
+    /// Number of integers
+    pub n: usize,
+    /// Size of the encoded input in bytes, as returned by `Encoding::encode`
+    pub size: usize,
+    pub blocks: Vec<BlockReport>,
+}
+
+impl EncodingReport {
+    /// Total number of exceptions
+    pub fn exceptions(&self) -> usize {
+        self.blocks.iter().map(|block| block.exceptions).sum()
+    }
+
+    /// Number of blocks patched with `patching`
+    pub fn count(&self, patching: Patching) -> usize {
+        self.blocks.iter().filter(|block| block.patching == patching).count()
+    }
+
+    /// Average number of bits per integer
+    pub fn bits_per_integer(&self) -> f64 {
+        if self.n == 0 { 0.0 } else { 8.0 * self.size as f64 / self.n as f64 }
+    }
+}
+
+impl fmt::Display for EncodingReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:<26} {:>10} bytes {:>6.2} bits/int {:>8} exceptions, blocks: {} plain, {} bitmap, {} vbyte, {} constant",
+            self.encoding, self.size, self.bits_per_integer(), self.exceptions(),
+            self.count(Patching::None), self.count(Patching::Bitmap), self.count(Patching::VByte), self.count(Patching::Constant))
+    }
+}
+
+/// Name of the encoding without module path and type parameters
+fn encoding_name<E>() -> &'static str {
+    let name = std::any::type_name::<E>();
+    let name = name.split('<').next().unwrap();
+    name.rsplit("::").next().unwrap()
+}
+
+/// Analyze how `E` encodes `input`
+pub fn analyze<E: BlockEncoding>(input: &[E::T]) -> EncodingReport
+    where E::T: Codec<E::W> + Copy + Default + PrimInt
+{
+    let mut buf = vec![0u8; E::enc_buf_size(input.len())];
+    let size = E::encode(input, &mut buf);
+    let mut buf = vec![0u8; E::max_compressed_size(E::W::BLOCK_LEN)];
+    let bits = 8 * size_of::<E::T>() as u32;
+    // the delta encodings store the first integer in front of the first block
+    let (mut start, mut offset) = (E::T::default(), 0);
+    if E::HAS_START && !input.is_empty() {
+        (start, offset) = (input[0], 1);
+    }
+    let mut blocks = Vec::with_capacity(input.len().div_ceil(E::W::BLOCK_LEN));
+    for chunk in input[offset..].chunks(E::W::BLOCK_LEN) {
+        let encoder = BlockEncoder::<E>::new().with_start(start);
+        let BlockBits { b, bx } = encoder.best_bits(chunk).unwrap();
+        let patching = match bx {
+            0 => Patching::None,
+            bx if bx <= bits => Patching::Bitmap,
+            bx if bx == bits + 1 => Patching::VByte,
+            _ => Patching::Constant,
+        };
+        let mut prev = start;
+        let exceptions = match patching {
+            Patching::Constant => 0,
+            _ => chunk.iter().filter(|&&x| {
+                let d = E::delta(x, prev);
+                prev = x;
+                E::stored_bits(d).checked_shr(b).unwrap_or(0) != 0
+            }).count(),
+        };
+        let size = encoder.encode(chunk, &mut buf).unwrap();
+        blocks.push(BlockReport { offset, len: chunk.len(), b, bx, exceptions, patching, size });
+        start = chunk[chunk.len() - 1];
+        offset += chunk.len();
+    }
+    EncodingReport { encoding: encoding_name::<E>(), n: input.len(), size, blocks }
+}
+
+/// Analyze `input` with `StandardEncoding`, `IncreasingEncoding`, `StrictlyIncreasingEncoding` and `ZigZagEncoding`
+/// # Returns
+/// The reports in this order; the sorted encodings are skipped if `input` isn't (strictly) increasing
+pub fn analyze_all<WT: Width, T>(input: &[T]) -> Vec<EncodingReport>
+    where Standard: Distribution<T>, T: DeltaCodec<WT> + Default + Arithmetic + PrimInt + WrappingSub
+{
+    let mut reports = vec![analyze::<StandardEncoding<WT, T>>(input)];
+    if input.windows(2).all(|w| w[0] <= w[1]) {
+        reports.push(analyze::<IncreasingEncoding<WT, T>>(input));
+    }
+    if input.windows(2).all(|w| w[0] < w[1]) {
+        reports.push(analyze::<StrictlyIncreasingEncoding<WT, T>>(input));
+    }
+    reports.push(analyze::<ZigZagEncoding<WT, T>>(input));
+    reports
+}
+
+#[test]
+fn test_analyze() {
+    // small integers with a few outliers
+    let input: Vec<u32> = (0..1000).map(|i| if i % 100 == 7 { 1 << 20 } else { i % 16 }).collect();
+    let report = analyze::<StandardEncoding<W, u32>>(&input);
+    assert_eq!(report.encoding, "StandardEncoding");
+    assert_eq!(report.n, 1000);
+    assert_eq!(report.blocks.len(), 8);
+    assert_eq!(report.blocks[7], BlockReport { offset: 896, len: 104, ..report.blocks[7] });
+    assert_eq!(report.exceptions(), 10);
+    assert!(report.blocks.iter().all(|block| block.b == 4 && block.patching != Patching::None));
+    assert_eq!(report.blocks.iter().map(|block| block.size).sum::<usize>(), report.size);
+    let input = vec![5u16; 300];
+    let report = analyze::<StandardEncoding<W, u16>>(&input);
+    assert_eq!(report.exceptions(), 0);
+    assert_eq!(report.count(Patching::Bitmap), 0);
+}
+
+#[test]
+fn test_analyze_all() {
+    let input: Vec<u32> = (0..10_000).map(|i| 1_000_000 + 3 * i).collect();
+    let reports = analyze_all::<W, u32>(&input);
+    let names: Vec<&str> = reports.iter().map(|report| report.encoding).collect();
+    assert_eq!(names, ["StandardEncoding", "IncreasingEncoding", "StrictlyIncreasingEncoding", "ZigZagEncoding"]);
+    assert!(reports[1].size < reports[0].size / 4);
+    // the first value is stored in front of the first block
+    assert_eq!(reports[1].blocks[0].offset, 1);
+    assert!(reports[1].blocks.iter().map(|block| block.size).sum::<usize>() < reports[1].size);
+    let input: Vec<u32> = (0..1000).rev().collect();
+    assert_eq!(analyze_all::<W128v, u32>(&input).len(), 2);
+    // signed integers with negative deltas
+    let input: Vec<i32> = (0..1000).map(|i| 500 - 3 * i).collect();
+    let reports = analyze_all::<W, i32>(&input);
+    assert_eq!(reports.len(), 2);
+    assert!(reports[1].blocks.iter().all(|block| block.b == 3 && block.exceptions == 0));
+    assert!(reports[1].size < reports[0].size / 3);
+}
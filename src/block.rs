@@ -0,0 +1,456 @@
+/// Low-level single block API.
+///
+/// The `p4n*` functions split the input into blocks of `Width::BLOCK_LEN` integers and encode every block
+/// with the single block functions of vp4.h (`p4enc32`, `p4denc32`, ... and `p4dec32`, `p4ddec32`, ...).
+/// `BlockEncoder` and `BlockDecoder` expose these functions for blocks of at most `Width::BLOCK_LEN` integers,
+/// with an explicit `start` value for the delta encodings, e.g. to build container formats of your own.
+///
+/// A block starts with a header holding the bit widths `b` and `bx` (see `block_header`), unless it is written by
+/// `BlockEncoder::encode_without_header` (`_p4enc32`). Then `b` and `bx` have to be stored elsewhere, e.g. in an
+/// index like TurboPFor's idxcr.c does, and handed to `BlockDecoder::decode_without_header` (`_p4dec32`, ...).
+/// `b` and `bx` are either chosen by the library (`_p4bits32`, see `BlockEncoder::best_bits`) or given by the caller.
+///
+/// The delta encodings encode the differences to the previous integer (minus 1 for `StrictlyIncreasingEncoding`,
+/// zigzag encoded for `ZigZagEncoding`), the first one relative to `start`.
+use crate::blockwise::*;
+use crate::codec::*;
+use crate::error::*;
+use crate::generic::*;
+use crate::sample::*;
+use num_traits::{PrimInt, WrappingSub};
+use std::mem::size_of;
+use rand::{
+    prelude::Distribution,
+    distributions::Standard,
+};
+
+/// Bit widths of a block: `b` for all integers and `bx` for the exceptions, i.e. the high bits of the integers
+/// that don't fit into `b` bits. `bx` is 0 for blocks without exceptions, the number of bits of the type + 1
+/// for exceptions stored as variable bytes and the number of bits + 2 for blocks of equal integers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockBits {
+    pub b: u32,
+    pub bx: u32,
+}
+
+/// The header of a block of `bits` bit integers with the bit widths `b` and `bx` (port of the `P4HVE` macros)
+/// # Returns
+/// The header bytes and the header length, 1 or 2
+pub fn block_header(b: u32, bx: u32, bits: u32) -> ([u8; 2], usize) {
+    // 64 bit blocks store b = 64 as 63
+    let b = if bits == 64 && b == 64 { 63 } else { b } as u8;
+    if bx == 0 {
+        ([b, 0], 1)
+    } else if bx <= bits {
+        ([0x80 | b, bx as u8], 2)
+    } else if bx == bits + 1 {
+        ([0x40 | b, 0], 1)
+    } else {
+        ([0xc0 | b, 0], 1)
+    }
+}
+
+/// Encodings whose blocks can be encoded and decoded on their own
+pub trait BlockEncoding: Blockwise
+    where Self::T: Codec<Self::W> + Copy + Default
+{
+    /// Encode a single block with header, `start` is the last integer before the block
+    /// # Returns
+    /// Number of output bytes used
+    fn encode_block(input: &[Self::T], output: &mut [u8], start: Self::T) -> usize;
+
+    /// The integer stored for `x`, `prev` is the integer before `x`
+    fn delta(x: Self::T, prev: Self::T) -> Self::T;
+
+    /// The bits the block packs for the stored integer `delta`: the zigzag of a signed integer for
+    /// `StandardEncoding`, the two's complement of the (zigzag encoded) delta for the delta encodings
+    fn stored_bits(delta: Self::T) -> u64;
+
+    /// The bit widths the library chooses for the stored integers `deltas` (`_p4bits32` etc.)
+    fn block_bits(deltas: &[Self::T]) -> (u32, u32);
+
+    /// Encode the stored integers `deltas` into a block without header with the bit widths `b` and `bx`
+    /// # Returns
+    /// Number of output bytes used
+    fn encode_block_bits(deltas: &[Self::T], output: &mut [u8], b: u32, bx: u32) -> usize;
+
+    /// Decode a single block without header with the bit widths `b` and `bx`, `start` is the last integer before the block
+    /// # Returns
+    /// Number of input bytes used
+    fn decode_block_bits(input: &[u8], n: usize, output: &mut [Self::T], start: Self::T, b: u32, bx: u32) -> usize;
+}
+
+impl<WT: Width, T: Codec<WT> + Default> BlockEncoding for StandardEncoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic + PrimInt + WrappingSub
+{
+    fn encode_block(input: &[T], output: &mut [u8], _start: T) -> usize {
+        T::enc_block(input, output)
+    }
+    fn delta(x: T, _prev: T) -> T {
+        x
+    }
+    fn stored_bits(delta: T) -> u64 {
+        zigzag_bits(delta)
+    }
+    fn block_bits(deltas: &[T]) -> (u32, u32) {
+        T::block_bits(deltas)
+    }
+    fn encode_block_bits(deltas: &[T], output: &mut [u8], b: u32, bx: u32) -> usize {
+        T::enc_block_bits(deltas, output, b, bx)
+    }
+    fn decode_block_bits(input: &[u8], n: usize, output: &mut [T], _start: T, b: u32, bx: u32) -> usize {
+        T::dec_block_bits(input, n, output, b, bx)
+    }
+}
+
+impl<WT: Width, T: DeltaCodec<WT> + Default> BlockEncoding for IncreasingEncoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic + PrimInt + WrappingSub
+{
+    fn encode_block(input: &[T], output: &mut [u8], start: T) -> usize {
+        T::denc_block(input, output, start)
+    }
+    fn delta(x: T, prev: T) -> T {
+        x.wrapping_sub(&prev)
+    }
+    fn stored_bits(delta: T) -> u64 {
+        twos_complement_bits(delta)
+    }
+    fn block_bits(deltas: &[T]) -> (u32, u32) {
+        T::delta_block_bits(deltas)
+    }
+    fn encode_block_bits(deltas: &[T], output: &mut [u8], b: u32, bx: u32) -> usize {
+        T::enc_delta_block_bits(deltas, output, b, bx)
+    }
+    fn decode_block_bits(input: &[u8], n: usize, output: &mut [T], start: T, b: u32, bx: u32) -> usize {
+        T::ddec_block_bits(input, n, output, start, b, bx)
+    }
+}
+
+impl<WT: Width, T: DeltaCodec<WT> + Default> BlockEncoding for StrictlyIncreasingEncoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic + PrimInt + WrappingSub
+{
+    fn encode_block(input: &[T], output: &mut [u8], start: T) -> usize {
+        T::d1enc_block(input, output, start)
+    }
+    fn delta(x: T, prev: T) -> T {
+        x.wrapping_sub(&prev).wrapping_sub(&T::one())
+    }
+    fn stored_bits(delta: T) -> u64 {
+        twos_complement_bits(delta)
+    }
+    fn block_bits(deltas: &[T]) -> (u32, u32) {
+        T::delta_block_bits(deltas)
+    }
+    fn encode_block_bits(deltas: &[T], output: &mut [u8], b: u32, bx: u32) -> usize {
+        T::enc_delta_block_bits(deltas, output, b, bx)
+    }
+    fn decode_block_bits(input: &[u8], n: usize, output: &mut [T], start: T, b: u32, bx: u32) -> usize {
+        T::d1dec_block_bits(input, n, output, start, b, bx)
+    }
+}
+
+impl<WT: Width, T: DeltaCodec<WT> + Default> BlockEncoding for ZigZagEncoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic + PrimInt + WrappingSub
+{
+    fn encode_block(input: &[T], output: &mut [u8], start: T) -> usize {
+        T::zenc_block(input, output, start)
+    }
+    fn delta(x: T, prev: T) -> T {
+        // a logical shift, so that the sign is 0 or 1 for the signed types as well
+        let d = x.wrapping_sub(&prev);
+        let sign = d.unsigned_shr(8 * size_of::<T>() as u32 - 1);
+        (d << 1) ^ T::zero().wrapping_sub(&sign)
+    }
+    fn stored_bits(delta: T) -> u64 {
+        twos_complement_bits(delta)
+    }
+    fn block_bits(deltas: &[T]) -> (u32, u32) {
+        T::delta_block_bits(deltas)
+    }
+    fn encode_block_bits(deltas: &[T], output: &mut [u8], b: u32, bx: u32) -> usize {
+        T::enc_delta_block_bits(deltas, output, b, bx)
+    }
+    fn decode_block_bits(input: &[u8], n: usize, output: &mut [T], start: T, b: u32, bx: u32) -> usize {
+        T::zdec_block_bits(input, n, output, start, b, bx)
+    }
+}
+
+/// Check that a block of `n` integers fits into a block of width `WT`
+fn check_len<WT: Width>(n: usize) -> Result<()> {
+    if n > WT::BLOCK_LEN {
+        return Err(TurboPforError::BlockTooLong { n, max: WT::BLOCK_LEN });
+    }
+    Ok(())
+}
+
+/// The two's complement of `x` as `u64`, `SignedInteger::to_unsigned` for the signed types
+pub(crate) fn twos_complement_bits<T: PrimInt>(x: T) -> u64 {
+    match x.to_u64() {
+        Some(v) => v,
+        None => x.to_i64().unwrap() as u64 & (u64::MAX >> (64 - 8 * size_of::<T>())),
+    }
+}
+
+/// `SignedInteger::zigzag` of `x` as `u64` for the signed types, the unsigned ones as they are
+pub(crate) fn zigzag_bits<T: PrimInt>(x: T) -> u64 {
+    if T::min_value() < T::zero() {
+        let v = x.to_i64().unwrap();
+        ((v << 1) ^ (v >> 63)) as u64
+    } else {
+        x.to_u64().unwrap()
+    }
+}
+
+/// Check that the stored integers `values` (see `BlockEncoding::stored_bits`) of `type_bits` bit integers can be
+/// encoded with the bit widths `bits`
+fn check_bits(values: &[u64], type_bits: u32, bits: BlockBits) -> Result<()> {
+    check_bit_width(bits.b, type_bits)?;
+    check_bit_width(bits.bx, type_bits + 2)?;
+    let first = values.first().copied().unwrap_or(0);
+    for &v in values {
+        let high = v.checked_shr(bits.b).unwrap_or(0);
+        let fits = match bits.bx {
+            0 => high == 0,
+            bx if bx <= type_bits => high.checked_shr(bx).unwrap_or(0) == 0,
+            bx if bx == type_bits + 1 => true,
+            _ => high == 0 && v == first,
+        };
+        if !fits {
+            return Err(TurboPforError::ValueTooWide { value: v, b: (bits.b + bits.bx).min(type_bits) });
+        }
+    }
+    Ok(())
+}
+
+/// Encodes single blocks with the encoding `E`
+pub struct BlockEncoder<E: BlockEncoding>
+    where E::T: Codec<E::W> + Copy + Default
+{
+    start: E::T,
+    bits: Option<BlockBits>,
+}
+
+impl<E: BlockEncoding> Default for BlockEncoder<E>
+    where E::T: Codec<E::W> + Copy + Default + PrimInt
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: BlockEncoding> BlockEncoder<E>
+    where E::T: Codec<E::W> + Copy + Default + PrimInt
+{
+    /// New encoder with `start` 0 and the bit widths chosen by the library
+    pub fn new() -> Self {
+        BlockEncoder { start: E::T::default(), bits: None }
+    }
+
+    /// Set the last integer before the block, the delta encodings encode the first integer relative to it
+    pub fn with_start(mut self, start: E::T) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Encode with the bit widths `bits` instead of the ones chosen by the library
+    pub fn with_bits(mut self, bits: BlockBits) -> Self {
+        self.bits = Some(bits);
+        self
+    }
+
+    /// Upper bound of the encoded size of a block of `n` integers, with header
+    pub fn max_encoded_size(n: usize) -> usize {
+        E::max_compressed_size(n)
+    }
+
+    /// The bit widths the library chooses for `input` (`_p4bits32` etc.)
+    /// # Returns
+    /// The bit widths, or `BlockTooLong` if `input` is longer than `E::W::BLOCK_LEN`
+    pub fn best_bits(&self, input: &[E::T]) -> Result<BlockBits> {
+        check_len::<E::W>(input.len())?;
+        let (b, bx) = E::block_bits(&self.deltas(input));
+        Ok(BlockBits { b, bx })
+    }
+
+    /// Encode `input` into a block with header (`p4enc32`, `p4denc32`, ...)
+    /// # Returns
+    /// Number of output bytes used, or `BlockTooLong`/`OutputTooSmall`, or `InvalidBitWidth`/`ValueTooWide`
+    /// if the integers don't fit into the bit widths given by `with_bits`
+    pub fn encode(&self, input: &[E::T], output: &mut [u8]) -> Result<usize> {
+        check_len::<E::W>(input.len())?;
+        check_output(Self::max_encoded_size(input.len()), output.len())?;
+        let bits = match self.bits {
+            None => return Ok(E::encode_block(input, output, self.start)),
+            Some(bits) => bits,
+        };
+        let deltas = self.checked_deltas(input, bits)?;
+        let (header, len) = block_header(bits.b, bits.bx, 8 * size_of::<E::T>() as u32);
+        output[..len].copy_from_slice(&header[..len]);
+        Ok(len + E::encode_block_bits(&deltas, &mut output[len..], bits.b, bits.bx))
+    }
+
+    /// Encode `input` into a block without header (`_p4enc32`)
+    /// # Returns
+    /// Number of output bytes used and the bit widths needed for decoding, or the errors of `encode`
+    pub fn encode_without_header(&self, input: &[E::T], output: &mut [u8]) -> Result<(usize, BlockBits)> {
+        let bits = match self.bits {
+            None => self.best_bits(input)?,
+            Some(bits) => bits,
+        };
+        check_len::<E::W>(input.len())?;
+        check_output(Self::max_encoded_size(input.len()), output.len())?;
+        let deltas = self.checked_deltas(input, bits)?;
+        Ok((E::encode_block_bits(&deltas, output, bits.b, bits.bx), bits))
+    }
+
+    /// The integers stored for `input`
+    fn deltas(&self, input: &[E::T]) -> Vec<E::T> {
+        let mut prev = self.start;
+        input.iter().map(|&x| { let d = E::delta(x, prev); prev = x; d }).collect()
+    }
+
+    /// The integers stored for `input`, checked against the bit widths `bits`
+    fn checked_deltas(&self, input: &[E::T], bits: BlockBits) -> Result<Vec<E::T>> {
+        let deltas = self.deltas(input);
+        let values: Vec<u64> = deltas.iter().map(|&d| E::stored_bits(d)).collect();
+        check_bits(&values, 8 * size_of::<E::T>() as u32, bits)?;
+        Ok(deltas)
+    }
+}
+
+/// Decodes single blocks encoded with the encoding `E`
+///
+/// Like `Encoding::decode`, the decoder trusts `input` and panics if the block turns out to extend beyond it.
+pub struct BlockDecoder<E: BlockEncoding>
+    where E::T: Codec<E::W> + Copy + Default
+{
+    start: E::T,
+}
+
+impl<E: BlockEncoding> Default for BlockDecoder<E>
+    where E::T: Codec<E::W> + Copy + Default
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: BlockEncoding> BlockDecoder<E>
+    where E::T: Codec<E::W> + Copy + Default
+{
+    /// New decoder with `start` 0
+    pub fn new() -> Self {
+        BlockDecoder { start: E::T::default() }
+    }
+
+    /// Set the last integer before the block, as given to the encoder
+    pub fn with_start(mut self, start: E::T) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Decode a block of `n` integers with header (`p4dec32`, `p4ddec32`, ...)
+    /// # Returns
+    /// Number of input bytes used, or `BlockTooLong`/`OutputTooSmall`/`InputTruncated`
+    pub fn decode(&self, input: &[u8], n: usize, output: &mut [E::T]) -> Result<usize> {
+        check_len::<E::W>(n)?;
+        check_input(n, input.len())?;
+        check_output(E::dec_buf_len(n), output.len())?;
+        Ok(E::decode_block(input, n, output, self.start))
+    }
+
+    /// Decode a block of `n` integers without header, encoded with the bit widths `bits` (`_p4dec32`, ...)
+    /// # Returns
+    /// Number of input bytes used, or `BlockTooLong`/`InvalidBitWidth`/`OutputTooSmall`
+    pub fn decode_without_header(&self, input: &[u8], n: usize, output: &mut [E::T], bits: BlockBits) -> Result<usize> {
+        let type_bits = 8 * size_of::<E::T>() as u32;
+        check_len::<E::W>(n)?;
+        check_bit_width(bits.b, type_bits)?;
+        check_bit_width(bits.bx, type_bits + 2)?;
+        check_output(E::dec_buf_len(n), output.len())?;
+        Ok(E::decode_block_bits(input, n, output, self.start, bits.b, bits.bx))
+    }
+}
+
+#[cfg(test)]
+fn test_block_generic<E: BlockEncoding>()
+    where E::T: Codec<E::W> + Copy + Default + PrimInt + std::fmt::Debug
+{
+    let input: Vec<E::T> = E::sample(E::W::BLOCK_LEN + 1);
+    let (start, input) = (input[0], &input[1..]);
+    for n in [0, 1, 100, E::W::BLOCK_LEN] {
+        // the increasing samples may be cut short to avoid an overflow
+        let n = n.min(input.len());
+        let input = &input[..n];
+        let encoder = BlockEncoder::<E>::new().with_start(start);
+        let decoder = BlockDecoder::<E>::new().with_start(start);
+        let mut buf = vec![0u8; BlockEncoder::<E>::max_encoded_size(n)];
+        let mut output = vec![E::T::default(); E::dec_buf_len(n)];
+        // with header
+        let size = encoder.encode(input, &mut buf).unwrap();
+        assert_eq!(decoder.decode(&buf[..size], n, &mut output), Ok(size));
+        assert_eq!(&output[..n], input);
+        // the header and the block the library writes for its own choice of bit widths
+        let bits = encoder.best_bits(input).unwrap();
+        let mut buf2 = vec![0u8; buf.len()];
+        assert_eq!(BlockEncoder::<E>::new().with_start(start).with_bits(bits).encode(input, &mut buf2), Ok(size));
+        assert_eq!(buf[..size], buf2[..size]);
+        // without header
+        let (size, bits2) = encoder.encode_without_header(input, &mut buf).unwrap();
+        assert_eq!(bits2, bits);
+        assert_eq!(decoder.decode_without_header(&buf[..size], n, &mut output, bits), Ok(size));
+        assert_eq!(&output[..n], input);
+    }
+}
+
+#[test]
+fn test_block() {
+    test_block_generic::<StandardEncoding<W, u8>>();
+    test_block_generic::<StandardEncoding<W, u64>>();
+    test_block_generic::<StandardEncoding<W128v, u16>>();
+    test_block_generic::<StandardEncoding<W128v, u64>>();
+    test_block_generic::<StandardEncoding<W256v, u32>>();
+    test_block_generic::<IncreasingEncoding<W, u32>>();
+    test_block_generic::<IncreasingEncoding<W256v, u32>>();
+    test_block_generic::<StrictlyIncreasingEncoding<W, u16>>();
+    test_block_generic::<StrictlyIncreasingEncoding<W128v, u32>>();
+    test_block_generic::<ZigZagEncoding<W, u64>>();
+    test_block_generic::<ZigZagEncoding<W128v, u32>>();
+    test_block_generic::<StandardEncoding<W, i32>>();
+    test_block_generic::<IncreasingEncoding<W, i32>>();
+    test_block_generic::<StrictlyIncreasingEncoding<W128v, i16>>();
+    test_block_generic::<ZigZagEncoding<W, i64>>();
+    test_block_generic::<ZigZagEncoding<W256v, i32>>();
+}
+
+#[test]
+fn test_block_bits() {
+    type E = IncreasingEncoding<W, u32>;
+    let input: Vec<u32> = (0..128).map(|i| 1000 + 3 * i).collect();
+    let encoder = BlockEncoder::<E>::new().with_start(997);
+    assert_eq!(encoder.best_bits(&input), Ok(BlockBits { b: 2, bx: 0 }));
+    let mut buf = vec![0u8; BlockEncoder::<E>::max_encoded_size(input.len())];
+    let mut output = vec![0u32; E::dec_buf_len(input.len())];
+    // caller-supplied bit widths, e.g. a common b for all blocks
+    let bits = BlockBits { b: 5, bx: 0 };
+    let (size, _) = encoder.with_bits(bits).encode_without_header(&input, &mut buf).unwrap();
+    assert_eq!(size, 128 * 5 / 8);
+    let decoder = BlockDecoder::<E>::new().with_start(997);
+    assert_eq!(decoder.decode_without_header(&buf[..size], input.len(), &mut output, bits), Ok(size));
+    assert_eq!(output[..128], input);
+    let encoder = BlockEncoder::<E>::new();
+    assert_eq!(encoder.with_bits(bits).encode(&input, &mut buf), Err(TurboPforError::ValueTooWide { value: 1000, b: 5 }));
+    let encoder = BlockEncoder::<E>::new().with_start(997);
+    assert_eq!(encoder.with_bits(BlockBits { b: 33, bx: 0 }).encode(&input, &mut buf), Err(TurboPforError::InvalidBitWidth { b: 33, max: 32 }));
+    let input: Vec<u32> = (0..129).collect();
+    assert_eq!(BlockEncoder::<E>::new().encode(&input, &mut buf), Err(TurboPforError::BlockTooLong { n: 129, max: 128 }));
+}
+
+#[test]
+fn test_block_header() {
+    assert_eq!(block_header(7, 0, 32), ([7, 0], 1));
+    assert_eq!(block_header(7, 3, 32), ([0x87, 3], 2));
+    assert_eq!(block_header(7, 33, 32), ([0x47, 0], 1));
+    assert_eq!(block_header(7, 34, 32), ([0xc7, 0], 1));
+    assert_eq!(block_header(64, 0, 64), ([63, 0], 1));
+}
@@ -0,0 +1,241 @@
+/// Lazy block-wise decoding.
+///
+/// `p4n*dec*` walks the compressed stream block by block (see `validate` for the layout), decoding each block
+/// of `Width::BLOCK_LEN` integers with the single block functions `p4dec*`, `p4ddec*`, `p4d1dec*` and `p4zdec*`.
+/// `Blockwise::blocks` exposes this walk: it decodes one block at a time into an internal scratch buffer
+/// and hands out `&[T]` slices, `Blockwise::iter` yields the integers one by one. Neither allocates
+/// more than a single block, so scanning a list until a condition is met only decodes the blocks up to that point.
+///
+/// The first block of the delta encodings also contains the first value, i.e. it is one integer longer.
+///
+/// Like `Encoding::decode`, the decoders trust `input` and panic if a block turns out to extend beyond it;
+/// use `validate::validate` first for untrusted input.
+use crate::codec::*;
+use crate::generic::*;
+use crate::sample::*;
+use crate::validate::*;
+use std::cmp::min;
+use rand::{
+    prelude::Distribution,
+    distributions::Standard,
+};
+
+/// Encodings that can be decoded block by block
+pub trait Blockwise: Validate + Sized
+    where Self::T: Codec<Self::W> + Copy + Default
+{
+    /// Decode a single block of `n` integers, `start` is the last integer before the block
+    /// # Returns
+    /// Number of input bytes used
+    fn decode_block(input: &[u8], n: usize, output: &mut [Self::T], start: Self::T) -> usize;
+
+    /// Blocks of the `n` integers encoded in `input`
+    fn blocks(input: &[u8], n: usize) -> Blocks<'_, Self> {
+        Blocks::new(input, n)
+    }
+
+    /// Iterator over the `n` integers encoded in `input`
+    fn iter(input: &[u8], n: usize) -> BlockIter<'_, Self> {
+        BlockIter { blocks: Blocks::new(input, n), idx: 0 }
+    }
+}
+
+impl<WT: Width, T: Codec<WT> + Default> Blockwise for StandardEncoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    fn decode_block(input: &[u8], n: usize, output: &mut [T], _start: T) -> usize {
+        T::dec_block(input, n, output)
+    }
+}
+
+impl<WT: Width, T: DeltaCodec<WT> + Default> Blockwise for IncreasingEncoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    fn decode_block(input: &[u8], n: usize, output: &mut [T], start: T) -> usize {
+        T::ddec_block(input, n, output, start)
+    }
+}
+
+impl<WT: Width, T: DeltaCodec<WT> + Default> Blockwise for StrictlyIncreasingEncoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    fn decode_block(input: &[u8], n: usize, output: &mut [T], start: T) -> usize {
+        T::d1dec_block(input, n, output, start)
+    }
+}
+
+impl<WT: Width, T: DeltaCodec<WT> + Default> Blockwise for ZigZagEncoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    fn decode_block(input: &[u8], n: usize, output: &mut [T], start: T) -> usize {
+        T::zdec_block(input, n, output, start)
+    }
+}
+
+/// Decodes one block at a time, see `Blockwise::blocks`
+pub struct Blocks<'a, E: Blockwise>
+    where E::T: Codec<E::W> + Copy + Default
+{
+    input: &'a [u8],
+    n: usize,
+    pos: usize,
+    remaining: usize,
+    first: bool,
+    start: E::T,
+    scratch: Vec<E::T>,
+    len: usize,
+}
+
+impl<'a, E: Blockwise> Blocks<'a, E>
+    where E::T: Codec<E::W> + Copy + Default
+{
+    fn new(input: &'a [u8], n: usize) -> Self {
+        Blocks {
+            input,
+            n,
+            pos: 0,
+            remaining: n,
+            first: true,
+            start: E::T::default(),
+            scratch: vec![E::T::default(); 1 + E::dec_buf_len(E::W::BLOCK_LEN)],
+            len: 0,
+        }
+    }
+
+    /// Decode the next block
+    /// # Returns
+    /// The integers of the block, or `None` after the last block
+    pub fn next_block(&mut self) -> Option<&[E::T]> {
+        if self.advance() { Some(self.block()) } else { None }
+    }
+
+    /// Number of input bytes used by the blocks decoded so far
+    pub fn consumed(&self) -> usize {
+        self.pos
+    }
+
+    /// Number of integers in the blocks not decoded yet
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    /// The block decoded last
+    pub(crate) fn block(&self) -> &[E::T] {
+        &self.scratch[..self.len]
+    }
+
+    /// Move before block number `block`, which starts at byte `pos` and follows the integer `start`, e.g. as
+    /// recorded in a `cursor::SkipIndex`; the next `advance` decodes it
+    pub(crate) fn seek(&mut self, block: usize, pos: usize, start: E::T) {
+        let before = if block == 0 { 0 } else { block * E::W::BLOCK_LEN + E::HAS_START as usize };
+        self.first = block == 0;
+        self.pos = pos;
+        self.start = start;
+        self.remaining = self.n.saturating_sub(before);
+        self.len = 0;
+    }
+
+    /// Decode the next block, `false` after the last block
+    pub(crate) fn advance(&mut self) -> bool {
+        if self.remaining == 0 {
+            return false;
+        }
+        let mut offset = 0;
+        if self.first && E::HAS_START {
+            // the first value is variable byte encoded in front of the first block
+            self.pos = E::decode_start(self.input, &mut self.scratch);
+            self.start = self.scratch[0];
+            self.remaining -= 1;
+            offset = 1;
+        }
+        self.first = false;
+        let n = min(self.remaining, E::W::BLOCK_LEN);
+        if n > 0 {
+            self.pos += E::decode_block(&self.input[self.pos..], n, &mut self.scratch[offset..], self.start);
+            self.start = self.scratch[offset + n - 1];
+            self.remaining -= n;
+        }
+        self.len = offset + n;
+        true
+    }
+}
+
+/// Iterator over the integers, see `Blockwise::iter`
+pub struct BlockIter<'a, E: Blockwise>
+    where E::T: Codec<E::W> + Copy + Default
+{
+    blocks: Blocks<'a, E>,
+    idx: usize,
+}
+
+impl<E: Blockwise> Iterator for BlockIter<'_, E>
+    where E::T: Codec<E::W> + Copy + Default
+{
+    type Item = E::T;
+
+    fn next(&mut self) -> Option<E::T> {
+        if self.idx == self.blocks.len {
+            if !self.blocks.advance() {
+                return None;
+            }
+            self.idx = 0;
+        }
+        self.idx += 1;
+        Some(self.blocks.scratch[self.idx - 1])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.blocks.len - self.idx + self.blocks.remaining;
+        (n, Some(n))
+    }
+}
+
+impl<E: Blockwise> ExactSizeIterator for BlockIter<'_, E>
+    where E::T: Codec<E::W> + Copy + Default
+{}
+
+#[cfg(test)]
+fn test_blockwise_generic<E: Blockwise>()
+    where E::T: Codec<E::W> + Copy + Default + std::fmt::Debug + PartialEq
+{
+    for len in [1, 2, 128, 129, 256, 257, 1000] {
+        let input: Vec<E::T> = E::sample(len);
+        let mut buf = vec![0u8; E::enc_buf_size(input.len())];
+        let size = E::encode(&input, &mut buf);
+        let output: Vec<E::T> = E::iter(&buf[..size], len).collect();
+        assert_eq!(input, output);
+        let mut blocks = E::blocks(&buf[..size], len);
+        let mut output = Vec::new();
+        while let Some(block) = blocks.next_block() {
+            assert!(block.len() <= E::W::BLOCK_LEN + 1);
+            output.extend_from_slice(block);
+        }
+        assert_eq!(input, output);
+        assert_eq!(blocks.consumed(), size);
+    }
+}
+
+#[test]
+fn test_blockwise() {
+    test_blockwise_generic::<StandardEncoding<W, u8>>();
+    test_blockwise_generic::<StandardEncoding<W128v, u32>>();
+    test_blockwise_generic::<IncreasingEncoding<W, u64>>();
+    test_blockwise_generic::<IncreasingEncoding<W256v, u32>>();
+    test_blockwise_generic::<StrictlyIncreasingEncoding<W, u32>>();
+    test_blockwise_generic::<StrictlyIncreasingEncoding<W128v, u16>>();
+    test_blockwise_generic::<ZigZagEncoding<W, u16>>();
+    test_blockwise_generic::<ZigZagEncoding<W256v, u32>>();
+}
+
+#[test]
+fn test_blockwise_lazy() {
+    type E = IncreasingEncoding<W, u32>;
+    let input: Vec<u32> = (0..10_000).collect();
+    let mut buf = vec![0u8; E::enc_buf_size(input.len())];
+    let size = E::encode(&input, &mut buf);
+    let mut iter = E::iter(&buf[..size], input.len());
+    assert_eq!(iter.len(), 10_000);
+    assert_eq!(iter.find(|&x| x >= 200), Some(200));
+    // only the first value and two blocks have been decoded
+    assert_eq!(iter.blocks.remaining(), 10_000 - 1 - 2 * 128);
+}
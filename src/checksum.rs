@@ -0,0 +1,74 @@
+/// CRC32C (Castagnoli) checksums for detecting corrupted payloads.
+///
+/// A flipped bit in a p4 block silently decodes to wrong integers, or sends the decoder beyond the input.
+/// `generic::encode_with_checksum` and `frame::encode_with_checksum` store a CRC32C next to the payload,
+/// the decoders verify it before decoding and return `ChecksumMismatch` on corruption.
+use crate::error::*;
+
+/// Length of a stored checksum in bytes
+pub const CHECKSUM_LEN: usize = 4;
+
+/// Reflected CRC32C polynomial
+const POLY: u32 = 0x82f6_3b78;
+
+const fn make_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut k = 0;
+        while k < 8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ POLY } else { crc >> 1 };
+            k += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+static TABLE: [u32; 256] = make_table();
+
+/// CRC32C of `data`
+pub fn crc32c(data: &[u8]) -> u32 {
+    !data.iter().fold(!0u32, |crc, &b| TABLE[((crc ^ b as u32) & 0xff) as usize] ^ (crc >> 8))
+}
+
+/// Append the CRC32C of `data[..len]` at `data[len..len + CHECKSUM_LEN]`
+/// # Returns
+/// `len + CHECKSUM_LEN`
+pub(crate) fn put_checksum(data: &mut [u8], len: usize) -> usize {
+    let crc = crc32c(&data[..len]);
+    data[len..len + CHECKSUM_LEN].copy_from_slice(&crc.to_le_bytes());
+    len + CHECKSUM_LEN
+}
+
+/// Verify the CRC32C stored in the last `CHECKSUM_LEN` bytes of `data`
+/// # Returns
+/// `data` without the checksum, or `InputTruncated`/`ChecksumMismatch`
+pub(crate) fn check_checksum(data: &[u8]) -> Result<&[u8]> {
+    if data.len() < CHECKSUM_LEN {
+        return Err(TurboPforError::InputTruncated { needed: CHECKSUM_LEN, got: data.len() });
+    }
+    let (payload, stored) = data.split_at(data.len() - CHECKSUM_LEN);
+    let expected = u32::from_le_bytes(stored.try_into().unwrap());
+    let got = crc32c(payload);
+    if expected != got {
+        return Err(TurboPforError::ChecksumMismatch { expected, got });
+    }
+    Ok(payload)
+}
+
+#[test]
+fn test_crc32c() {
+    // check values from RFC 3720, B.4
+    assert_eq!(crc32c(b""), 0);
+    assert_eq!(crc32c(b"123456789"), 0xe306_9283);
+    assert_eq!(crc32c(&[0u8; 32]), 0x8a91_36aa);
+    assert_eq!(crc32c(&[0xffu8; 32]), 0x62a8_ab43);
+    let mut data = *b"123456789\0\0\0\0";
+    assert_eq!(put_checksum(&mut data, 9), 13);
+    assert_eq!(check_checksum(&data), Ok(&b"123456789"[..]));
+    data[3] ^= 0x10;
+    assert!(matches!(check_checksum(&data), Err(TurboPforError::ChecksumMismatch { .. })));
+}
@@ -1,41 +1,76 @@
 use std::mem::size_of;
 use super::p4::*;
+use crate::bit;
+use crate::vb;
+use crate::v8;
+use crate::vs;
+use crate::ef;
+use crate::fp;
+use crate::error::*;
 
 pub trait Width {
-    /// Minimum safe [u8] size required for encoding `n` T's
+    /// Number of integers per TurboPFor block
+    const BLOCK_LEN: usize;
+    /// Numeric ID of the width, stored in `frame` headers
+    const WIDTH_ID: u8;
+    /// Conservative [u8] size for encoding `n` T's with any codec of this width,
+    /// see `Codec::max_compressed_size` for the exact per codec bound
     fn enc_buf_size<T>(n:usize) -> usize;
-    /// Minimum safe [T] size required for decoding `n` T's
+    /// Minimum safe [T] size required for decoding `n` T's, saturating at `usize::MAX` so that a huge
+    /// untrusted `n` fails the output size checks instead of wrapping around
     fn dec_buf_len<T>(n:usize)  -> usize;
 }
 
 /// Default width
 pub struct W;
 impl Width for W {
+    const BLOCK_LEN: usize = 128;
+    const WIDTH_ID: u8 = 0;
     fn enc_buf_size<T>(n:usize) -> usize {
         (n+127)/128 + (n+32)*size_of::<T>()
     }
-    fn dec_buf_len<T>(n:usize) -> usize { n+32 }
+    fn dec_buf_len<T>(n:usize) -> usize { n.saturating_add(32) }
 }
 
 /// Width 128v
 pub struct W128v;
 impl Width for W128v {
+    const BLOCK_LEN: usize = 128;
+    const WIDTH_ID: u8 = 1;
     fn enc_buf_size<T>(n:usize) -> usize {
         (n+127)/128 + (n+32)*size_of::<T>()
     }
-    fn dec_buf_len<T>(n:usize) -> usize { n+32 }
+    fn dec_buf_len<T>(n:usize) -> usize { n.saturating_add(32) }
 }
 
 /// Width 256
 pub struct W256v;
 impl Width for W256v {
+    const BLOCK_LEN: usize = 256;
+    const WIDTH_ID: u8 = 2;
     fn enc_buf_size<T>(n:usize) -> usize {
         (n+255)/256 + (n+32)*size_of::<T>()
     }
-    fn dec_buf_len<T>(n:usize) -> usize { n+32 }
+    fn dec_buf_len<T>(n:usize) -> usize { n.saturating_add(32) }
 }
 
+/// PFor codecs of the C library (`p4nenc*`/`p4ndec*` and the block functions of vp4.h), implemented for the
+/// combinations of width and type it provides: `u8` to `u64` with `W`, `u16` to `u64` with `W128v` and `u32` with
+/// `W256v`, plus the signed types of the same sizes. The transforming variants are in `DeltaCodec`.
+///
+/// The encoders and decoders panic if the output is shorter than `max_compressed_size` or `W::dec_buf_len`,
+/// respectively, or the input is truncated or corrupt; the `try_` variants return a `TurboPforError` instead.
+/// Implementations provide the checked decoders, the panicking ones unwrap them.
+#[diagnostic::on_unimplemented(
+    message = "TurboPFor has no `{W}` codec for `{Self}`",
+    label = "no `p4nenc`/`p4ndec` for this width and type",
+    note = "the p4 codecs exist for u8..u64 with `W`, u16..u64 with `W128v` and u32 with `W256v`, and the signed types of the same sizes",
+)]
 pub trait Codec<W:Width> where Self:Sized {
+    /// Upper bound of the compressed size of `n` integers of type `Self`, as computed by the C library
+    /// (`p4nbound*`), i.e. the minimum safe [u8] size required for encoding
+    fn max_compressed_size(n: usize) -> usize;
+
     /// Turbopfor encoding for unsorted integer lists of type `Self`
     /// # Arguments
     /// * `input` - `&[T]` containing the uncompressed input
@@ -51,8 +86,83 @@ pub trait Codec<W:Width> where Self:Sized {
     /// * `output` - Output `&[T]` containing the decompressed data
     /// # Returns
     /// Number of input bytes used
-    fn dec(input: &[u8], n: usize, output: &mut [Self]) -> usize;
+    fn dec(input: &[u8], n: usize, output: &mut [Self]) -> usize {
+        Self::try_dec(input, n, output).unwrap_or_else(|e| panic!("Codec::dec: {}", e))
+    }
+
+    /// Turbopfor decoding of a single block of at most 256 integers, as written by `enc`
+    /// # Arguments
+    /// * `input` - `&[u8]` containing the compressed block
+    /// * `n` - Number of integers in the block
+    /// * `output` - Output `&[T]` containing the decompressed data
+    /// # Returns
+    /// Number of input bytes used
+    fn dec_block(input: &[u8], n: usize, output: &mut [Self]) -> usize;
+
+    /// Turbopfor encoding of a single block of at most 256 integers with the block header, the block of `enc`
+    /// # Arguments
+    /// * `input` - `&[T]` containing the uncompressed input
+    /// * `output` - `[u8]` containing the compressed output
+    /// # Returns
+    /// Number of output bytes used
+    fn enc_block(input: &[Self], output: &mut [u8]) -> usize;
+
+    /// Best bit widths `(b, bx)` of a single block of at most 256 integers, as chosen by `enc_block`
+    fn block_bits(input: &[Self]) -> (u32, u32);
+
+    /// Turbopfor encoding of a single block of at most 256 integers with the bit widths `b` and `bx`,
+    /// without the block header
+    /// # Arguments
+    /// * `input` - `&[T]` containing the uncompressed input
+    /// * `output` - `[u8]` containing the compressed output
+    /// * `b` - Bit width of the integers
+    /// * `bx` - Bit width of the exceptions
+    /// # Returns
+    /// Number of output bytes used
+    fn enc_block_bits(input: &[Self], output: &mut [u8], b: u32, bx: u32) -> usize;
+
+    /// Turbopfor decoding of a single block without block header, as written by `enc_block_bits`
+    /// # Arguments
+    /// * `input` - `&[u8]` containing the compressed block
+    /// * `n` - Number of integers in the block
+    /// * `output` - Output `&[T]` containing the decompressed data
+    /// * `b` - Bit width of the integers
+    /// * `bx` - Bit width of the exceptions
+    /// # Returns
+    /// Number of input bytes used
+    fn dec_block_bits(input: &[u8], n: usize, output: &mut [Self], b: u32, bx: u32) -> usize;
+
+    /// Checked version of `enc`
+    /// # Returns
+    /// Number of output bytes used, or `OutputTooSmall` if `output` is shorter than `max_compressed_size`
+    fn try_enc(input: &[Self], output: &mut [u8]) -> Result<usize> {
+        check_output(Self::max_compressed_size(input.len()), output.len())?;
+        Ok(Self::enc(input, output))
+    }
 
+    /// Checked version of `dec`
+    /// # Returns
+    /// Number of input bytes used, or `OutputTooSmall` if `output` is shorter than `W::dec_buf_len`,
+    /// or `InputTruncated`/`BlockTruncated`/`InvalidBlockHeader` if `input` is truncated or corrupt
+    fn try_dec(input: &[u8], n: usize, output: &mut [Self]) -> Result<usize>;
+}
+
+/// The delta (`p4nd*`), strictly increasing delta (`p4nd1*`) and zigzag of delta (`p4nz*`) PFor codecs. The C
+/// library has them for the same combinations as `Codec` except `u64` with `W128v`.
+///
+/// The encodings are only implemented where the codecs exist, so the missing combinations don't compile:
+/// ```compile_fail
+/// use turbopfor_rs::codec::*;
+/// use turbopfor_rs::generic::*;
+/// let mut buf = vec![0u8; 1024];
+/// encode::<IncreasingEncoding<W128v, u64>>(&[1, 2, 3], &mut buf);
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "TurboPFor has no `{W}` delta codecs for `{Self}`",
+    label = "no `p4nd`/`p4nd1`/`p4nz` for this width and type",
+    note = "the delta codecs exist for u8..u64 with `W`, u16 and u32 with `W128v` and u32 with `W256v`, and the signed types of the same sizes",
+)]
+pub trait DeltaCodec<W:Width>: Codec<W> {
     /// Turbopfor delta encoding for increasing integer lists of type `Self` (sorted w/ duplicate)
     /// # Arguments
     /// * `input` - `&[T]` containing the uncompressed input
@@ -68,7 +178,9 @@ pub trait Codec<W:Width> where Self:Sized {
     /// * `output` - Output `&[T]` containing the decompressed data
     /// # Returns
     /// Number of input bytes used
-    fn ddec(input: &[u8], n: usize, output: &mut [Self]) -> usize;
+    fn ddec(input: &[u8], n: usize, output: &mut [Self]) -> usize {
+        Self::try_ddec(input, n, output).unwrap_or_else(|e| panic!("DeltaCodec::ddec: {}", e))
+    }
 
     /// Turbopfor delta encoding for strictly increasing integer lists of type `Self` (sorted unique)
     /// # Arguments
@@ -85,7 +197,9 @@ pub trait Codec<W:Width> where Self:Sized {
     /// * `output` - Output `&[T]` containing the decompressed data
     /// # Returns
     /// Number of input bytes used
-    fn d1dec(input: &[u8], n: usize, output: &mut [Self]) -> usize;
+    fn d1dec(input: &[u8], n: usize, output: &mut [Self]) -> usize {
+        Self::try_d1dec(input, n, output).unwrap_or_else(|e| panic!("DeltaCodec::d1dec: {}", e))
+    }
 
     /// Turbopfor ZigZag encoding for unsorted integer lists of type `Self`
     /// # Arguments
@@ -102,195 +216,2926 @@ pub trait Codec<W:Width> where Self:Sized {
     /// * `output` - Output `&[T]` containing the decompressed data
     /// # Returns
     /// Number of input bytes used
-    fn zdec(input: &[u8], n: usize, output: &mut [Self]) -> usize;
+    fn zdec(input: &[u8], n: usize, output: &mut [Self]) -> usize {
+        Self::try_zdec(input, n, output).unwrap_or_else(|e| panic!("DeltaCodec::zdec: {}", e))
+    }
+
+    /// Turbopfor delta decoding of a single block of at most 256 integers, as written by `denc`
+    /// # Arguments
+    /// * `input` - `&[u8]` containing the compressed block
+    /// * `n` - Number of integers in the block
+    /// * `output` - Output `&[T]` containing the decompressed data
+    /// * `start` - Last integer before the block
+    /// # Returns
+    /// Number of input bytes used
+    fn ddec_block(input: &[u8], n: usize, output: &mut [Self], start: Self) -> usize;
+
+    /// Turbopfor delta decoding of a single block of at most 256 integers, as written by `d1enc`
+    /// # Arguments
+    /// * `input` - `&[u8]` containing the compressed block
+    /// * `n` - Number of integers in the block
+    /// * `output` - Output `&[T]` containing the decompressed data
+    /// * `start` - Last integer before the block
+    /// # Returns
+    /// Number of input bytes used
+    fn d1dec_block(input: &[u8], n: usize, output: &mut [Self], start: Self) -> usize;
+
+    /// Turbopfor ZigZag decoding of a single block of at most 256 integers, as written by `zenc`
+    /// # Arguments
+    /// * `input` - `&[u8]` containing the compressed block
+    /// * `n` - Number of integers in the block
+    /// * `output` - Output `&[T]` containing the decompressed data
+    /// * `start` - Last integer before the block
+    /// # Returns
+    /// Number of input bytes used
+    fn zdec_block(input: &[u8], n: usize, output: &mut [Self], start: Self) -> usize;
+
+    /// Turbopfor delta encoding of a single block of at most 256 integers with the block header, the block of `denc`
+    /// # Arguments
+    /// * `input` - `&[T]` containing the uncompressed input
+    /// * `output` - `[u8]` containing the compressed output
+    /// * `start` - Last integer before the block
+    /// # Returns
+    /// Number of output bytes used
+    fn denc_block(input: &[Self], output: &mut [u8], start: Self) -> usize;
+
+    /// Turbopfor delta encoding of a single block of at most 256 integers with the block header, the block of `d1enc`
+    /// # Arguments
+    /// * `input` - `&[T]` containing the uncompressed input
+    /// * `output` - `[u8]` containing the compressed output
+    /// * `start` - Last integer before the block
+    /// # Returns
+    /// Number of output bytes used
+    fn d1enc_block(input: &[Self], output: &mut [u8], start: Self) -> usize;
+
+    /// Turbopfor ZigZag encoding of a single block of at most 256 integers with the block header, the block of `zenc`
+    /// # Arguments
+    /// * `input` - `&[T]` containing the uncompressed input
+    /// * `output` - `[u8]` containing the compressed output
+    /// * `start` - Last integer before the block
+    /// # Returns
+    /// Number of output bytes used
+    fn zenc_block(input: &[Self], output: &mut [u8], start: Self) -> usize;
+
+    /// Turbopfor delta decoding of a single block without block header, as written by `enc_delta_block_bits` from
+    /// the deltas
+    /// # Arguments
+    /// * `input` - `&[u8]` containing the compressed block
+    /// * `n` - Number of integers in the block
+    /// * `output` - Output `&[T]` containing the decompressed data
+    /// * `start` - Last integer before the block
+    /// * `b` - Bit width of the deltas
+    /// * `bx` - Bit width of the exceptions
+    /// # Returns
+    /// Number of input bytes used
+    fn ddec_block_bits(input: &[u8], n: usize, output: &mut [Self], start: Self, b: u32, bx: u32) -> usize;
+
+    /// Turbopfor delta decoding of a single block without block header, as written by `enc_delta_block_bits` from
+    /// the deltas - 1
+    /// # Arguments
+    /// * `input` - `&[u8]` containing the compressed block
+    /// * `n` - Number of integers in the block
+    /// * `output` - Output `&[T]` containing the decompressed data
+    /// * `start` - Last integer before the block
+    /// * `b` - Bit width of the deltas
+    /// * `bx` - Bit width of the exceptions
+    /// # Returns
+    /// Number of input bytes used
+    fn d1dec_block_bits(input: &[u8], n: usize, output: &mut [Self], start: Self, b: u32, bx: u32) -> usize;
+
+    /// Turbopfor ZigZag decoding of a single block without block header, as written by `enc_delta_block_bits` from the
+    /// zigzag encoded deltas
+    /// # Arguments
+    /// * `input` - `&[u8]` containing the compressed block
+    /// * `n` - Number of integers in the block
+    /// * `output` - Output `&[T]` containing the decompressed data
+    /// * `start` - Last integer before the block
+    /// * `b` - Bit width of the deltas
+    /// * `bx` - Bit width of the exceptions
+    /// # Returns
+    /// Number of input bytes used
+    fn zdec_block_bits(input: &[u8], n: usize, output: &mut [Self], start: Self, b: u32, bx: u32) -> usize;
+
+    /// `Codec::block_bits` of deltas as the `*dec_block_bits` functions read them back: the same for the unsigned
+    /// types, of the two's complement instead of the zigzag for the signed ones
+    fn delta_block_bits(input: &[Self]) -> (u32, u32) {
+        Self::block_bits(input)
+    }
+
+    /// `Codec::enc_block_bits` of deltas as the `*dec_block_bits` functions read them back, see `delta_block_bits`
+    fn enc_delta_block_bits(input: &[Self], output: &mut [u8], b: u32, bx: u32) -> usize {
+        Self::enc_block_bits(input, output, b, bx)
+    }
+
+    /// Checked version of `denc`
+    /// # Returns
+    /// Number of output bytes used, or `OutputTooSmall` if `output` is shorter than `max_compressed_size`
+    fn try_denc(input: &[Self], output: &mut [u8]) -> Result<usize> {
+        check_output(Self::max_compressed_size(input.len()), output.len())?;
+        Ok(Self::denc(input, output))
+    }
+
+    /// Checked version of `ddec`
+    /// # Returns
+    /// Number of input bytes used, or `OutputTooSmall` if `output` is shorter than `W::dec_buf_len`,
+    /// or `InputTruncated`/`BlockTruncated`/`InvalidBlockHeader` if `input` is truncated or corrupt
+    fn try_ddec(input: &[u8], n: usize, output: &mut [Self]) -> Result<usize>;
+
+    /// Checked version of `d1enc`
+    /// # Returns
+    /// Number of output bytes used, or `OutputTooSmall` if `output` is shorter than `max_compressed_size`
+    fn try_d1enc(input: &[Self], output: &mut [u8]) -> Result<usize> {
+        check_output(Self::max_compressed_size(input.len()), output.len())?;
+        Ok(Self::d1enc(input, output))
+    }
+
+    /// Checked version of `d1dec`
+    /// # Returns
+    /// Number of input bytes used, or `OutputTooSmall` if `output` is shorter than `W::dec_buf_len`,
+    /// or `InputTruncated`/`BlockTruncated`/`InvalidBlockHeader` if `input` is truncated or corrupt
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [Self]) -> Result<usize>;
 
+    /// Checked version of `zenc`
+    /// # Returns
+    /// Number of output bytes used, or `OutputTooSmall` if `output` is shorter than `max_compressed_size`
+    fn try_zenc(input: &[Self], output: &mut [u8]) -> Result<usize> {
+        check_output(Self::max_compressed_size(input.len()), output.len())?;
+        Ok(Self::zenc(input, output))
+    }
+
+    /// Checked version of `zdec`
+    /// # Returns
+    /// Number of input bytes used, or `OutputTooSmall` if `output` is shorter than `W::dec_buf_len`,
+    /// or `InputTruncated`/`BlockTruncated`/`InvalidBlockHeader` if `input` is truncated or corrupt
+    fn try_zdec(input: &[u8], n: usize, output: &mut [Self]) -> Result<usize>;
 }
 
 impl Codec<W> for u8 {
+    fn max_compressed_size(n: usize) -> usize {
+        bound8(n)
+    }
     fn enc(input: &[u8], output: &mut [u8]) -> usize {
         enc8(input, output)
     }
-    fn dec(input: &[u8], n: usize, output: &mut [u8]) -> usize {
-        dec8(input, n, output)
+    fn try_dec(input: &[u8], n: usize, output: &mut [u8]) -> Result<usize> {
+        try_dec8(input, n, output)
+    }
+    fn dec_block(input: &[u8], n: usize, output: &mut [u8]) -> usize {
+        dec_block8(input, n, output)
+    }
+    fn enc_block(input: &[u8], output: &mut [u8]) -> usize {
+        enc_block8(input, output)
+    }
+    fn block_bits(input: &[u8]) -> (u32, u32) {
+        bits8(input)
     }
+    fn enc_block_bits(input: &[u8], output: &mut [u8], b: u32, bx: u32) -> usize {
+        enc_block_bits8(input, output, b, bx)
+    }
+    fn dec_block_bits(input: &[u8], n: usize, output: &mut [u8], b: u32, bx: u32) -> usize {
+        dec_block_bits8(input, n, output, b, bx)
+    }
+}
+
+impl DeltaCodec<W> for u8 {
     fn denc(input: &[u8], output: &mut [u8]) -> usize {
         denc8(input, output)
     }
-    fn ddec(input: &[u8], n: usize, output: &mut [u8]) -> usize {
-        ddec8(input, n, output)
+    fn try_ddec(input: &[u8], n: usize, output: &mut [u8]) -> Result<usize> {
+        try_ddec8(input, n, output)
     }
     fn d1enc(input: &[u8], output: &mut [u8]) -> usize {
         d1enc8(input, output)
     }
-    fn d1dec(input: &[u8], n: usize, output: &mut [u8]) -> usize {
-        d1dec8(input, n, output)
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [u8]) -> Result<usize> {
+        try_d1dec8(input, n, output)
     }
     fn zenc(input: &[u8], output: &mut [u8]) -> usize {
         zenc8(input, output)
     }
-    fn zdec(input: &[u8], n: usize, output: &mut [u8]) -> usize {
-        zdec8(input, n, output)
+    fn try_zdec(input: &[u8], n: usize, output: &mut [u8]) -> Result<usize> {
+        try_zdec8(input, n, output)
+    }
+    fn ddec_block(input: &[u8], n: usize, output: &mut [u8], start: u8) -> usize {
+        ddec_block8(input, n, output, start)
+    }
+    fn d1dec_block(input: &[u8], n: usize, output: &mut [u8], start: u8) -> usize {
+        d1dec_block8(input, n, output, start)
+    }
+    fn zdec_block(input: &[u8], n: usize, output: &mut [u8], start: u8) -> usize {
+        zdec_block8(input, n, output, start)
+    }
+    fn denc_block(input: &[u8], output: &mut [u8], start: u8) -> usize {
+        denc_block8(input, output, start)
+    }
+    fn d1enc_block(input: &[u8], output: &mut [u8], start: u8) -> usize {
+        d1enc_block8(input, output, start)
+    }
+    fn zenc_block(input: &[u8], output: &mut [u8], start: u8) -> usize {
+        zenc_block8(input, output, start)
+    }
+    fn ddec_block_bits(input: &[u8], n: usize, output: &mut [u8], start: u8, b: u32, bx: u32) -> usize {
+        ddec_block_bits8(input, n, output, start, b, bx)
+    }
+    fn d1dec_block_bits(input: &[u8], n: usize, output: &mut [u8], start: u8, b: u32, bx: u32) -> usize {
+        d1dec_block_bits8(input, n, output, start, b, bx)
+    }
+    fn zdec_block_bits(input: &[u8], n: usize, output: &mut [u8], start: u8, b: u32, bx: u32) -> usize {
+        zdec_block_bits8(input, n, output, start, b, bx)
     }
 }
 
 impl Codec<W> for u16 {
+    fn max_compressed_size(n: usize) -> usize {
+        bound16(n)
+    }
     fn enc(input: &[u16], output: &mut [u8]) -> usize {
         enc16(input, output)
     }
-    fn dec(input: &[u8], n: usize, output: &mut [u16]) -> usize {
-        dec16(input, n, output)
+    fn try_dec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        try_dec16(input, n, output)
+    }
+    fn dec_block(input: &[u8], n: usize, output: &mut [u16]) -> usize {
+        dec_block16(input, n, output)
+    }
+    fn enc_block(input: &[u16], output: &mut [u8]) -> usize {
+        enc_block16(input, output)
     }
+    fn block_bits(input: &[u16]) -> (u32, u32) {
+        bits16(input)
+    }
+    fn enc_block_bits(input: &[u16], output: &mut [u8], b: u32, bx: u32) -> usize {
+        enc_block_bits16(input, output, b, bx)
+    }
+    fn dec_block_bits(input: &[u8], n: usize, output: &mut [u16], b: u32, bx: u32) -> usize {
+        dec_block_bits16(input, n, output, b, bx)
+    }
+}
+
+impl DeltaCodec<W> for u16 {
     fn denc(input: &[u16], output: &mut [u8]) -> usize {
         denc16(input, output)
     }
-    fn ddec(input: &[u8], n: usize, output: &mut [u16]) -> usize {
-        ddec16(input, n, output)
+    fn try_ddec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        try_ddec16(input, n, output)
     }
     fn d1enc(input: &[u16], output: &mut [u8]) -> usize {
         d1enc16(input, output)
     }
-    fn d1dec(input: &[u8], n: usize, output: &mut [u16]) -> usize {
-        d1dec16(input, n, output)
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        try_d1dec16(input, n, output)
     }
     fn zenc(input: &[u16], output: &mut [u8]) -> usize {
         zenc16(input, output)
     }
-    fn zdec(input: &[u8], n: usize, output: &mut [u16]) -> usize {
-        zdec16(input, n, output)
+    fn try_zdec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        try_zdec16(input, n, output)
+    }
+    fn ddec_block(input: &[u8], n: usize, output: &mut [u16], start: u16) -> usize {
+        ddec_block16(input, n, output, start)
+    }
+    fn d1dec_block(input: &[u8], n: usize, output: &mut [u16], start: u16) -> usize {
+        d1dec_block16(input, n, output, start)
+    }
+    fn zdec_block(input: &[u8], n: usize, output: &mut [u16], start: u16) -> usize {
+        zdec_block16(input, n, output, start)
+    }
+    fn denc_block(input: &[u16], output: &mut [u8], start: u16) -> usize {
+        denc_block16(input, output, start)
+    }
+    fn d1enc_block(input: &[u16], output: &mut [u8], start: u16) -> usize {
+        d1enc_block16(input, output, start)
+    }
+    fn zenc_block(input: &[u16], output: &mut [u8], start: u16) -> usize {
+        zenc_block16(input, output, start)
+    }
+    fn ddec_block_bits(input: &[u8], n: usize, output: &mut [u16], start: u16, b: u32, bx: u32) -> usize {
+        ddec_block_bits16(input, n, output, start, b, bx)
+    }
+    fn d1dec_block_bits(input: &[u8], n: usize, output: &mut [u16], start: u16, b: u32, bx: u32) -> usize {
+        d1dec_block_bits16(input, n, output, start, b, bx)
+    }
+    fn zdec_block_bits(input: &[u8], n: usize, output: &mut [u16], start: u16, b: u32, bx: u32) -> usize {
+        zdec_block_bits16(input, n, output, start, b, bx)
     }
 }
 
 impl Codec<W> for u32 {
+    fn max_compressed_size(n: usize) -> usize {
+        bound32(n)
+    }
     fn enc(input: &[u32], output: &mut [u8]) -> usize {
         enc32(input, output)
     }
-    fn dec(input: &[u8], n: usize, output: &mut [u32]) -> usize {
-        dec32(input, n, output)
+    fn try_dec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        try_dec32(input, n, output)
+    }
+    fn dec_block(input: &[u8], n: usize, output: &mut [u32]) -> usize {
+        dec_block32(input, n, output)
     }
+    fn enc_block(input: &[u32], output: &mut [u8]) -> usize {
+        enc_block32(input, output)
+    }
+    fn block_bits(input: &[u32]) -> (u32, u32) {
+        bits32(input)
+    }
+    fn enc_block_bits(input: &[u32], output: &mut [u8], b: u32, bx: u32) -> usize {
+        enc_block_bits32(input, output, b, bx)
+    }
+    fn dec_block_bits(input: &[u8], n: usize, output: &mut [u32], b: u32, bx: u32) -> usize {
+        dec_block_bits32(input, n, output, b, bx)
+    }
+}
+
+impl DeltaCodec<W> for u32 {
     fn denc(input: &[u32], output: &mut [u8]) -> usize {
         denc32(input, output)
     }
-    fn ddec(input: &[u8], n: usize, output: &mut [u32]) -> usize {
-        ddec32(input, n, output)
+    fn try_ddec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        try_ddec32(input, n, output)
     }
     fn d1enc(input: &[u32], output: &mut [u8]) -> usize {
         d1enc32(input, output)
     }
-    fn d1dec(input: &[u8], n: usize, output: &mut [u32]) -> usize {
-        d1dec32(input, n, output)
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        try_d1dec32(input, n, output)
     }
     fn zenc(input: &[u32], output: &mut [u8]) -> usize {
         zenc32(input, output)
     }
-    fn zdec(input: &[u8], n: usize, output: &mut [u32]) -> usize {
-        zdec32(input, n, output)
+    fn try_zdec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        try_zdec32(input, n, output)
+    }
+    fn ddec_block(input: &[u8], n: usize, output: &mut [u32], start: u32) -> usize {
+        ddec_block32(input, n, output, start)
+    }
+    fn d1dec_block(input: &[u8], n: usize, output: &mut [u32], start: u32) -> usize {
+        d1dec_block32(input, n, output, start)
+    }
+    fn zdec_block(input: &[u8], n: usize, output: &mut [u32], start: u32) -> usize {
+        zdec_block32(input, n, output, start)
+    }
+    fn denc_block(input: &[u32], output: &mut [u8], start: u32) -> usize {
+        denc_block32(input, output, start)
+    }
+    fn d1enc_block(input: &[u32], output: &mut [u8], start: u32) -> usize {
+        d1enc_block32(input, output, start)
+    }
+    fn zenc_block(input: &[u32], output: &mut [u8], start: u32) -> usize {
+        zenc_block32(input, output, start)
+    }
+    fn ddec_block_bits(input: &[u8], n: usize, output: &mut [u32], start: u32, b: u32, bx: u32) -> usize {
+        ddec_block_bits32(input, n, output, start, b, bx)
+    }
+    fn d1dec_block_bits(input: &[u8], n: usize, output: &mut [u32], start: u32, b: u32, bx: u32) -> usize {
+        d1dec_block_bits32(input, n, output, start, b, bx)
+    }
+    fn zdec_block_bits(input: &[u8], n: usize, output: &mut [u32], start: u32, b: u32, bx: u32) -> usize {
+        zdec_block_bits32(input, n, output, start, b, bx)
     }
 }
 
 impl Codec<W> for u64 {
+    fn max_compressed_size(n: usize) -> usize {
+        bound64(n)
+    }
     fn enc(input: &[u64], output: &mut [u8]) -> usize {
         enc64(input, output)
     }
-    fn dec(input: &[u8], n: usize, output: &mut [u64]) -> usize {
-        dec64(input, n, output)
+    fn try_dec(input: &[u8], n: usize, output: &mut [u64]) -> Result<usize> {
+        try_dec64(input, n, output)
     }
+    fn dec_block(input: &[u8], n: usize, output: &mut [u64]) -> usize {
+        dec_block64(input, n, output)
+    }
+    fn enc_block(input: &[u64], output: &mut [u8]) -> usize {
+        enc_block64(input, output)
+    }
+    fn block_bits(input: &[u64]) -> (u32, u32) {
+        bits64(input)
+    }
+    fn enc_block_bits(input: &[u64], output: &mut [u8], b: u32, bx: u32) -> usize {
+        enc_block_bits64(input, output, b, bx)
+    }
+    fn dec_block_bits(input: &[u8], n: usize, output: &mut [u64], b: u32, bx: u32) -> usize {
+        dec_block_bits64(input, n, output, b, bx)
+    }
+}
+
+impl DeltaCodec<W> for u64 {
     fn denc(input: &[u64], output: &mut [u8]) -> usize {
         denc64(input, output)
     }
-    fn ddec(input: &[u8], n: usize, output: &mut [u64]) -> usize {
-        ddec64(input, n, output)
+    fn try_ddec(input: &[u8], n: usize, output: &mut [u64]) -> Result<usize> {
+        try_ddec64(input, n, output)
     }
     fn d1enc(input: &[u64], output: &mut [u8]) -> usize {
         d1enc64(input, output)
     }
-    fn d1dec(input: &[u8], n: usize, output: &mut [u64]) -> usize {
-        d1dec64(input, n, output)
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [u64]) -> Result<usize> {
+        try_d1dec64(input, n, output)
     }
     fn zenc(input: &[u64], output: &mut [u8]) -> usize {
         zenc64(input, output)
     }
-    fn zdec(input: &[u8], n: usize, output: &mut [u64]) -> usize {
-        zdec64(input, n, output)
+    fn try_zdec(input: &[u8], n: usize, output: &mut [u64]) -> Result<usize> {
+        try_zdec64(input, n, output)
+    }
+    fn ddec_block(input: &[u8], n: usize, output: &mut [u64], start: u64) -> usize {
+        ddec_block64(input, n, output, start)
+    }
+    fn d1dec_block(input: &[u8], n: usize, output: &mut [u64], start: u64) -> usize {
+        d1dec_block64(input, n, output, start)
+    }
+    fn zdec_block(input: &[u8], n: usize, output: &mut [u64], start: u64) -> usize {
+        zdec_block64(input, n, output, start)
+    }
+    fn denc_block(input: &[u64], output: &mut [u8], start: u64) -> usize {
+        denc_block64(input, output, start)
+    }
+    fn d1enc_block(input: &[u64], output: &mut [u8], start: u64) -> usize {
+        d1enc_block64(input, output, start)
+    }
+    fn zenc_block(input: &[u64], output: &mut [u8], start: u64) -> usize {
+        zenc_block64(input, output, start)
+    }
+    fn ddec_block_bits(input: &[u8], n: usize, output: &mut [u64], start: u64, b: u32, bx: u32) -> usize {
+        ddec_block_bits64(input, n, output, start, b, bx)
+    }
+    fn d1dec_block_bits(input: &[u8], n: usize, output: &mut [u64], start: u64, b: u32, bx: u32) -> usize {
+        d1dec_block_bits64(input, n, output, start, b, bx)
+    }
+    fn zdec_block_bits(input: &[u8], n: usize, output: &mut [u64], start: u64, b: u32, bx: u32) -> usize {
+        zdec_block_bits64(input, n, output, start, b, bx)
     }
 }
 
 impl Codec<W128v> for u16 {
+    fn max_compressed_size(n: usize) -> usize {
+        bound128v16(n)
+    }
     fn enc(input: &[u16], output: &mut [u8]) -> usize {
         enc128v16(input, output)
     }
-    fn dec(input: &[u8], n: usize, output: &mut [u16]) -> usize {
-        dec128v16(input, n, output)
+    fn try_dec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        try_dec128v16(input, n, output)
+    }
+    fn dec_block(input: &[u8], n: usize, output: &mut [u16]) -> usize {
+        dec_block128v16(input, n, output)
+    }
+    fn enc_block(input: &[u16], output: &mut [u8]) -> usize {
+        enc_block128v16(input, output)
+    }
+    fn block_bits(input: &[u16]) -> (u32, u32) {
+        bits16(input)
     }
+    fn enc_block_bits(input: &[u16], output: &mut [u8], b: u32, bx: u32) -> usize {
+        enc_block_bits128v16(input, output, b, bx)
+    }
+    fn dec_block_bits(input: &[u8], n: usize, output: &mut [u16], b: u32, bx: u32) -> usize {
+        dec_block_bits128v16(input, n, output, b, bx)
+    }
+}
+
+impl DeltaCodec<W128v> for u16 {
     fn denc(input: &[u16], output: &mut [u8]) -> usize {
         denc128v16(input, output)
     }
-    fn ddec(input: &[u8], n: usize, output: &mut [u16]) -> usize {
-        ddec128v16(input, n, output)
+    fn try_ddec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        try_ddec128v16(input, n, output)
     }
     fn d1enc(input: &[u16], output: &mut [u8]) -> usize {
         d1enc128v16(input, output)
     }
-    fn d1dec(input: &[u8], n: usize, output: &mut [u16]) -> usize {
-        d1dec128v16(input, n, output)
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        try_d1dec128v16(input, n, output)
     }
     fn zenc(input: &[u16], output: &mut [u8]) -> usize {
         zenc128v16(input, output)
     }
-    fn zdec(input: &[u8], n: usize, output: &mut [u16]) -> usize {
-        zdec128v16(input, n, output)
+    fn try_zdec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        try_zdec128v16(input, n, output)
+    }
+    fn ddec_block(input: &[u8], n: usize, output: &mut [u16], start: u16) -> usize {
+        ddec_block128v16(input, n, output, start)
+    }
+    fn d1dec_block(input: &[u8], n: usize, output: &mut [u16], start: u16) -> usize {
+        d1dec_block128v16(input, n, output, start)
+    }
+    fn zdec_block(input: &[u8], n: usize, output: &mut [u16], start: u16) -> usize {
+        zdec_block128v16(input, n, output, start)
+    }
+    fn denc_block(input: &[u16], output: &mut [u8], start: u16) -> usize {
+        denc_block128v16(input, output, start)
+    }
+    fn d1enc_block(input: &[u16], output: &mut [u8], start: u16) -> usize {
+        d1enc_block128v16(input, output, start)
+    }
+    fn zenc_block(input: &[u16], output: &mut [u8], start: u16) -> usize {
+        zenc_block128v16(input, output, start)
+    }
+    fn ddec_block_bits(input: &[u8], n: usize, output: &mut [u16], start: u16, b: u32, bx: u32) -> usize {
+        ddec_block_bits128v16(input, n, output, start, b, bx)
+    }
+    fn d1dec_block_bits(input: &[u8], n: usize, output: &mut [u16], start: u16, b: u32, bx: u32) -> usize {
+        d1dec_block_bits128v16(input, n, output, start, b, bx)
+    }
+    fn zdec_block_bits(input: &[u8], n: usize, output: &mut [u16], start: u16, b: u32, bx: u32) -> usize {
+        zdec_block_bits128v16(input, n, output, start, b, bx)
     }
 }
 
 impl Codec<W128v> for u32 {
+    fn max_compressed_size(n: usize) -> usize {
+        bound128v32(n)
+    }
     fn enc(input: &[u32], output: &mut [u8]) -> usize {
         enc128v32(input, output)
     }
-    fn dec(input: &[u8], n: usize, output: &mut [u32]) -> usize {
-        dec128v32(input, n, output)
+    fn try_dec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        try_dec128v32(input, n, output)
+    }
+    fn dec_block(input: &[u8], n: usize, output: &mut [u32]) -> usize {
+        dec_block128v32(input, n, output)
+    }
+    fn enc_block(input: &[u32], output: &mut [u8]) -> usize {
+        enc_block128v32(input, output)
     }
+    fn block_bits(input: &[u32]) -> (u32, u32) {
+        bits32(input)
+    }
+    fn enc_block_bits(input: &[u32], output: &mut [u8], b: u32, bx: u32) -> usize {
+        enc_block_bits128v32(input, output, b, bx)
+    }
+    fn dec_block_bits(input: &[u8], n: usize, output: &mut [u32], b: u32, bx: u32) -> usize {
+        dec_block_bits128v32(input, n, output, b, bx)
+    }
+}
+
+impl DeltaCodec<W128v> for u32 {
     fn denc(input: &[u32], output: &mut [u8]) -> usize {
         denc128v32(input, output)
     }
-    fn ddec(input: &[u8], n: usize, output: &mut [u32]) -> usize {
-        ddec128v32(input, n, output)
+    fn try_ddec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        try_ddec128v32(input, n, output)
     }
     fn d1enc(input: &[u32], output: &mut [u8]) -> usize {
         d1enc128v32(input, output)
     }
-    fn d1dec(input: &[u8], n: usize, output: &mut [u32]) -> usize {
-        d1dec128v32(input, n, output)
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        try_d1dec128v32(input, n, output)
     }
     fn zenc(input: &[u32], output: &mut [u8]) -> usize {
         zenc128v32(input, output)
     }
-    fn zdec(input: &[u8], n: usize, output: &mut [u32]) -> usize {
-        zdec128v32(input, n, output)
+    fn try_zdec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        try_zdec128v32(input, n, output)
+    }
+    fn ddec_block(input: &[u8], n: usize, output: &mut [u32], start: u32) -> usize {
+        ddec_block128v32(input, n, output, start)
+    }
+    fn d1dec_block(input: &[u8], n: usize, output: &mut [u32], start: u32) -> usize {
+        d1dec_block128v32(input, n, output, start)
+    }
+    fn zdec_block(input: &[u8], n: usize, output: &mut [u32], start: u32) -> usize {
+        zdec_block128v32(input, n, output, start)
+    }
+    fn denc_block(input: &[u32], output: &mut [u8], start: u32) -> usize {
+        denc_block128v32(input, output, start)
+    }
+    fn d1enc_block(input: &[u32], output: &mut [u8], start: u32) -> usize {
+        d1enc_block128v32(input, output, start)
+    }
+    fn zenc_block(input: &[u32], output: &mut [u8], start: u32) -> usize {
+        zenc_block128v32(input, output, start)
+    }
+    fn ddec_block_bits(input: &[u8], n: usize, output: &mut [u32], start: u32, b: u32, bx: u32) -> usize {
+        ddec_block_bits128v32(input, n, output, start, b, bx)
+    }
+    fn d1dec_block_bits(input: &[u8], n: usize, output: &mut [u32], start: u32, b: u32, bx: u32) -> usize {
+        d1dec_block_bits128v32(input, n, output, start, b, bx)
+    }
+    fn zdec_block_bits(input: &[u8], n: usize, output: &mut [u32], start: u32, b: u32, bx: u32) -> usize {
+        zdec_block_bits128v32(input, n, output, start, b, bx)
+    }
+}
+
+impl Codec<W128v> for u64 {
+    fn max_compressed_size(n: usize) -> usize {
+        bound128v64(n)
+    }
+    fn enc(input: &[u64], output: &mut [u8]) -> usize {
+        enc128v64(input, output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u64]) -> Result<usize> {
+        try_dec128v64(input, n, output)
+    }
+    fn dec_block(input: &[u8], n: usize, output: &mut [u64]) -> usize {
+        dec_block128v64(input, n, output)
+    }
+    fn enc_block(input: &[u64], output: &mut [u8]) -> usize {
+        enc_block128v64(input, output)
+    }
+    fn block_bits(input: &[u64]) -> (u32, u32) {
+        bits64(input)
+    }
+    fn enc_block_bits(input: &[u64], output: &mut [u8], b: u32, bx: u32) -> usize {
+        enc_block_bits128v64(input, output, b, bx)
+    }
+    fn dec_block_bits(input: &[u8], n: usize, output: &mut [u64], b: u32, bx: u32) -> usize {
+        dec_block_bits128v64(input, n, output, b, bx)
     }
 }
 
 impl Codec<W256v> for u32 {
+    fn max_compressed_size(n: usize) -> usize {
+        bound256v32(n)
+    }
     fn enc(input: &[u32], output: &mut [u8]) -> usize {
         enc256v32(input, output)
     }
-    fn dec(input: &[u8], n: usize, output: &mut [u32]) -> usize {
-        dec256v32(input, n, output)
+    fn try_dec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        try_dec256v32(input, n, output)
+    }
+    fn dec_block(input: &[u8], n: usize, output: &mut [u32]) -> usize {
+        dec_block256v32(input, n, output)
+    }
+    fn enc_block(input: &[u32], output: &mut [u8]) -> usize {
+        enc_block256v32(input, output)
     }
+    fn block_bits(input: &[u32]) -> (u32, u32) {
+        bits32(input)
+    }
+    fn enc_block_bits(input: &[u32], output: &mut [u8], b: u32, bx: u32) -> usize {
+        enc_block_bits256v32(input, output, b, bx)
+    }
+    fn dec_block_bits(input: &[u8], n: usize, output: &mut [u32], b: u32, bx: u32) -> usize {
+        // `_p4dec256v32` isn't exported by the library: prepend the header and decode with `p4dec256v32`
+        let (header, len) = crate::block::block_header(b, bx, 32);
+        let mut block = header[..len].to_vec();
+        block.extend_from_slice(&input[..input.len().min(bound256v32(n))]);
+        dec_block256v32(&block, n, output) - len
+    }
+}
+
+impl DeltaCodec<W256v> for u32 {
     fn denc(input: &[u32], output: &mut [u8]) -> usize {
         denc256v32(input, output)
     }
-    fn ddec(input: &[u8], n: usize, output: &mut [u32]) -> usize {
-        ddec256v32(input, n, output)
+    fn try_ddec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        try_ddec256v32(input, n, output)
     }
     fn d1enc(input: &[u32], output: &mut [u8]) -> usize {
         d1enc256v32(input, output)
     }
-    fn d1dec(input: &[u8], n: usize, output: &mut [u32]) -> usize {
-        d1dec256v32(input, n, output)
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        try_d1dec256v32(input, n, output)
     }
     fn zenc(input: &[u32], output: &mut [u8]) -> usize {
         zenc256v32(input, output)
     }
-    fn zdec(input: &[u8], n: usize, output: &mut [u32]) -> usize {
-        zdec256v32(input, n, output)
+    fn try_zdec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        try_zdec256v32(input, n, output)
+    }
+    fn ddec_block(input: &[u8], n: usize, output: &mut [u32], start: u32) -> usize {
+        ddec_block256v32(input, n, output, start)
+    }
+    fn d1dec_block(input: &[u8], n: usize, output: &mut [u32], start: u32) -> usize {
+        d1dec_block256v32(input, n, output, start)
+    }
+    fn zdec_block(input: &[u8], n: usize, output: &mut [u32], start: u32) -> usize {
+        zdec_block256v32(input, n, output, start)
+    }
+    fn denc_block(input: &[u32], output: &mut [u8], start: u32) -> usize {
+        denc_block256v32(input, output, start)
+    }
+    fn d1enc_block(input: &[u32], output: &mut [u8], start: u32) -> usize {
+        d1enc_block256v32(input, output, start)
+    }
+    fn zenc_block(input: &[u32], output: &mut [u8], start: u32) -> usize {
+        zenc_block256v32(input, output, start)
+    }
+    fn ddec_block_bits(input: &[u8], n: usize, output: &mut [u32], start: u32, b: u32, bx: u32) -> usize {
+        ddec_block_bits256v32(input, n, output, start, b, bx)
+    }
+    fn d1dec_block_bits(input: &[u8], n: usize, output: &mut [u32], start: u32, b: u32, bx: u32) -> usize {
+        d1dec_block_bits256v32(input, n, output, start, b, bx)
+    }
+    fn zdec_block_bits(input: &[u8], n: usize, output: &mut [u32], start: u32, b: u32, bx: u32) -> usize {
+        zdec_block_bits256v32(input, n, output, start, b, bx)
+    }
+}
+
+/// The TurboVByte variable byte codecs (`vb*`). They encode every integer on its own, so there are no blocks
+/// and no width; the decoders write exactly `n` integers. The delta codecs take the integer before the first one
+/// as `start`.
+pub trait VByteCodec where Self: Sized {
+    /// Upper bound of the encoded size of `n` integers of type `Self` (`vbbound*`)
+    fn max_compressed_size(n: usize) -> usize;
+    /// Variable byte encoding for unsorted integer lists
+    fn enc(input: &[Self], output: &mut [u8]) -> usize;
+    /// Variable byte decoding into unsorted integer lists
+    fn dec(input: &[u8], n: usize, output: &mut [Self]) -> usize {
+        Self::try_dec(input, n, output).unwrap_or_else(|e| panic!("VByteCodec::dec: {}", e))
+    }
+    /// Checked version of `dec`
+    fn try_dec(input: &[u8], n: usize, output: &mut [Self]) -> Result<usize>;
+    /// Variable byte delta encoding for increasing integer lists (sorted w/ duplicate)
+    fn denc(input: &[Self], output: &mut [u8], start: Self) -> usize;
+    /// Variable byte delta decoding into increasing integer lists (sorted w/ duplicate)
+    fn ddec(input: &[u8], n: usize, output: &mut [Self], start: Self) -> usize {
+        Self::try_ddec(input, n, output, start).unwrap_or_else(|e| panic!("VByteCodec::ddec: {}", e))
+    }
+    /// Checked version of `ddec`
+    fn try_ddec(input: &[u8], n: usize, output: &mut [Self], start: Self) -> Result<usize>;
+    /// Variable byte delta encoding for strictly increasing integer lists (sorted unique)
+    fn d1enc(input: &[Self], output: &mut [u8], start: Self) -> usize;
+    /// Variable byte delta decoding into strictly increasing integer lists (sorted unique)
+    fn d1dec(input: &[u8], n: usize, output: &mut [Self], start: Self) -> usize {
+        Self::try_d1dec(input, n, output, start).unwrap_or_else(|e| panic!("VByteCodec::d1dec: {}", e))
+    }
+    /// Checked version of `d1dec`
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [Self], start: Self) -> Result<usize>;
+    /// Variable byte ZigZag of delta encoding for unsorted integer lists
+    fn zenc(input: &[Self], output: &mut [u8], start: Self) -> usize;
+    /// Variable byte ZigZag of delta decoding into unsorted integer lists
+    fn zdec(input: &[u8], n: usize, output: &mut [Self], start: Self) -> usize {
+        Self::try_zdec(input, n, output, start).unwrap_or_else(|e| panic!("VByteCodec::zdec: {}", e))
+    }
+    /// Checked version of `zdec`
+    fn try_zdec(input: &[u8], n: usize, output: &mut [Self], start: Self) -> Result<usize>;
+    /// Variable byte XOR encoding for unsorted integer lists
+    fn xenc(input: &[Self], output: &mut [u8], start: Self) -> usize;
+    /// Variable byte XOR decoding into unsorted integer lists
+    fn xdec(input: &[u8], n: usize, output: &mut [Self], start: Self) -> usize {
+        Self::try_xdec(input, n, output, start).unwrap_or_else(|e| panic!("VByteCodec::xdec: {}", e))
+    }
+    /// Checked version of `xdec`
+    fn try_xdec(input: &[u8], n: usize, output: &mut [Self], start: Self) -> Result<usize>;
+    /// Variable byte delta of delta encoding for unsorted integer lists
+    fn ddenc(input: &[Self], output: &mut [u8], start: Self) -> usize;
+    /// Variable byte delta of delta decoding into unsorted integer lists
+    fn dddec(input: &[u8], n: usize, output: &mut [Self], start: Self) -> usize {
+        Self::try_dddec(input, n, output, start).unwrap_or_else(|e| panic!("VByteCodec::dddec: {}", e))
+    }
+    /// Checked version of `dddec`
+    fn try_dddec(input: &[u8], n: usize, output: &mut [Self], start: Self) -> Result<usize>;
+}
+
+impl VByteCodec for u16 {
+    fn max_compressed_size(n: usize) -> usize {
+        vb::bound16(n)
+    }
+    fn enc(input: &[u16], output: &mut [u8]) -> usize {
+        vb::enc16(input, output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        vb::try_dec16(input, n, output)
+    }
+    fn denc(input: &[u16], output: &mut [u8], start: u16) -> usize {
+        vb::denc16(input, output, start)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [u16], start: u16) -> Result<usize> {
+        vb::try_ddec16(input, n, output, start)
+    }
+    fn d1enc(input: &[u16], output: &mut [u8], start: u16) -> usize {
+        vb::d1enc16(input, output, start)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [u16], start: u16) -> Result<usize> {
+        vb::try_d1dec16(input, n, output, start)
+    }
+    fn zenc(input: &[u16], output: &mut [u8], start: u16) -> usize {
+        vb::zenc16(input, output, start)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [u16], start: u16) -> Result<usize> {
+        vb::try_zdec16(input, n, output, start)
+    }
+    fn xenc(input: &[u16], output: &mut [u8], start: u16) -> usize {
+        vb::xenc16(input, output, start)
+    }
+    fn try_xdec(input: &[u8], n: usize, output: &mut [u16], start: u16) -> Result<usize> {
+        vb::try_xdec16(input, n, output, start)
+    }
+    fn ddenc(input: &[u16], output: &mut [u8], start: u16) -> usize {
+        vb::ddenc16(input, output, start)
+    }
+    fn try_dddec(input: &[u8], n: usize, output: &mut [u16], start: u16) -> Result<usize> {
+        vb::try_dddec16(input, n, output, start)
+    }
+}
+
+impl VByteCodec for u32 {
+    fn max_compressed_size(n: usize) -> usize {
+        vb::bound32(n)
+    }
+    fn enc(input: &[u32], output: &mut [u8]) -> usize {
+        vb::enc32(input, output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        vb::try_dec32(input, n, output)
+    }
+    fn denc(input: &[u32], output: &mut [u8], start: u32) -> usize {
+        vb::denc32(input, output, start)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [u32], start: u32) -> Result<usize> {
+        vb::try_ddec32(input, n, output, start)
+    }
+    fn d1enc(input: &[u32], output: &mut [u8], start: u32) -> usize {
+        vb::d1enc32(input, output, start)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [u32], start: u32) -> Result<usize> {
+        vb::try_d1dec32(input, n, output, start)
+    }
+    fn zenc(input: &[u32], output: &mut [u8], start: u32) -> usize {
+        vb::zenc32(input, output, start)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [u32], start: u32) -> Result<usize> {
+        vb::try_zdec32(input, n, output, start)
+    }
+    fn xenc(input: &[u32], output: &mut [u8], start: u32) -> usize {
+        vb::xenc32(input, output, start)
+    }
+    fn try_xdec(input: &[u8], n: usize, output: &mut [u32], start: u32) -> Result<usize> {
+        vb::try_xdec32(input, n, output, start)
+    }
+    fn ddenc(input: &[u32], output: &mut [u8], start: u32) -> usize {
+        vb::ddenc32(input, output, start)
+    }
+    fn try_dddec(input: &[u8], n: usize, output: &mut [u32], start: u32) -> Result<usize> {
+        vb::try_dddec32(input, n, output, start)
+    }
+}
+
+impl VByteCodec for u64 {
+    fn max_compressed_size(n: usize) -> usize {
+        vb::bound64(n)
+    }
+    fn enc(input: &[u64], output: &mut [u8]) -> usize {
+        vb::enc64(input, output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u64]) -> Result<usize> {
+        vb::try_dec64(input, n, output)
+    }
+    fn denc(input: &[u64], output: &mut [u8], start: u64) -> usize {
+        vb::denc64(input, output, start)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [u64], start: u64) -> Result<usize> {
+        vb::try_ddec64(input, n, output, start)
+    }
+    fn d1enc(input: &[u64], output: &mut [u8], start: u64) -> usize {
+        vb::d1enc64(input, output, start)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [u64], start: u64) -> Result<usize> {
+        vb::try_d1dec64(input, n, output, start)
+    }
+    fn zenc(input: &[u64], output: &mut [u8], start: u64) -> usize {
+        vb::zenc64(input, output, start)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [u64], start: u64) -> Result<usize> {
+        vb::try_zdec64(input, n, output, start)
+    }
+    fn xenc(input: &[u64], output: &mut [u8], start: u64) -> usize {
+        vb::xenc64(input, output, start)
+    }
+    fn try_xdec(input: &[u8], n: usize, output: &mut [u64], start: u64) -> Result<usize> {
+        vb::try_xdec64(input, n, output, start)
+    }
+    fn ddenc(input: &[u64], output: &mut [u8], start: u64) -> usize {
+        vb::ddenc64(input, output, start)
+    }
+    fn try_dddec(input: &[u8], n: usize, output: &mut [u64], start: u64) -> Result<usize> {
+        vb::try_dddec64(input, n, output, start)
+    }
+}
+
+/// The SIMD TurboByte codec `v8n*` of vint.h for `u16` and `u32`. Like `Codec` the encodings are tied
+/// to a `Width`: `W` uses the scalar `v8nenc16`/`v8nenc32`, `W128v` and `W256v` the `v8n*128v*` and `v8n*256v32`
+/// variants. The decoders need an output of at least `W::dec_buf_len(n)`.
+pub trait V8Codec<W: Width> where Self: Sized {
+    /// Upper bound of the encoded size of `n` integers of type `Self` (`v8bound*`)
+    fn max_compressed_size(n: usize) -> usize;
+    /// TurboByte encoding for unsorted integer lists
+    fn enc(input: &[Self], output: &mut [u8]) -> usize;
+    /// TurboByte decoding into unsorted integer lists
+    fn dec(input: &[u8], n: usize, output: &mut [Self]) -> usize {
+        Self::try_dec(input, n, output).unwrap_or_else(|e| panic!("V8Codec::dec: {}", e))
+    }
+    /// Checked version of `dec`
+    fn try_dec(input: &[u8], n: usize, output: &mut [Self]) -> Result<usize>;
+    /// TurboByte delta encoding for increasing integer lists (sorted w/ duplicate)
+    fn denc(input: &[Self], output: &mut [u8]) -> usize;
+    /// TurboByte delta decoding into increasing integer lists (sorted w/ duplicate)
+    fn ddec(input: &[u8], n: usize, output: &mut [Self]) -> usize {
+        Self::try_ddec(input, n, output).unwrap_or_else(|e| panic!("V8Codec::ddec: {}", e))
+    }
+    /// Checked version of `ddec`
+    fn try_ddec(input: &[u8], n: usize, output: &mut [Self]) -> Result<usize>;
+    /// TurboByte delta encoding for strictly increasing integer lists (sorted unique)
+    fn d1enc(input: &[Self], output: &mut [u8]) -> usize;
+    /// TurboByte delta decoding into strictly increasing integer lists (sorted unique)
+    fn d1dec(input: &[u8], n: usize, output: &mut [Self]) -> usize {
+        Self::try_d1dec(input, n, output).unwrap_or_else(|e| panic!("V8Codec::d1dec: {}", e))
+    }
+    /// Checked version of `d1dec`
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [Self]) -> Result<usize>;
+    /// TurboByte ZigZag of delta encoding for unsorted integer lists
+    fn zenc(input: &[Self], output: &mut [u8]) -> usize;
+    /// TurboByte ZigZag of delta decoding into unsorted integer lists
+    fn zdec(input: &[u8], n: usize, output: &mut [Self]) -> usize {
+        Self::try_zdec(input, n, output).unwrap_or_else(|e| panic!("V8Codec::zdec: {}", e))
+    }
+    /// Checked version of `zdec`
+    fn try_zdec(input: &[u8], n: usize, output: &mut [Self]) -> Result<usize>;
+    /// TurboByte XOR encoding for unsorted integer lists
+    fn xenc(input: &[Self], output: &mut [u8]) -> usize;
+    /// TurboByte XOR decoding into unsorted integer lists
+    fn xdec(input: &[u8], n: usize, output: &mut [Self]) -> usize {
+        Self::try_xdec(input, n, output).unwrap_or_else(|e| panic!("V8Codec::xdec: {}", e))
+    }
+    /// Checked version of `xdec`
+    fn try_xdec(input: &[u8], n: usize, output: &mut [Self]) -> Result<usize>;
+}
+
+impl V8Codec<W> for u16 {
+    fn max_compressed_size(n: usize) -> usize {
+        v8::bound16(n)
+    }
+    fn enc(input: &[u16], output: &mut [u8]) -> usize {
+        v8::enc16(input, output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        v8::try_dec16(input, n, output)
+    }
+    fn denc(input: &[u16], output: &mut [u8]) -> usize {
+        v8::denc16(input, output)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        v8::try_ddec16(input, n, output)
+    }
+    fn d1enc(input: &[u16], output: &mut [u8]) -> usize {
+        v8::d1enc16(input, output)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        v8::try_d1dec16(input, n, output)
+    }
+    fn zenc(input: &[u16], output: &mut [u8]) -> usize {
+        v8::zenc16(input, output)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        v8::try_zdec16(input, n, output)
+    }
+    fn xenc(input: &[u16], output: &mut [u8]) -> usize {
+        v8::xenc16(input, output)
+    }
+    fn try_xdec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        v8::try_xdec16(input, n, output)
+    }
+}
+
+impl V8Codec<W> for u32 {
+    fn max_compressed_size(n: usize) -> usize {
+        v8::bound32(n)
+    }
+    fn enc(input: &[u32], output: &mut [u8]) -> usize {
+        v8::enc32(input, output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        v8::try_dec32(input, n, output)
+    }
+    fn denc(input: &[u32], output: &mut [u8]) -> usize {
+        v8::denc32(input, output)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        v8::try_ddec32(input, n, output)
+    }
+    fn d1enc(input: &[u32], output: &mut [u8]) -> usize {
+        v8::d1enc32(input, output)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        v8::try_d1dec32(input, n, output)
+    }
+    fn zenc(input: &[u32], output: &mut [u8]) -> usize {
+        v8::zenc32(input, output)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        v8::try_zdec32(input, n, output)
+    }
+    fn xenc(input: &[u32], output: &mut [u8]) -> usize {
+        v8::xenc32(input, output)
+    }
+    fn try_xdec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        v8::try_xdec32(input, n, output)
+    }
+}
+
+impl V8Codec<W128v> for u16 {
+    fn max_compressed_size(n: usize) -> usize {
+        v8::bound16(n)
+    }
+    fn enc(input: &[u16], output: &mut [u8]) -> usize {
+        v8::enc128v16(input, output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        v8::try_dec128v16(input, n, output)
+    }
+    fn denc(input: &[u16], output: &mut [u8]) -> usize {
+        v8::denc128v16(input, output)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        v8::try_ddec128v16(input, n, output)
+    }
+    fn d1enc(input: &[u16], output: &mut [u8]) -> usize {
+        v8::d1enc128v16(input, output)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        v8::try_d1dec128v16(input, n, output)
+    }
+    fn zenc(input: &[u16], output: &mut [u8]) -> usize {
+        v8::zenc128v16(input, output)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        v8::try_zdec128v16(input, n, output)
+    }
+    fn xenc(input: &[u16], output: &mut [u8]) -> usize {
+        v8::xenc128v16(input, output)
+    }
+    fn try_xdec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        v8::try_xdec128v16(input, n, output)
+    }
+}
+
+impl V8Codec<W128v> for u32 {
+    fn max_compressed_size(n: usize) -> usize {
+        v8::bound32(n)
+    }
+    fn enc(input: &[u32], output: &mut [u8]) -> usize {
+        v8::enc128v32(input, output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        v8::try_dec128v32(input, n, output)
+    }
+    fn denc(input: &[u32], output: &mut [u8]) -> usize {
+        v8::denc128v32(input, output)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        v8::try_ddec128v32(input, n, output)
+    }
+    fn d1enc(input: &[u32], output: &mut [u8]) -> usize {
+        v8::d1enc128v32(input, output)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        v8::try_d1dec128v32(input, n, output)
+    }
+    fn zenc(input: &[u32], output: &mut [u8]) -> usize {
+        v8::zenc128v32(input, output)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        v8::try_zdec128v32(input, n, output)
+    }
+    fn xenc(input: &[u32], output: &mut [u8]) -> usize {
+        v8::xenc128v32(input, output)
+    }
+    fn try_xdec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        v8::try_xdec128v32(input, n, output)
+    }
+}
+
+impl V8Codec<W256v> for u32 {
+    fn max_compressed_size(n: usize) -> usize {
+        v8::bound32(n)
+    }
+    fn enc(input: &[u32], output: &mut [u8]) -> usize {
+        v8::enc256v32(input, output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        v8::try_dec256v32(input, n, output)
+    }
+    fn denc(input: &[u32], output: &mut [u8]) -> usize {
+        v8::denc256v32(input, output)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        v8::try_ddec256v32(input, n, output)
+    }
+    fn d1enc(input: &[u32], output: &mut [u8]) -> usize {
+        v8::d1enc256v32(input, output)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        v8::try_d1dec256v32(input, n, output)
+    }
+    fn zenc(input: &[u32], output: &mut [u8]) -> usize {
+        v8::zenc256v32(input, output)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        v8::try_zdec256v32(input, n, output)
+    }
+    fn xenc(input: &[u32], output: &mut [u8]) -> usize {
+        v8::xenc256v32(input, output)
+    }
+    fn try_xdec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        v8::try_xdec256v32(input, n, output)
+    }
+}
+
+/// The SimpleV codec `vsenc*`/`vsdec*` of vsimple.h, a simple family codec with run length encoding.
+/// The decoders need an output of at least `W::dec_buf_len(n)`.
+pub trait SimpleVCodec where Self: Sized {
+    /// Upper bound of the encoded size of `n` integers of type `Self` (`vsbound*`)
+    fn max_compressed_size(n: usize) -> usize;
+    /// SimpleV encoding for integer lists
+    fn enc(input: &[Self], output: &mut [u8]) -> usize;
+    /// SimpleV decoding into integer lists
+    fn dec(input: &[u8], n: usize, output: &mut [Self]) -> usize {
+        Self::try_dec(input, n, output).unwrap_or_else(|e| panic!("SimpleVCodec::dec: {}", e))
+    }
+    /// Checked version of `dec`
+    fn try_dec(input: &[u8], n: usize, output: &mut [Self]) -> Result<usize>;
+}
+
+impl SimpleVCodec for u8 {
+    fn max_compressed_size(n: usize) -> usize {
+        vs::bound8(n)
+    }
+    fn enc(input: &[u8], output: &mut [u8]) -> usize {
+        vs::enc8(input, output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u8]) -> Result<usize> {
+        vs::try_dec8(input, n, output)
+    }
+}
+
+impl SimpleVCodec for u16 {
+    fn max_compressed_size(n: usize) -> usize {
+        vs::bound16(n)
+    }
+    fn enc(input: &[u16], output: &mut [u8]) -> usize {
+        vs::enc16(input, output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        vs::try_dec16(input, n, output)
+    }
+}
+
+impl SimpleVCodec for u32 {
+    fn max_compressed_size(n: usize) -> usize {
+        vs::bound32(n)
+    }
+    fn enc(input: &[u32], output: &mut [u8]) -> usize {
+        vs::enc32(input, output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        vs::try_dec32(input, n, output)
+    }
+}
+
+impl SimpleVCodec for u64 {
+    fn max_compressed_size(n: usize) -> usize {
+        vs::bound64(n)
+    }
+    fn enc(input: &[u64], output: &mut [u8]) -> usize {
+        vs::enc64(input, output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u64]) -> Result<usize> {
+        vs::try_dec64(input, n, output)
+    }
+}
+
+/// The Elias-Fano codec `efano*` of eliasfano.h for sorted integer lists. `W` uses the scalar functions for `u32`
+/// and `u64`, `W128v` and `W256v` the SIMD variants for `u32`. The encoders panic if the input isn't sorted,
+/// the decoders need an output of at least `W::dec_buf_len(n)`.
+pub trait EliasFanoCodec<W: Width> where Self: Sized {
+    /// Upper bound of the encoded size of `n` integers of type `Self` (`ef::bound*`)
+    fn max_compressed_size(n: usize) -> usize;
+    /// Elias-Fano encoding for increasing integer lists (sorted w/ duplicate)
+    fn enc(input: &[Self], output: &mut [u8], start: Self) -> usize;
+    /// Elias-Fano decoding into increasing integer lists (sorted w/ duplicate)
+    fn dec(input: &[u8], n: usize, output: &mut [Self], start: Self) -> usize {
+        Self::try_dec(input, n, output, start).unwrap_or_else(|e| panic!("EliasFanoCodec::dec: {}", e))
+    }
+    /// Checked version of `dec`
+    fn try_dec(input: &[u8], n: usize, output: &mut [Self], start: Self) -> Result<usize>;
+    /// Elias-Fano encoding for strictly increasing integer lists (sorted unique)
+    fn d1enc(input: &[Self], output: &mut [u8], start: Self) -> usize;
+    /// Elias-Fano decoding into strictly increasing integer lists (sorted unique)
+    fn d1dec(input: &[u8], n: usize, output: &mut [Self], start: Self) -> usize {
+        Self::try_d1dec(input, n, output, start).unwrap_or_else(|e| panic!("EliasFanoCodec::d1dec: {}", e))
+    }
+    /// Checked version of `d1dec`
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [Self], start: Self) -> Result<usize>;
+}
+
+impl EliasFanoCodec<W> for u32 {
+    fn max_compressed_size(n: usize) -> usize {
+        ef::bound32(n)
+    }
+    fn enc(input: &[u32], output: &mut [u8], start: u32) -> usize {
+        ef::enc32(input, output, start)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u32], start: u32) -> Result<usize> {
+        ef::try_dec32(input, n, output, start)
+    }
+    fn d1enc(input: &[u32], output: &mut [u8], start: u32) -> usize {
+        ef::d1enc32(input, output, start)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [u32], start: u32) -> Result<usize> {
+        ef::try_d1dec32(input, n, output, start)
+    }
+}
+
+impl EliasFanoCodec<W> for u64 {
+    fn max_compressed_size(n: usize) -> usize {
+        ef::bound64(n)
+    }
+    fn enc(input: &[u64], output: &mut [u8], start: u64) -> usize {
+        ef::enc64(input, output, start)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u64], start: u64) -> Result<usize> {
+        ef::try_dec64(input, n, output, start)
+    }
+    fn d1enc(input: &[u64], output: &mut [u8], start: u64) -> usize {
+        ef::d1enc64(input, output, start)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [u64], start: u64) -> Result<usize> {
+        ef::try_d1dec64(input, n, output, start)
+    }
+}
+
+impl EliasFanoCodec<W128v> for u32 {
+    fn max_compressed_size(n: usize) -> usize {
+        ef::bound32(n)
+    }
+    fn enc(input: &[u32], output: &mut [u8], start: u32) -> usize {
+        ef::enc128v32(input, output, start)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u32], start: u32) -> Result<usize> {
+        ef::try_dec128v32(input, n, output, start)
+    }
+    fn d1enc(input: &[u32], output: &mut [u8], start: u32) -> usize {
+        ef::d1enc128v32(input, output, start)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [u32], start: u32) -> Result<usize> {
+        ef::try_d1dec128v32(input, n, output, start)
+    }
+}
+
+impl EliasFanoCodec<W256v> for u32 {
+    fn max_compressed_size(n: usize) -> usize {
+        ef::bound32(n)
+    }
+    fn enc(input: &[u32], output: &mut [u8], start: u32) -> usize {
+        ef::enc256v32(input, output, start)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u32], start: u32) -> Result<usize> {
+        ef::try_dec256v32(input, n, output, start)
+    }
+    fn d1enc(input: &[u32], output: &mut [u8], start: u32) -> usize {
+        ef::d1enc256v32(input, output, start)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [u32], start: u32) -> Result<usize> {
+        ef::try_d1dec256v32(input, n, output, start)
+    }
+}
+
+/// The TurboPFor codec on the zigzag of delta `p4nzz*128v*` of fp.h, i.e. delta of delta coding. It suits
+/// regularly sampled timestamps that are almost, but not strictly, increasing. Only `W128v` is available.
+/// The decoders need an output of at least `W128v::dec_buf_len(n)`.
+pub trait DeltaOfDeltaCodec<W: Width> where Self: Sized {
+    /// Upper bound of the encoded size of `n` integers of type `Self` (`p4nbound128v*`)
+    fn max_compressed_size(n: usize) -> usize;
+    /// TurboPFor encoding of the zigzag of delta, `start` is the predecessor of the first integer
+    fn enc(input: &[Self], output: &mut [u8], start: Self) -> usize;
+    /// TurboPFor decoding of the zigzag of delta, `start` as given to the encoder
+    fn dec(input: &[u8], n: usize, output: &mut [Self], start: Self) -> usize {
+        Self::try_dec(input, n, output, start).unwrap_or_else(|e| panic!("DeltaOfDeltaCodec::dec: {}", e))
+    }
+    /// Checked version of `dec`
+    fn try_dec(input: &[u8], n: usize, output: &mut [Self], start: Self) -> Result<usize>;
+}
+
+impl DeltaOfDeltaCodec<W128v> for u8 {
+    fn max_compressed_size(n: usize) -> usize {
+        bound128v8(n)
+    }
+    fn enc(input: &[u8], output: &mut [u8], start: u8) -> usize {
+        fp::nzzenc128v8(input, output, start)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u8], start: u8) -> Result<usize> {
+        fp::try_nzzdec128v8(input, n, output, start)
+    }
+}
+
+impl DeltaOfDeltaCodec<W128v> for u16 {
+    fn max_compressed_size(n: usize) -> usize {
+        bound128v16(n)
+    }
+    fn enc(input: &[u16], output: &mut [u8], start: u16) -> usize {
+        fp::nzzenc128v16(input, output, start)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u16], start: u16) -> Result<usize> {
+        fp::try_nzzdec128v16(input, n, output, start)
+    }
+}
+
+impl DeltaOfDeltaCodec<W128v> for u32 {
+    fn max_compressed_size(n: usize) -> usize {
+        bound128v32(n)
+    }
+    fn enc(input: &[u32], output: &mut [u8], start: u32) -> usize {
+        fp::nzzenc128v32(input, output, start)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u32], start: u32) -> Result<usize> {
+        fp::try_nzzdec128v32(input, n, output, start)
+    }
+}
+
+impl DeltaOfDeltaCodec<W128v> for u64 {
+    fn max_compressed_size(n: usize) -> usize {
+        bound128v64(n)
+    }
+    fn enc(input: &[u64], output: &mut [u8], start: u64) -> usize {
+        fp::nzzenc128v64(input, output, start)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u64], start: u64) -> Result<usize> {
+        fp::try_nzzdec128v64(input, n, output, start)
+    }
+}
+
+/// The bit/io codecs `bvzenc*` (zigzag of delta) and `bvzzenc*` (zigzag of delta of delta) of fp.h. They write
+/// a variable number of bits per integer and need no blocks, so slowly varying signals cost a few bits per value.
+/// The bounds `fp::bound*` hold for any input, see `enclen bitio`. The decoders need an output of at least
+/// `W::dec_buf_len(n)`.
+pub trait BitIoCodec where Self: Sized {
+    /// Upper bound of the encoded size of `n` integers of type `Self` (`fp::bound*`)
+    fn max_compressed_size(n: usize) -> usize;
+    /// Bit/io encoding of the zigzag of delta, `start` is the predecessor of the first integer
+    fn zenc(input: &[Self], output: &mut [u8], start: Self) -> usize;
+    /// Bit/io decoding of the zigzag of delta, `start` as given to the encoder
+    fn zdec(input: &[u8], n: usize, output: &mut [Self], start: Self) -> usize {
+        Self::try_zdec(input, n, output, start).unwrap_or_else(|e| panic!("BitIoCodec::zdec: {}", e))
+    }
+    /// Checked version of `zdec`
+    fn try_zdec(input: &[u8], n: usize, output: &mut [Self], start: Self) -> Result<usize>;
+    /// Bit/io encoding of the zigzag of delta of delta, `start` is the predecessor of the first integer
+    fn zzenc(input: &[Self], output: &mut [u8], start: Self) -> usize;
+    /// Bit/io decoding of the zigzag of delta of delta, `start` as given to the encoder
+    fn zzdec(input: &[u8], n: usize, output: &mut [Self], start: Self) -> usize {
+        Self::try_zzdec(input, n, output, start).unwrap_or_else(|e| panic!("BitIoCodec::zzdec: {}", e))
+    }
+    /// Checked version of `zzdec`
+    fn try_zzdec(input: &[u8], n: usize, output: &mut [Self], start: Self) -> Result<usize>;
+}
+
+impl BitIoCodec for u8 {
+    fn max_compressed_size(n: usize) -> usize {
+        fp::bound8(n)
+    }
+    fn zenc(input: &[u8], output: &mut [u8], start: u8) -> usize {
+        fp::bvzenc8(input, output, start)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [u8], start: u8) -> Result<usize> {
+        fp::try_bvzdec8(input, n, output, start)
+    }
+    fn zzenc(input: &[u8], output: &mut [u8], start: u8) -> usize {
+        fp::bvzzenc8(input, output, start)
+    }
+    fn try_zzdec(input: &[u8], n: usize, output: &mut [u8], start: u8) -> Result<usize> {
+        fp::try_bvzzdec8(input, n, output, start)
+    }
+}
+
+impl BitIoCodec for u16 {
+    fn max_compressed_size(n: usize) -> usize {
+        fp::bound16(n)
+    }
+    fn zenc(input: &[u16], output: &mut [u8], start: u16) -> usize {
+        fp::bvzenc16(input, output, start)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [u16], start: u16) -> Result<usize> {
+        fp::try_bvzdec16(input, n, output, start)
+    }
+    fn zzenc(input: &[u16], output: &mut [u8], start: u16) -> usize {
+        fp::bvzzenc16(input, output, start)
+    }
+    fn try_zzdec(input: &[u8], n: usize, output: &mut [u16], start: u16) -> Result<usize> {
+        fp::try_bvzzdec16(input, n, output, start)
+    }
+}
+
+impl BitIoCodec for u32 {
+    fn max_compressed_size(n: usize) -> usize {
+        fp::bound32(n)
+    }
+    fn zenc(input: &[u32], output: &mut [u8], start: u32) -> usize {
+        fp::bvzenc32(input, output, start)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [u32], start: u32) -> Result<usize> {
+        fp::try_bvzdec32(input, n, output, start)
+    }
+    fn zzenc(input: &[u32], output: &mut [u8], start: u32) -> usize {
+        fp::bvzzenc32(input, output, start)
+    }
+    fn try_zzdec(input: &[u8], n: usize, output: &mut [u32], start: u32) -> Result<usize> {
+        fp::try_bvzzdec32(input, n, output, start)
+    }
+}
+
+impl BitIoCodec for u64 {
+    fn max_compressed_size(n: usize) -> usize {
+        fp::bound64(n)
+    }
+    fn zenc(input: &[u64], output: &mut [u8], start: u64) -> usize {
+        fp::bvzenc64(input, output, start)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [u64], start: u64) -> Result<usize> {
+        fp::try_bvzdec64(input, n, output, start)
+    }
+    fn zzenc(input: &[u64], output: &mut [u8], start: u64) -> usize {
+        fp::bvzzenc64(input, output, start)
+    }
+    fn try_zzdec(input: &[u8], n: usize, output: &mut [u64], start: u64) -> Result<usize> {
+        fp::try_bvzzdec64(input, n, output, start)
+    }
+}
+
+/// The XOR bit packing `bitnxpack*`/`bitnxunpack*` of bitpack.h for unsorted integer lists whose neighbours share
+/// their high bits, e.g. hashes and bit flags. `W` uses the scalar functions for `u8` to `u64`, `W128v` the SIMD ones
+/// for `u16`/`u32` and `W256v` for `u32`. The decoders need an output of at least `W::dec_buf_len(n)`.
+pub trait XorCodec<W: Width> where Self: Sized {
+    /// Upper bound of the encoded size of `n` integers of type `Self` (`bitnbound*`)
+    fn max_compressed_size(n: usize) -> usize;
+    /// Bit packing of the XOR of neighbours
+    fn xenc(input: &[Self], output: &mut [u8]) -> usize;
+    /// Bit unpacking of the XOR of neighbours
+    fn xdec(input: &[u8], n: usize, output: &mut [Self]) -> usize {
+        Self::try_xdec(input, n, output).unwrap_or_else(|e| panic!("XorCodec::xdec: {}", e))
+    }
+    /// Checked version of `xdec`
+    fn try_xdec(input: &[u8], n: usize, output: &mut [Self]) -> Result<usize>;
+}
+
+impl XorCodec<W> for u8 {
+    fn max_compressed_size(n: usize) -> usize {
+        bit::bound8(n)
+    }
+    fn xenc(input: &[u8], output: &mut [u8]) -> usize {
+        bit::xpack8(input, output)
+    }
+    fn try_xdec(input: &[u8], n: usize, output: &mut [u8]) -> Result<usize> {
+        bit::try_xunpack8(input, n, output)
+    }
+}
+
+impl XorCodec<W> for u16 {
+    fn max_compressed_size(n: usize) -> usize {
+        bit::bound16(n)
+    }
+    fn xenc(input: &[u16], output: &mut [u8]) -> usize {
+        bit::xpack16(input, output)
+    }
+    fn try_xdec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        bit::try_xunpack16(input, n, output)
+    }
+}
+
+impl XorCodec<W> for u32 {
+    fn max_compressed_size(n: usize) -> usize {
+        bit::bound32(n)
+    }
+    fn xenc(input: &[u32], output: &mut [u8]) -> usize {
+        bit::xpack32(input, output)
+    }
+    fn try_xdec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        bit::try_xunpack32(input, n, output)
+    }
+}
+
+impl XorCodec<W> for u64 {
+    fn max_compressed_size(n: usize) -> usize {
+        bit::bound64(n)
+    }
+    fn xenc(input: &[u64], output: &mut [u8]) -> usize {
+        bit::xpack64(input, output)
+    }
+    fn try_xdec(input: &[u8], n: usize, output: &mut [u64]) -> Result<usize> {
+        bit::try_xunpack64(input, n, output)
+    }
+}
+
+impl XorCodec<W128v> for u16 {
+    fn max_compressed_size(n: usize) -> usize {
+        bit::bound128v16(n)
+    }
+    fn xenc(input: &[u16], output: &mut [u8]) -> usize {
+        bit::xpack128v16(input, output)
+    }
+    fn try_xdec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        bit::try_xunpack128v16(input, n, output)
+    }
+}
+
+impl XorCodec<W128v> for u32 {
+    fn max_compressed_size(n: usize) -> usize {
+        bit::bound128v32(n)
+    }
+    fn xenc(input: &[u32], output: &mut [u8]) -> usize {
+        bit::xpack128v32(input, output)
+    }
+    fn try_xdec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        bit::try_xunpack128v32(input, n, output)
+    }
+}
+
+impl XorCodec<W256v> for u32 {
+    fn max_compressed_size(n: usize) -> usize {
+        bit::bound256v32(n)
+    }
+    fn xenc(input: &[u32], output: &mut [u8]) -> usize {
+        bit::xpack256v32(input, output)
+    }
+    fn try_xdec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        bit::try_xunpack256v32(input, n, output)
+    }
+}
+
+/// Bit packing without exceptions, `bitnpack*`/`bitnunpack*` of bitpack.h. Every block of 128 integers is packed
+/// with the bit width of its largest integer, which decodes faster than PFor and loses little on uniform data.
+/// `W` uses the scalar functions for `u8` to `u64`, `W128v` the SIMD ones for `u16` to `u64` and `W256v` for `u32`.
+/// The decoders need an output of at least `W::dec_buf_len(n)`.
+pub trait BitCodec<W: Width> where Self: Sized {
+    /// Upper bound of the encoded size of `n` integers of type `Self` (`bitnbound*`)
+    fn max_compressed_size(n: usize) -> usize;
+    /// Bit packing for unsorted integer lists
+    fn enc(input: &[Self], output: &mut [u8]) -> usize;
+    /// Bit unpacking for unsorted integer lists
+    fn dec(input: &[u8], n: usize, output: &mut [Self]) -> usize {
+        Self::try_dec(input, n, output).unwrap_or_else(|e| panic!("BitCodec::dec: {}", e))
+    }
+    /// Checked version of `dec`
+    fn try_dec(input: &[u8], n: usize, output: &mut [Self]) -> Result<usize>;
+}
+
+/// The transforming bit packing variants `bitnd*`, `bitnd1*`, `bitnf*` and `bitnz*` of bitpack.h. They exist for
+/// the same combinations as `BitCodec` except `u64` with `W128v`.
+pub trait BitDeltaCodec<W: Width>: BitCodec<W> {
+    /// Bit packing delta for increasing integer lists (sorted w/ duplicate)
+    fn denc(input: &[Self], output: &mut [u8]) -> usize;
+    /// Bit unpacking delta for increasing integer lists (sorted w/ duplicate)
+    fn ddec(input: &[u8], n: usize, output: &mut [Self]) -> usize {
+        Self::try_ddec(input, n, output).unwrap_or_else(|e| panic!("BitDeltaCodec::ddec: {}", e))
+    }
+    /// Checked version of `ddec`
+    fn try_ddec(input: &[u8], n: usize, output: &mut [Self]) -> Result<usize>;
+    /// Bit packing delta for strictly increasing integer lists (sorted unique)
+    fn d1enc(input: &[Self], output: &mut [u8]) -> usize;
+    /// Bit unpacking delta for strictly increasing integer lists (sorted unique)
+    fn d1dec(input: &[u8], n: usize, output: &mut [Self]) -> usize {
+        Self::try_d1dec(input, n, output).unwrap_or_else(|e| panic!("BitDeltaCodec::d1dec: {}", e))
+    }
+    /// Checked version of `d1dec`
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [Self]) -> Result<usize>;
+    /// Bit packing frame of reference for sorted integer lists
+    fn fenc(input: &[Self], output: &mut [u8]) -> usize;
+    /// Bit unpacking frame of reference for sorted integer lists
+    fn fdec(input: &[u8], n: usize, output: &mut [Self]) -> usize {
+        Self::try_fdec(input, n, output).unwrap_or_else(|e| panic!("BitDeltaCodec::fdec: {}", e))
+    }
+    /// Checked version of `fdec`
+    fn try_fdec(input: &[u8], n: usize, output: &mut [Self]) -> Result<usize>;
+    /// Bit packing ZigZag for unsorted integer lists
+    fn zenc(input: &[Self], output: &mut [u8]) -> usize;
+    /// Bit unpacking ZigZag for unsorted integer lists
+    fn zdec(input: &[u8], n: usize, output: &mut [Self]) -> usize {
+        Self::try_zdec(input, n, output).unwrap_or_else(|e| panic!("BitDeltaCodec::zdec: {}", e))
+    }
+    /// Checked version of `zdec`
+    fn try_zdec(input: &[u8], n: usize, output: &mut [Self]) -> Result<usize>;
+}
+
+impl BitCodec<W> for u8 {
+    fn max_compressed_size(n: usize) -> usize {
+        bit::bound8(n)
+    }
+    fn enc(input: &[u8], output: &mut [u8]) -> usize {
+        bit::pack8(input, output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u8]) -> Result<usize> {
+        bit::try_unpack8(input, n, output)
+    }
+}
+
+impl BitCodec<W> for u16 {
+    fn max_compressed_size(n: usize) -> usize {
+        bit::bound16(n)
+    }
+    fn enc(input: &[u16], output: &mut [u8]) -> usize {
+        bit::pack16(input, output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        bit::try_unpack16(input, n, output)
+    }
+}
+
+impl BitCodec<W> for u32 {
+    fn max_compressed_size(n: usize) -> usize {
+        bit::bound32(n)
+    }
+    fn enc(input: &[u32], output: &mut [u8]) -> usize {
+        bit::pack32(input, output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        bit::try_unpack32(input, n, output)
+    }
+}
+
+impl BitCodec<W> for u64 {
+    fn max_compressed_size(n: usize) -> usize {
+        bit::bound64(n)
+    }
+    fn enc(input: &[u64], output: &mut [u8]) -> usize {
+        bit::pack64(input, output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u64]) -> Result<usize> {
+        bit::try_unpack64(input, n, output)
+    }
+}
+
+impl BitCodec<W128v> for u16 {
+    fn max_compressed_size(n: usize) -> usize {
+        bit::bound128v16(n)
+    }
+    fn enc(input: &[u16], output: &mut [u8]) -> usize {
+        bit::pack128v16(input, output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        bit::try_unpack128v16(input, n, output)
+    }
+}
+
+impl BitCodec<W128v> for u32 {
+    fn max_compressed_size(n: usize) -> usize {
+        bit::bound128v32(n)
+    }
+    fn enc(input: &[u32], output: &mut [u8]) -> usize {
+        bit::pack128v32(input, output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        bit::try_unpack128v32(input, n, output)
+    }
+}
+
+impl BitCodec<W128v> for u64 {
+    fn max_compressed_size(n: usize) -> usize {
+        bit::bound128v64(n)
+    }
+    fn enc(input: &[u64], output: &mut [u8]) -> usize {
+        bit::pack128v64(input, output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u64]) -> Result<usize> {
+        bit::try_unpack128v64(input, n, output)
+    }
+}
+
+impl BitCodec<W256v> for u32 {
+    fn max_compressed_size(n: usize) -> usize {
+        bit::bound256v32(n)
+    }
+    fn enc(input: &[u32], output: &mut [u8]) -> usize {
+        bit::pack256v32(input, output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        bit::try_unpack256v32(input, n, output)
+    }
+}
+
+impl BitDeltaCodec<W> for u8 {
+    fn denc(input: &[u8], output: &mut [u8]) -> usize {
+        bit::dpack8(input, output)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [u8]) -> Result<usize> {
+        bit::try_dunpack8(input, n, output)
+    }
+    fn d1enc(input: &[u8], output: &mut [u8]) -> usize {
+        bit::d1pack8(input, output)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [u8]) -> Result<usize> {
+        bit::try_d1unpack8(input, n, output)
+    }
+    fn fenc(input: &[u8], output: &mut [u8]) -> usize {
+        bit::fpack8(input, output)
+    }
+    fn try_fdec(input: &[u8], n: usize, output: &mut [u8]) -> Result<usize> {
+        bit::try_funpack8(input, n, output)
+    }
+    fn zenc(input: &[u8], output: &mut [u8]) -> usize {
+        bit::zpack8(input, output)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [u8]) -> Result<usize> {
+        bit::try_zunpack8(input, n, output)
+    }
+}
+
+impl BitDeltaCodec<W> for u16 {
+    fn denc(input: &[u16], output: &mut [u8]) -> usize {
+        bit::dpack16(input, output)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        bit::try_dunpack16(input, n, output)
+    }
+    fn d1enc(input: &[u16], output: &mut [u8]) -> usize {
+        bit::d1pack16(input, output)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        bit::try_d1unpack16(input, n, output)
+    }
+    fn fenc(input: &[u16], output: &mut [u8]) -> usize {
+        bit::fpack16(input, output)
+    }
+    fn try_fdec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        bit::try_funpack16(input, n, output)
+    }
+    fn zenc(input: &[u16], output: &mut [u8]) -> usize {
+        bit::zpack16(input, output)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        bit::try_zunpack16(input, n, output)
+    }
+}
+
+impl BitDeltaCodec<W> for u32 {
+    fn denc(input: &[u32], output: &mut [u8]) -> usize {
+        bit::dpack32(input, output)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        bit::try_dunpack32(input, n, output)
+    }
+    fn d1enc(input: &[u32], output: &mut [u8]) -> usize {
+        bit::d1pack32(input, output)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        bit::try_d1unpack32(input, n, output)
+    }
+    fn fenc(input: &[u32], output: &mut [u8]) -> usize {
+        bit::fpack32(input, output)
+    }
+    fn try_fdec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        bit::try_funpack32(input, n, output)
+    }
+    fn zenc(input: &[u32], output: &mut [u8]) -> usize {
+        bit::zpack32(input, output)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        bit::try_zunpack32(input, n, output)
+    }
+}
+
+impl BitDeltaCodec<W> for u64 {
+    fn denc(input: &[u64], output: &mut [u8]) -> usize {
+        bit::dpack64(input, output)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [u64]) -> Result<usize> {
+        bit::try_dunpack64(input, n, output)
+    }
+    fn d1enc(input: &[u64], output: &mut [u8]) -> usize {
+        bit::d1pack64(input, output)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [u64]) -> Result<usize> {
+        bit::try_d1unpack64(input, n, output)
+    }
+    fn fenc(input: &[u64], output: &mut [u8]) -> usize {
+        bit::fpack64(input, output)
+    }
+    fn try_fdec(input: &[u8], n: usize, output: &mut [u64]) -> Result<usize> {
+        bit::try_funpack64(input, n, output)
+    }
+    fn zenc(input: &[u64], output: &mut [u8]) -> usize {
+        bit::zpack64(input, output)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [u64]) -> Result<usize> {
+        bit::try_zunpack64(input, n, output)
+    }
+}
+
+impl BitDeltaCodec<W128v> for u16 {
+    fn denc(input: &[u16], output: &mut [u8]) -> usize {
+        bit::dpack128v16(input, output)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        bit::try_dunpack128v16(input, n, output)
+    }
+    fn d1enc(input: &[u16], output: &mut [u8]) -> usize {
+        bit::d1pack128v16(input, output)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        bit::try_d1unpack128v16(input, n, output)
+    }
+    fn fenc(input: &[u16], output: &mut [u8]) -> usize {
+        bit::fpack128v16(input, output)
+    }
+    fn try_fdec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        bit::try_funpack128v16(input, n, output)
+    }
+    fn zenc(input: &[u16], output: &mut [u8]) -> usize {
+        bit::zpack128v16(input, output)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [u16]) -> Result<usize> {
+        bit::try_zunpack128v16(input, n, output)
+    }
+}
+
+impl BitDeltaCodec<W128v> for u32 {
+    fn denc(input: &[u32], output: &mut [u8]) -> usize {
+        bit::dpack128v32(input, output)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        bit::try_dunpack128v32(input, n, output)
+    }
+    fn d1enc(input: &[u32], output: &mut [u8]) -> usize {
+        bit::d1pack128v32(input, output)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        bit::try_d1unpack128v32(input, n, output)
+    }
+    fn fenc(input: &[u32], output: &mut [u8]) -> usize {
+        bit::fpack128v32(input, output)
+    }
+    fn try_fdec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        bit::try_funpack128v32(input, n, output)
+    }
+    fn zenc(input: &[u32], output: &mut [u8]) -> usize {
+        bit::zpack128v32(input, output)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        bit::try_zunpack128v32(input, n, output)
+    }
+}
+
+impl BitDeltaCodec<W256v> for u32 {
+    fn denc(input: &[u32], output: &mut [u8]) -> usize {
+        bit::dpack256v32(input, output)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        bit::try_dunpack256v32(input, n, output)
+    }
+    fn d1enc(input: &[u32], output: &mut [u8]) -> usize {
+        bit::d1pack256v32(input, output)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        bit::try_d1unpack256v32(input, n, output)
+    }
+    fn fenc(input: &[u32], output: &mut [u8]) -> usize {
+        bit::fpack256v32(input, output)
+    }
+    fn try_fdec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        bit::try_funpack256v32(input, n, output)
+    }
+    fn zenc(input: &[u32], output: &mut [u8]) -> usize {
+        bit::zpack256v32(input, output)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [u32]) -> Result<usize> {
+        bit::try_zunpack256v32(input, n, output)
+    }
+}
+
+/// The signed integer types. They are stored as the unsigned type of the same size: `Codec::enc` and `BitCodec::enc`
+/// pack the zigzag of the integers, so that small negative integers stay small, the delta and zigzag (of delta)
+/// codecs work on the two's complement directly, because the wrapping differences are the same.
+/// `enc` zigzags the whole input into a temporary `Vec` for the C call, the block functions (`enc_block`,
+/// `block_bits`, `enc_block_bits`) into a block on the stack.
+pub trait SignedInteger: Copy {
+    /// The unsigned type of the same size
+    type Unsigned: Copy + Default;
+    /// The two's complement as unsigned integer
+    fn to_unsigned(self) -> Self::Unsigned;
+    /// ZigZag encode, 0, -1, 1, -2, ... map to 0, 1, 2, 3, ...
+    fn zigzag(self) -> Self::Unsigned;
+    /// Inverse of `zigzag`
+    fn unzigzag(x: Self::Unsigned) -> Self;
+}
+
+impl SignedInteger for i8 {
+    type Unsigned = u8;
+    fn to_unsigned(self) -> u8 {
+        self as u8
+    }
+    fn zigzag(self) -> u8 {
+        ((self << 1) ^ (self >> 7)) as u8
+    }
+    fn unzigzag(x: u8) -> i8 {
+        ((x >> 1) as i8) ^ -((x & 1) as i8)
+    }
+}
+
+impl SignedInteger for i16 {
+    type Unsigned = u16;
+    fn to_unsigned(self) -> u16 {
+        self as u16
+    }
+    fn zigzag(self) -> u16 {
+        ((self << 1) ^ (self >> 15)) as u16
+    }
+    fn unzigzag(x: u16) -> i16 {
+        ((x >> 1) as i16) ^ -((x & 1) as i16)
+    }
+}
+
+impl SignedInteger for i32 {
+    type Unsigned = u32;
+    fn to_unsigned(self) -> u32 {
+        self as u32
+    }
+    fn zigzag(self) -> u32 {
+        ((self << 1) ^ (self >> 31)) as u32
+    }
+    fn unzigzag(x: u32) -> i32 {
+        ((x >> 1) as i32) ^ -((x & 1) as i32)
+    }
+}
+
+impl SignedInteger for i64 {
+    type Unsigned = u64;
+    fn to_unsigned(self) -> u64 {
+        self as u64
+    }
+    fn zigzag(self) -> u64 {
+        ((self << 1) ^ (self >> 63)) as u64
+    }
+    fn unzigzag(x: u64) -> i64 {
+        ((x >> 1) as i64) ^ -((x & 1) as i64)
+    }
+}
+
+fn as_unsigned<S: SignedInteger>(input: &[S]) -> &[S::Unsigned] {
+    // SAFETY: the signed and unsigned integer types of the same size have the same size and alignment
+    unsafe { std::slice::from_raw_parts(input.as_ptr() as *const S::Unsigned, input.len()) }
+}
+
+fn as_unsigned_mut<S: SignedInteger>(output: &mut [S]) -> &mut [S::Unsigned] {
+    // SAFETY: see `as_unsigned`
+    unsafe { std::slice::from_raw_parts_mut(output.as_mut_ptr() as *mut S::Unsigned, output.len()) }
+}
+
+/// The zigzag of `input` in a new `Vec`: the stream encoders hand the whole list to a single C call
+fn zigzag<S: SignedInteger>(input: &[S]) -> Vec<S::Unsigned> {
+    input.iter().map(|x| x.zigzag()).collect()
+}
+
+/// Call `f` with the zigzag of the block `input`, on the stack unless it's longer than a block of any width
+fn with_zigzag<S: SignedInteger, R>(input: &[S], f: impl FnOnce(&[S::Unsigned]) -> R) -> R {
+    if input.len() > W256v::BLOCK_LEN {
+        return f(&zigzag(input));
+    }
+    let mut block = [S::Unsigned::default(); W256v::BLOCK_LEN];
+    for (z, x) in block.iter_mut().zip(input) {
+        *z = x.zigzag();
+    }
+    f(&block[..input.len()])
+}
+
+/// Turn the first `n` decoded zigzag integers in `output` back into signed ones
+fn unzigzag<S: SignedInteger>(output: &mut [S], n: usize) {
+    for x in &mut output[..n] {
+        *x = S::unzigzag(x.to_unsigned());
+    }
+}
+
+impl Codec<W> for i8 {
+    fn max_compressed_size(n: usize) -> usize {
+        <u8 as Codec<W>>::max_compressed_size(n)
+    }
+    fn enc(input: &[i8], output: &mut [u8]) -> usize {
+        <u8 as Codec<W>>::enc(&zigzag(input), output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [i8]) -> Result<usize> {
+        let size = <u8 as Codec<W>>::try_dec(input, n, as_unsigned_mut(output))?;
+        unzigzag(output, n);
+        Ok(size)
+    }
+    fn dec_block(input: &[u8], n: usize, output: &mut [i8]) -> usize {
+        let size = <u8 as Codec<W>>::dec_block(input, n, as_unsigned_mut(output));
+        unzigzag(output, n);
+        size
+    }
+    fn enc_block(input: &[i8], output: &mut [u8]) -> usize {
+        with_zigzag(input, |input| <u8 as Codec<W>>::enc_block(input, output))
+    }
+    fn block_bits(input: &[i8]) -> (u32, u32) {
+        with_zigzag(input, <u8 as Codec<W>>::block_bits)
+    }
+    fn enc_block_bits(input: &[i8], output: &mut [u8], b: u32, bx: u32) -> usize {
+        with_zigzag(input, |input| <u8 as Codec<W>>::enc_block_bits(input, output, b, bx))
+    }
+    fn dec_block_bits(input: &[u8], n: usize, output: &mut [i8], b: u32, bx: u32) -> usize {
+        let size = <u8 as Codec<W>>::dec_block_bits(input, n, as_unsigned_mut(output), b, bx);
+        unzigzag(output, n);
+        size
+    }
+}
+
+impl DeltaCodec<W> for i8 {
+    fn denc(input: &[i8], output: &mut [u8]) -> usize {
+        <u8 as DeltaCodec<W>>::denc(as_unsigned(input), output)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [i8]) -> Result<usize> {
+        <u8 as DeltaCodec<W>>::try_ddec(input, n, as_unsigned_mut(output))
+    }
+    fn d1enc(input: &[i8], output: &mut [u8]) -> usize {
+        <u8 as DeltaCodec<W>>::d1enc(as_unsigned(input), output)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [i8]) -> Result<usize> {
+        <u8 as DeltaCodec<W>>::try_d1dec(input, n, as_unsigned_mut(output))
+    }
+    fn zenc(input: &[i8], output: &mut [u8]) -> usize {
+        <u8 as DeltaCodec<W>>::zenc(as_unsigned(input), output)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [i8]) -> Result<usize> {
+        <u8 as DeltaCodec<W>>::try_zdec(input, n, as_unsigned_mut(output))
+    }
+    fn ddec_block(input: &[u8], n: usize, output: &mut [i8], start: i8) -> usize {
+        <u8 as DeltaCodec<W>>::ddec_block(input, n, as_unsigned_mut(output), start as u8)
+    }
+    fn d1dec_block(input: &[u8], n: usize, output: &mut [i8], start: i8) -> usize {
+        <u8 as DeltaCodec<W>>::d1dec_block(input, n, as_unsigned_mut(output), start as u8)
+    }
+    fn zdec_block(input: &[u8], n: usize, output: &mut [i8], start: i8) -> usize {
+        <u8 as DeltaCodec<W>>::zdec_block(input, n, as_unsigned_mut(output), start as u8)
+    }
+    fn denc_block(input: &[i8], output: &mut [u8], start: i8) -> usize {
+        <u8 as DeltaCodec<W>>::denc_block(as_unsigned(input), output, start as u8)
+    }
+    fn d1enc_block(input: &[i8], output: &mut [u8], start: i8) -> usize {
+        <u8 as DeltaCodec<W>>::d1enc_block(as_unsigned(input), output, start as u8)
+    }
+    fn zenc_block(input: &[i8], output: &mut [u8], start: i8) -> usize {
+        <u8 as DeltaCodec<W>>::zenc_block(as_unsigned(input), output, start as u8)
+    }
+    fn ddec_block_bits(input: &[u8], n: usize, output: &mut [i8], start: i8, b: u32, bx: u32) -> usize {
+        <u8 as DeltaCodec<W>>::ddec_block_bits(input, n, as_unsigned_mut(output), start as u8, b, bx)
+    }
+    fn d1dec_block_bits(input: &[u8], n: usize, output: &mut [i8], start: i8, b: u32, bx: u32) -> usize {
+        <u8 as DeltaCodec<W>>::d1dec_block_bits(input, n, as_unsigned_mut(output), start as u8, b, bx)
+    }
+    fn zdec_block_bits(input: &[u8], n: usize, output: &mut [i8], start: i8, b: u32, bx: u32) -> usize {
+        <u8 as DeltaCodec<W>>::zdec_block_bits(input, n, as_unsigned_mut(output), start as u8, b, bx)
+    }
+    fn delta_block_bits(input: &[i8]) -> (u32, u32) {
+        <u8 as Codec<W>>::block_bits(as_unsigned(input))
+    }
+    fn enc_delta_block_bits(input: &[i8], output: &mut [u8], b: u32, bx: u32) -> usize {
+        <u8 as Codec<W>>::enc_block_bits(as_unsigned(input), output, b, bx)
+    }
+}
+
+impl Codec<W> for i16 {
+    fn max_compressed_size(n: usize) -> usize {
+        <u16 as Codec<W>>::max_compressed_size(n)
+    }
+    fn enc(input: &[i16], output: &mut [u8]) -> usize {
+        <u16 as Codec<W>>::enc(&zigzag(input), output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [i16]) -> Result<usize> {
+        let size = <u16 as Codec<W>>::try_dec(input, n, as_unsigned_mut(output))?;
+        unzigzag(output, n);
+        Ok(size)
+    }
+    fn dec_block(input: &[u8], n: usize, output: &mut [i16]) -> usize {
+        let size = <u16 as Codec<W>>::dec_block(input, n, as_unsigned_mut(output));
+        unzigzag(output, n);
+        size
+    }
+    fn enc_block(input: &[i16], output: &mut [u8]) -> usize {
+        with_zigzag(input, |input| <u16 as Codec<W>>::enc_block(input, output))
+    }
+    fn block_bits(input: &[i16]) -> (u32, u32) {
+        with_zigzag(input, <u16 as Codec<W>>::block_bits)
+    }
+    fn enc_block_bits(input: &[i16], output: &mut [u8], b: u32, bx: u32) -> usize {
+        with_zigzag(input, |input| <u16 as Codec<W>>::enc_block_bits(input, output, b, bx))
+    }
+    fn dec_block_bits(input: &[u8], n: usize, output: &mut [i16], b: u32, bx: u32) -> usize {
+        let size = <u16 as Codec<W>>::dec_block_bits(input, n, as_unsigned_mut(output), b, bx);
+        unzigzag(output, n);
+        size
+    }
+}
+
+impl DeltaCodec<W> for i16 {
+    fn denc(input: &[i16], output: &mut [u8]) -> usize {
+        <u16 as DeltaCodec<W>>::denc(as_unsigned(input), output)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [i16]) -> Result<usize> {
+        <u16 as DeltaCodec<W>>::try_ddec(input, n, as_unsigned_mut(output))
+    }
+    fn d1enc(input: &[i16], output: &mut [u8]) -> usize {
+        <u16 as DeltaCodec<W>>::d1enc(as_unsigned(input), output)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [i16]) -> Result<usize> {
+        <u16 as DeltaCodec<W>>::try_d1dec(input, n, as_unsigned_mut(output))
+    }
+    fn zenc(input: &[i16], output: &mut [u8]) -> usize {
+        <u16 as DeltaCodec<W>>::zenc(as_unsigned(input), output)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [i16]) -> Result<usize> {
+        <u16 as DeltaCodec<W>>::try_zdec(input, n, as_unsigned_mut(output))
+    }
+    fn ddec_block(input: &[u8], n: usize, output: &mut [i16], start: i16) -> usize {
+        <u16 as DeltaCodec<W>>::ddec_block(input, n, as_unsigned_mut(output), start as u16)
+    }
+    fn d1dec_block(input: &[u8], n: usize, output: &mut [i16], start: i16) -> usize {
+        <u16 as DeltaCodec<W>>::d1dec_block(input, n, as_unsigned_mut(output), start as u16)
+    }
+    fn zdec_block(input: &[u8], n: usize, output: &mut [i16], start: i16) -> usize {
+        <u16 as DeltaCodec<W>>::zdec_block(input, n, as_unsigned_mut(output), start as u16)
+    }
+    fn denc_block(input: &[i16], output: &mut [u8], start: i16) -> usize {
+        <u16 as DeltaCodec<W>>::denc_block(as_unsigned(input), output, start as u16)
+    }
+    fn d1enc_block(input: &[i16], output: &mut [u8], start: i16) -> usize {
+        <u16 as DeltaCodec<W>>::d1enc_block(as_unsigned(input), output, start as u16)
+    }
+    fn zenc_block(input: &[i16], output: &mut [u8], start: i16) -> usize {
+        <u16 as DeltaCodec<W>>::zenc_block(as_unsigned(input), output, start as u16)
+    }
+    fn ddec_block_bits(input: &[u8], n: usize, output: &mut [i16], start: i16, b: u32, bx: u32) -> usize {
+        <u16 as DeltaCodec<W>>::ddec_block_bits(input, n, as_unsigned_mut(output), start as u16, b, bx)
+    }
+    fn d1dec_block_bits(input: &[u8], n: usize, output: &mut [i16], start: i16, b: u32, bx: u32) -> usize {
+        <u16 as DeltaCodec<W>>::d1dec_block_bits(input, n, as_unsigned_mut(output), start as u16, b, bx)
+    }
+    fn zdec_block_bits(input: &[u8], n: usize, output: &mut [i16], start: i16, b: u32, bx: u32) -> usize {
+        <u16 as DeltaCodec<W>>::zdec_block_bits(input, n, as_unsigned_mut(output), start as u16, b, bx)
+    }
+    fn delta_block_bits(input: &[i16]) -> (u32, u32) {
+        <u16 as Codec<W>>::block_bits(as_unsigned(input))
+    }
+    fn enc_delta_block_bits(input: &[i16], output: &mut [u8], b: u32, bx: u32) -> usize {
+        <u16 as Codec<W>>::enc_block_bits(as_unsigned(input), output, b, bx)
+    }
+}
+
+impl Codec<W> for i32 {
+    fn max_compressed_size(n: usize) -> usize {
+        <u32 as Codec<W>>::max_compressed_size(n)
+    }
+    fn enc(input: &[i32], output: &mut [u8]) -> usize {
+        <u32 as Codec<W>>::enc(&zigzag(input), output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [i32]) -> Result<usize> {
+        let size = <u32 as Codec<W>>::try_dec(input, n, as_unsigned_mut(output))?;
+        unzigzag(output, n);
+        Ok(size)
+    }
+    fn dec_block(input: &[u8], n: usize, output: &mut [i32]) -> usize {
+        let size = <u32 as Codec<W>>::dec_block(input, n, as_unsigned_mut(output));
+        unzigzag(output, n);
+        size
+    }
+    fn enc_block(input: &[i32], output: &mut [u8]) -> usize {
+        with_zigzag(input, |input| <u32 as Codec<W>>::enc_block(input, output))
+    }
+    fn block_bits(input: &[i32]) -> (u32, u32) {
+        with_zigzag(input, <u32 as Codec<W>>::block_bits)
+    }
+    fn enc_block_bits(input: &[i32], output: &mut [u8], b: u32, bx: u32) -> usize {
+        with_zigzag(input, |input| <u32 as Codec<W>>::enc_block_bits(input, output, b, bx))
+    }
+    fn dec_block_bits(input: &[u8], n: usize, output: &mut [i32], b: u32, bx: u32) -> usize {
+        let size = <u32 as Codec<W>>::dec_block_bits(input, n, as_unsigned_mut(output), b, bx);
+        unzigzag(output, n);
+        size
+    }
+}
+
+impl DeltaCodec<W> for i32 {
+    fn denc(input: &[i32], output: &mut [u8]) -> usize {
+        <u32 as DeltaCodec<W>>::denc(as_unsigned(input), output)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [i32]) -> Result<usize> {
+        <u32 as DeltaCodec<W>>::try_ddec(input, n, as_unsigned_mut(output))
+    }
+    fn d1enc(input: &[i32], output: &mut [u8]) -> usize {
+        <u32 as DeltaCodec<W>>::d1enc(as_unsigned(input), output)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [i32]) -> Result<usize> {
+        <u32 as DeltaCodec<W>>::try_d1dec(input, n, as_unsigned_mut(output))
+    }
+    fn zenc(input: &[i32], output: &mut [u8]) -> usize {
+        <u32 as DeltaCodec<W>>::zenc(as_unsigned(input), output)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [i32]) -> Result<usize> {
+        <u32 as DeltaCodec<W>>::try_zdec(input, n, as_unsigned_mut(output))
+    }
+    fn ddec_block(input: &[u8], n: usize, output: &mut [i32], start: i32) -> usize {
+        <u32 as DeltaCodec<W>>::ddec_block(input, n, as_unsigned_mut(output), start as u32)
+    }
+    fn d1dec_block(input: &[u8], n: usize, output: &mut [i32], start: i32) -> usize {
+        <u32 as DeltaCodec<W>>::d1dec_block(input, n, as_unsigned_mut(output), start as u32)
+    }
+    fn zdec_block(input: &[u8], n: usize, output: &mut [i32], start: i32) -> usize {
+        <u32 as DeltaCodec<W>>::zdec_block(input, n, as_unsigned_mut(output), start as u32)
+    }
+    fn denc_block(input: &[i32], output: &mut [u8], start: i32) -> usize {
+        <u32 as DeltaCodec<W>>::denc_block(as_unsigned(input), output, start as u32)
+    }
+    fn d1enc_block(input: &[i32], output: &mut [u8], start: i32) -> usize {
+        <u32 as DeltaCodec<W>>::d1enc_block(as_unsigned(input), output, start as u32)
+    }
+    fn zenc_block(input: &[i32], output: &mut [u8], start: i32) -> usize {
+        <u32 as DeltaCodec<W>>::zenc_block(as_unsigned(input), output, start as u32)
+    }
+    fn ddec_block_bits(input: &[u8], n: usize, output: &mut [i32], start: i32, b: u32, bx: u32) -> usize {
+        <u32 as DeltaCodec<W>>::ddec_block_bits(input, n, as_unsigned_mut(output), start as u32, b, bx)
+    }
+    fn d1dec_block_bits(input: &[u8], n: usize, output: &mut [i32], start: i32, b: u32, bx: u32) -> usize {
+        <u32 as DeltaCodec<W>>::d1dec_block_bits(input, n, as_unsigned_mut(output), start as u32, b, bx)
+    }
+    fn zdec_block_bits(input: &[u8], n: usize, output: &mut [i32], start: i32, b: u32, bx: u32) -> usize {
+        <u32 as DeltaCodec<W>>::zdec_block_bits(input, n, as_unsigned_mut(output), start as u32, b, bx)
+    }
+    fn delta_block_bits(input: &[i32]) -> (u32, u32) {
+        <u32 as Codec<W>>::block_bits(as_unsigned(input))
+    }
+    fn enc_delta_block_bits(input: &[i32], output: &mut [u8], b: u32, bx: u32) -> usize {
+        <u32 as Codec<W>>::enc_block_bits(as_unsigned(input), output, b, bx)
+    }
+}
+
+impl Codec<W> for i64 {
+    fn max_compressed_size(n: usize) -> usize {
+        <u64 as Codec<W>>::max_compressed_size(n)
+    }
+    fn enc(input: &[i64], output: &mut [u8]) -> usize {
+        <u64 as Codec<W>>::enc(&zigzag(input), output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [i64]) -> Result<usize> {
+        let size = <u64 as Codec<W>>::try_dec(input, n, as_unsigned_mut(output))?;
+        unzigzag(output, n);
+        Ok(size)
+    }
+    fn dec_block(input: &[u8], n: usize, output: &mut [i64]) -> usize {
+        let size = <u64 as Codec<W>>::dec_block(input, n, as_unsigned_mut(output));
+        unzigzag(output, n);
+        size
+    }
+    fn enc_block(input: &[i64], output: &mut [u8]) -> usize {
+        with_zigzag(input, |input| <u64 as Codec<W>>::enc_block(input, output))
+    }
+    fn block_bits(input: &[i64]) -> (u32, u32) {
+        with_zigzag(input, <u64 as Codec<W>>::block_bits)
+    }
+    fn enc_block_bits(input: &[i64], output: &mut [u8], b: u32, bx: u32) -> usize {
+        with_zigzag(input, |input| <u64 as Codec<W>>::enc_block_bits(input, output, b, bx))
+    }
+    fn dec_block_bits(input: &[u8], n: usize, output: &mut [i64], b: u32, bx: u32) -> usize {
+        let size = <u64 as Codec<W>>::dec_block_bits(input, n, as_unsigned_mut(output), b, bx);
+        unzigzag(output, n);
+        size
+    }
+}
+
+impl DeltaCodec<W> for i64 {
+    fn denc(input: &[i64], output: &mut [u8]) -> usize {
+        <u64 as DeltaCodec<W>>::denc(as_unsigned(input), output)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [i64]) -> Result<usize> {
+        <u64 as DeltaCodec<W>>::try_ddec(input, n, as_unsigned_mut(output))
+    }
+    fn d1enc(input: &[i64], output: &mut [u8]) -> usize {
+        <u64 as DeltaCodec<W>>::d1enc(as_unsigned(input), output)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [i64]) -> Result<usize> {
+        <u64 as DeltaCodec<W>>::try_d1dec(input, n, as_unsigned_mut(output))
+    }
+    fn zenc(input: &[i64], output: &mut [u8]) -> usize {
+        <u64 as DeltaCodec<W>>::zenc(as_unsigned(input), output)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [i64]) -> Result<usize> {
+        <u64 as DeltaCodec<W>>::try_zdec(input, n, as_unsigned_mut(output))
+    }
+    fn ddec_block(input: &[u8], n: usize, output: &mut [i64], start: i64) -> usize {
+        <u64 as DeltaCodec<W>>::ddec_block(input, n, as_unsigned_mut(output), start as u64)
+    }
+    fn d1dec_block(input: &[u8], n: usize, output: &mut [i64], start: i64) -> usize {
+        <u64 as DeltaCodec<W>>::d1dec_block(input, n, as_unsigned_mut(output), start as u64)
+    }
+    fn zdec_block(input: &[u8], n: usize, output: &mut [i64], start: i64) -> usize {
+        <u64 as DeltaCodec<W>>::zdec_block(input, n, as_unsigned_mut(output), start as u64)
+    }
+    fn denc_block(input: &[i64], output: &mut [u8], start: i64) -> usize {
+        <u64 as DeltaCodec<W>>::denc_block(as_unsigned(input), output, start as u64)
+    }
+    fn d1enc_block(input: &[i64], output: &mut [u8], start: i64) -> usize {
+        <u64 as DeltaCodec<W>>::d1enc_block(as_unsigned(input), output, start as u64)
+    }
+    fn zenc_block(input: &[i64], output: &mut [u8], start: i64) -> usize {
+        <u64 as DeltaCodec<W>>::zenc_block(as_unsigned(input), output, start as u64)
+    }
+    fn ddec_block_bits(input: &[u8], n: usize, output: &mut [i64], start: i64, b: u32, bx: u32) -> usize {
+        <u64 as DeltaCodec<W>>::ddec_block_bits(input, n, as_unsigned_mut(output), start as u64, b, bx)
+    }
+    fn d1dec_block_bits(input: &[u8], n: usize, output: &mut [i64], start: i64, b: u32, bx: u32) -> usize {
+        <u64 as DeltaCodec<W>>::d1dec_block_bits(input, n, as_unsigned_mut(output), start as u64, b, bx)
+    }
+    fn zdec_block_bits(input: &[u8], n: usize, output: &mut [i64], start: i64, b: u32, bx: u32) -> usize {
+        <u64 as DeltaCodec<W>>::zdec_block_bits(input, n, as_unsigned_mut(output), start as u64, b, bx)
+    }
+    fn delta_block_bits(input: &[i64]) -> (u32, u32) {
+        <u64 as Codec<W>>::block_bits(as_unsigned(input))
+    }
+    fn enc_delta_block_bits(input: &[i64], output: &mut [u8], b: u32, bx: u32) -> usize {
+        <u64 as Codec<W>>::enc_block_bits(as_unsigned(input), output, b, bx)
+    }
+}
+
+impl Codec<W128v> for i16 {
+    fn max_compressed_size(n: usize) -> usize {
+        <u16 as Codec<W128v>>::max_compressed_size(n)
+    }
+    fn enc(input: &[i16], output: &mut [u8]) -> usize {
+        <u16 as Codec<W128v>>::enc(&zigzag(input), output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [i16]) -> Result<usize> {
+        let size = <u16 as Codec<W128v>>::try_dec(input, n, as_unsigned_mut(output))?;
+        unzigzag(output, n);
+        Ok(size)
+    }
+    fn dec_block(input: &[u8], n: usize, output: &mut [i16]) -> usize {
+        let size = <u16 as Codec<W128v>>::dec_block(input, n, as_unsigned_mut(output));
+        unzigzag(output, n);
+        size
+    }
+    fn enc_block(input: &[i16], output: &mut [u8]) -> usize {
+        with_zigzag(input, |input| <u16 as Codec<W128v>>::enc_block(input, output))
+    }
+    fn block_bits(input: &[i16]) -> (u32, u32) {
+        with_zigzag(input, <u16 as Codec<W128v>>::block_bits)
+    }
+    fn enc_block_bits(input: &[i16], output: &mut [u8], b: u32, bx: u32) -> usize {
+        with_zigzag(input, |input| <u16 as Codec<W128v>>::enc_block_bits(input, output, b, bx))
+    }
+    fn dec_block_bits(input: &[u8], n: usize, output: &mut [i16], b: u32, bx: u32) -> usize {
+        let size = <u16 as Codec<W128v>>::dec_block_bits(input, n, as_unsigned_mut(output), b, bx);
+        unzigzag(output, n);
+        size
+    }
+}
+
+impl DeltaCodec<W128v> for i16 {
+    fn denc(input: &[i16], output: &mut [u8]) -> usize {
+        <u16 as DeltaCodec<W128v>>::denc(as_unsigned(input), output)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [i16]) -> Result<usize> {
+        <u16 as DeltaCodec<W128v>>::try_ddec(input, n, as_unsigned_mut(output))
+    }
+    fn d1enc(input: &[i16], output: &mut [u8]) -> usize {
+        <u16 as DeltaCodec<W128v>>::d1enc(as_unsigned(input), output)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [i16]) -> Result<usize> {
+        <u16 as DeltaCodec<W128v>>::try_d1dec(input, n, as_unsigned_mut(output))
+    }
+    fn zenc(input: &[i16], output: &mut [u8]) -> usize {
+        <u16 as DeltaCodec<W128v>>::zenc(as_unsigned(input), output)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [i16]) -> Result<usize> {
+        <u16 as DeltaCodec<W128v>>::try_zdec(input, n, as_unsigned_mut(output))
+    }
+    fn ddec_block(input: &[u8], n: usize, output: &mut [i16], start: i16) -> usize {
+        <u16 as DeltaCodec<W128v>>::ddec_block(input, n, as_unsigned_mut(output), start as u16)
+    }
+    fn d1dec_block(input: &[u8], n: usize, output: &mut [i16], start: i16) -> usize {
+        <u16 as DeltaCodec<W128v>>::d1dec_block(input, n, as_unsigned_mut(output), start as u16)
+    }
+    fn zdec_block(input: &[u8], n: usize, output: &mut [i16], start: i16) -> usize {
+        <u16 as DeltaCodec<W128v>>::zdec_block(input, n, as_unsigned_mut(output), start as u16)
+    }
+    fn denc_block(input: &[i16], output: &mut [u8], start: i16) -> usize {
+        <u16 as DeltaCodec<W128v>>::denc_block(as_unsigned(input), output, start as u16)
+    }
+    fn d1enc_block(input: &[i16], output: &mut [u8], start: i16) -> usize {
+        <u16 as DeltaCodec<W128v>>::d1enc_block(as_unsigned(input), output, start as u16)
+    }
+    fn zenc_block(input: &[i16], output: &mut [u8], start: i16) -> usize {
+        <u16 as DeltaCodec<W128v>>::zenc_block(as_unsigned(input), output, start as u16)
+    }
+    fn ddec_block_bits(input: &[u8], n: usize, output: &mut [i16], start: i16, b: u32, bx: u32) -> usize {
+        <u16 as DeltaCodec<W128v>>::ddec_block_bits(input, n, as_unsigned_mut(output), start as u16, b, bx)
+    }
+    fn d1dec_block_bits(input: &[u8], n: usize, output: &mut [i16], start: i16, b: u32, bx: u32) -> usize {
+        <u16 as DeltaCodec<W128v>>::d1dec_block_bits(input, n, as_unsigned_mut(output), start as u16, b, bx)
+    }
+    fn zdec_block_bits(input: &[u8], n: usize, output: &mut [i16], start: i16, b: u32, bx: u32) -> usize {
+        <u16 as DeltaCodec<W128v>>::zdec_block_bits(input, n, as_unsigned_mut(output), start as u16, b, bx)
+    }
+    fn delta_block_bits(input: &[i16]) -> (u32, u32) {
+        <u16 as Codec<W128v>>::block_bits(as_unsigned(input))
+    }
+    fn enc_delta_block_bits(input: &[i16], output: &mut [u8], b: u32, bx: u32) -> usize {
+        <u16 as Codec<W128v>>::enc_block_bits(as_unsigned(input), output, b, bx)
+    }
+}
+
+impl Codec<W128v> for i32 {
+    fn max_compressed_size(n: usize) -> usize {
+        <u32 as Codec<W128v>>::max_compressed_size(n)
+    }
+    fn enc(input: &[i32], output: &mut [u8]) -> usize {
+        <u32 as Codec<W128v>>::enc(&zigzag(input), output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [i32]) -> Result<usize> {
+        let size = <u32 as Codec<W128v>>::try_dec(input, n, as_unsigned_mut(output))?;
+        unzigzag(output, n);
+        Ok(size)
+    }
+    fn dec_block(input: &[u8], n: usize, output: &mut [i32]) -> usize {
+        let size = <u32 as Codec<W128v>>::dec_block(input, n, as_unsigned_mut(output));
+        unzigzag(output, n);
+        size
+    }
+    fn enc_block(input: &[i32], output: &mut [u8]) -> usize {
+        with_zigzag(input, |input| <u32 as Codec<W128v>>::enc_block(input, output))
+    }
+    fn block_bits(input: &[i32]) -> (u32, u32) {
+        with_zigzag(input, <u32 as Codec<W128v>>::block_bits)
+    }
+    fn enc_block_bits(input: &[i32], output: &mut [u8], b: u32, bx: u32) -> usize {
+        with_zigzag(input, |input| <u32 as Codec<W128v>>::enc_block_bits(input, output, b, bx))
+    }
+    fn dec_block_bits(input: &[u8], n: usize, output: &mut [i32], b: u32, bx: u32) -> usize {
+        let size = <u32 as Codec<W128v>>::dec_block_bits(input, n, as_unsigned_mut(output), b, bx);
+        unzigzag(output, n);
+        size
+    }
+}
+
+impl DeltaCodec<W128v> for i32 {
+    fn denc(input: &[i32], output: &mut [u8]) -> usize {
+        <u32 as DeltaCodec<W128v>>::denc(as_unsigned(input), output)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [i32]) -> Result<usize> {
+        <u32 as DeltaCodec<W128v>>::try_ddec(input, n, as_unsigned_mut(output))
+    }
+    fn d1enc(input: &[i32], output: &mut [u8]) -> usize {
+        <u32 as DeltaCodec<W128v>>::d1enc(as_unsigned(input), output)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [i32]) -> Result<usize> {
+        <u32 as DeltaCodec<W128v>>::try_d1dec(input, n, as_unsigned_mut(output))
+    }
+    fn zenc(input: &[i32], output: &mut [u8]) -> usize {
+        <u32 as DeltaCodec<W128v>>::zenc(as_unsigned(input), output)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [i32]) -> Result<usize> {
+        <u32 as DeltaCodec<W128v>>::try_zdec(input, n, as_unsigned_mut(output))
+    }
+    fn ddec_block(input: &[u8], n: usize, output: &mut [i32], start: i32) -> usize {
+        <u32 as DeltaCodec<W128v>>::ddec_block(input, n, as_unsigned_mut(output), start as u32)
+    }
+    fn d1dec_block(input: &[u8], n: usize, output: &mut [i32], start: i32) -> usize {
+        <u32 as DeltaCodec<W128v>>::d1dec_block(input, n, as_unsigned_mut(output), start as u32)
+    }
+    fn zdec_block(input: &[u8], n: usize, output: &mut [i32], start: i32) -> usize {
+        <u32 as DeltaCodec<W128v>>::zdec_block(input, n, as_unsigned_mut(output), start as u32)
+    }
+    fn denc_block(input: &[i32], output: &mut [u8], start: i32) -> usize {
+        <u32 as DeltaCodec<W128v>>::denc_block(as_unsigned(input), output, start as u32)
+    }
+    fn d1enc_block(input: &[i32], output: &mut [u8], start: i32) -> usize {
+        <u32 as DeltaCodec<W128v>>::d1enc_block(as_unsigned(input), output, start as u32)
+    }
+    fn zenc_block(input: &[i32], output: &mut [u8], start: i32) -> usize {
+        <u32 as DeltaCodec<W128v>>::zenc_block(as_unsigned(input), output, start as u32)
+    }
+    fn ddec_block_bits(input: &[u8], n: usize, output: &mut [i32], start: i32, b: u32, bx: u32) -> usize {
+        <u32 as DeltaCodec<W128v>>::ddec_block_bits(input, n, as_unsigned_mut(output), start as u32, b, bx)
+    }
+    fn d1dec_block_bits(input: &[u8], n: usize, output: &mut [i32], start: i32, b: u32, bx: u32) -> usize {
+        <u32 as DeltaCodec<W128v>>::d1dec_block_bits(input, n, as_unsigned_mut(output), start as u32, b, bx)
+    }
+    fn zdec_block_bits(input: &[u8], n: usize, output: &mut [i32], start: i32, b: u32, bx: u32) -> usize {
+        <u32 as DeltaCodec<W128v>>::zdec_block_bits(input, n, as_unsigned_mut(output), start as u32, b, bx)
+    }
+    fn delta_block_bits(input: &[i32]) -> (u32, u32) {
+        <u32 as Codec<W128v>>::block_bits(as_unsigned(input))
+    }
+    fn enc_delta_block_bits(input: &[i32], output: &mut [u8], b: u32, bx: u32) -> usize {
+        <u32 as Codec<W128v>>::enc_block_bits(as_unsigned(input), output, b, bx)
+    }
+}
+
+impl Codec<W128v> for i64 {
+    fn max_compressed_size(n: usize) -> usize {
+        <u64 as Codec<W128v>>::max_compressed_size(n)
+    }
+    fn enc(input: &[i64], output: &mut [u8]) -> usize {
+        <u64 as Codec<W128v>>::enc(&zigzag(input), output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [i64]) -> Result<usize> {
+        let size = <u64 as Codec<W128v>>::try_dec(input, n, as_unsigned_mut(output))?;
+        unzigzag(output, n);
+        Ok(size)
+    }
+    fn dec_block(input: &[u8], n: usize, output: &mut [i64]) -> usize {
+        let size = <u64 as Codec<W128v>>::dec_block(input, n, as_unsigned_mut(output));
+        unzigzag(output, n);
+        size
+    }
+    fn enc_block(input: &[i64], output: &mut [u8]) -> usize {
+        with_zigzag(input, |input| <u64 as Codec<W128v>>::enc_block(input, output))
+    }
+    fn block_bits(input: &[i64]) -> (u32, u32) {
+        with_zigzag(input, <u64 as Codec<W128v>>::block_bits)
+    }
+    fn enc_block_bits(input: &[i64], output: &mut [u8], b: u32, bx: u32) -> usize {
+        with_zigzag(input, |input| <u64 as Codec<W128v>>::enc_block_bits(input, output, b, bx))
+    }
+    fn dec_block_bits(input: &[u8], n: usize, output: &mut [i64], b: u32, bx: u32) -> usize {
+        let size = <u64 as Codec<W128v>>::dec_block_bits(input, n, as_unsigned_mut(output), b, bx);
+        unzigzag(output, n);
+        size
+    }
+}
+
+impl Codec<W256v> for i32 {
+    fn max_compressed_size(n: usize) -> usize {
+        <u32 as Codec<W256v>>::max_compressed_size(n)
+    }
+    fn enc(input: &[i32], output: &mut [u8]) -> usize {
+        <u32 as Codec<W256v>>::enc(&zigzag(input), output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [i32]) -> Result<usize> {
+        let size = <u32 as Codec<W256v>>::try_dec(input, n, as_unsigned_mut(output))?;
+        unzigzag(output, n);
+        Ok(size)
+    }
+    fn dec_block(input: &[u8], n: usize, output: &mut [i32]) -> usize {
+        let size = <u32 as Codec<W256v>>::dec_block(input, n, as_unsigned_mut(output));
+        unzigzag(output, n);
+        size
+    }
+    fn enc_block(input: &[i32], output: &mut [u8]) -> usize {
+        with_zigzag(input, |input| <u32 as Codec<W256v>>::enc_block(input, output))
+    }
+    fn block_bits(input: &[i32]) -> (u32, u32) {
+        with_zigzag(input, <u32 as Codec<W256v>>::block_bits)
+    }
+    fn enc_block_bits(input: &[i32], output: &mut [u8], b: u32, bx: u32) -> usize {
+        with_zigzag(input, |input| <u32 as Codec<W256v>>::enc_block_bits(input, output, b, bx))
+    }
+    fn dec_block_bits(input: &[u8], n: usize, output: &mut [i32], b: u32, bx: u32) -> usize {
+        let size = <u32 as Codec<W256v>>::dec_block_bits(input, n, as_unsigned_mut(output), b, bx);
+        unzigzag(output, n);
+        size
+    }
+}
+
+impl DeltaCodec<W256v> for i32 {
+    fn denc(input: &[i32], output: &mut [u8]) -> usize {
+        <u32 as DeltaCodec<W256v>>::denc(as_unsigned(input), output)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [i32]) -> Result<usize> {
+        <u32 as DeltaCodec<W256v>>::try_ddec(input, n, as_unsigned_mut(output))
+    }
+    fn d1enc(input: &[i32], output: &mut [u8]) -> usize {
+        <u32 as DeltaCodec<W256v>>::d1enc(as_unsigned(input), output)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [i32]) -> Result<usize> {
+        <u32 as DeltaCodec<W256v>>::try_d1dec(input, n, as_unsigned_mut(output))
+    }
+    fn zenc(input: &[i32], output: &mut [u8]) -> usize {
+        <u32 as DeltaCodec<W256v>>::zenc(as_unsigned(input), output)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [i32]) -> Result<usize> {
+        <u32 as DeltaCodec<W256v>>::try_zdec(input, n, as_unsigned_mut(output))
+    }
+    fn ddec_block(input: &[u8], n: usize, output: &mut [i32], start: i32) -> usize {
+        <u32 as DeltaCodec<W256v>>::ddec_block(input, n, as_unsigned_mut(output), start as u32)
+    }
+    fn d1dec_block(input: &[u8], n: usize, output: &mut [i32], start: i32) -> usize {
+        <u32 as DeltaCodec<W256v>>::d1dec_block(input, n, as_unsigned_mut(output), start as u32)
+    }
+    fn zdec_block(input: &[u8], n: usize, output: &mut [i32], start: i32) -> usize {
+        <u32 as DeltaCodec<W256v>>::zdec_block(input, n, as_unsigned_mut(output), start as u32)
+    }
+    fn denc_block(input: &[i32], output: &mut [u8], start: i32) -> usize {
+        <u32 as DeltaCodec<W256v>>::denc_block(as_unsigned(input), output, start as u32)
+    }
+    fn d1enc_block(input: &[i32], output: &mut [u8], start: i32) -> usize {
+        <u32 as DeltaCodec<W256v>>::d1enc_block(as_unsigned(input), output, start as u32)
+    }
+    fn zenc_block(input: &[i32], output: &mut [u8], start: i32) -> usize {
+        <u32 as DeltaCodec<W256v>>::zenc_block(as_unsigned(input), output, start as u32)
+    }
+    fn ddec_block_bits(input: &[u8], n: usize, output: &mut [i32], start: i32, b: u32, bx: u32) -> usize {
+        <u32 as DeltaCodec<W256v>>::ddec_block_bits(input, n, as_unsigned_mut(output), start as u32, b, bx)
+    }
+    fn d1dec_block_bits(input: &[u8], n: usize, output: &mut [i32], start: i32, b: u32, bx: u32) -> usize {
+        <u32 as DeltaCodec<W256v>>::d1dec_block_bits(input, n, as_unsigned_mut(output), start as u32, b, bx)
+    }
+    fn zdec_block_bits(input: &[u8], n: usize, output: &mut [i32], start: i32, b: u32, bx: u32) -> usize {
+        <u32 as DeltaCodec<W256v>>::zdec_block_bits(input, n, as_unsigned_mut(output), start as u32, b, bx)
+    }
+    fn delta_block_bits(input: &[i32]) -> (u32, u32) {
+        <u32 as Codec<W256v>>::block_bits(as_unsigned(input))
+    }
+    fn enc_delta_block_bits(input: &[i32], output: &mut [u8], b: u32, bx: u32) -> usize {
+        <u32 as Codec<W256v>>::enc_block_bits(as_unsigned(input), output, b, bx)
+    }
+}
+
+impl BitCodec<W> for i8 {
+    fn max_compressed_size(n: usize) -> usize {
+        <u8 as BitCodec<W>>::max_compressed_size(n)
+    }
+    fn enc(input: &[i8], output: &mut [u8]) -> usize {
+        <u8 as BitCodec<W>>::enc(&zigzag(input), output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [i8]) -> Result<usize> {
+        let size = <u8 as BitCodec<W>>::try_dec(input, n, as_unsigned_mut(output))?;
+        unzigzag(output, n);
+        Ok(size)
+    }
+}
+
+impl BitCodec<W> for i16 {
+    fn max_compressed_size(n: usize) -> usize {
+        <u16 as BitCodec<W>>::max_compressed_size(n)
+    }
+    fn enc(input: &[i16], output: &mut [u8]) -> usize {
+        <u16 as BitCodec<W>>::enc(&zigzag(input), output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [i16]) -> Result<usize> {
+        let size = <u16 as BitCodec<W>>::try_dec(input, n, as_unsigned_mut(output))?;
+        unzigzag(output, n);
+        Ok(size)
+    }
+}
+
+impl BitCodec<W> for i32 {
+    fn max_compressed_size(n: usize) -> usize {
+        <u32 as BitCodec<W>>::max_compressed_size(n)
+    }
+    fn enc(input: &[i32], output: &mut [u8]) -> usize {
+        <u32 as BitCodec<W>>::enc(&zigzag(input), output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [i32]) -> Result<usize> {
+        let size = <u32 as BitCodec<W>>::try_dec(input, n, as_unsigned_mut(output))?;
+        unzigzag(output, n);
+        Ok(size)
+    }
+}
+
+impl BitCodec<W> for i64 {
+    fn max_compressed_size(n: usize) -> usize {
+        <u64 as BitCodec<W>>::max_compressed_size(n)
+    }
+    fn enc(input: &[i64], output: &mut [u8]) -> usize {
+        <u64 as BitCodec<W>>::enc(&zigzag(input), output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [i64]) -> Result<usize> {
+        let size = <u64 as BitCodec<W>>::try_dec(input, n, as_unsigned_mut(output))?;
+        unzigzag(output, n);
+        Ok(size)
+    }
+}
+
+impl BitCodec<W128v> for i16 {
+    fn max_compressed_size(n: usize) -> usize {
+        <u16 as BitCodec<W128v>>::max_compressed_size(n)
+    }
+    fn enc(input: &[i16], output: &mut [u8]) -> usize {
+        <u16 as BitCodec<W128v>>::enc(&zigzag(input), output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [i16]) -> Result<usize> {
+        let size = <u16 as BitCodec<W128v>>::try_dec(input, n, as_unsigned_mut(output))?;
+        unzigzag(output, n);
+        Ok(size)
+    }
+}
+
+impl BitCodec<W128v> for i32 {
+    fn max_compressed_size(n: usize) -> usize {
+        <u32 as BitCodec<W128v>>::max_compressed_size(n)
+    }
+    fn enc(input: &[i32], output: &mut [u8]) -> usize {
+        <u32 as BitCodec<W128v>>::enc(&zigzag(input), output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [i32]) -> Result<usize> {
+        let size = <u32 as BitCodec<W128v>>::try_dec(input, n, as_unsigned_mut(output))?;
+        unzigzag(output, n);
+        Ok(size)
+    }
+}
+
+impl BitCodec<W128v> for i64 {
+    fn max_compressed_size(n: usize) -> usize {
+        <u64 as BitCodec<W128v>>::max_compressed_size(n)
+    }
+    fn enc(input: &[i64], output: &mut [u8]) -> usize {
+        <u64 as BitCodec<W128v>>::enc(&zigzag(input), output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [i64]) -> Result<usize> {
+        let size = <u64 as BitCodec<W128v>>::try_dec(input, n, as_unsigned_mut(output))?;
+        unzigzag(output, n);
+        Ok(size)
+    }
+}
+
+impl BitCodec<W256v> for i32 {
+    fn max_compressed_size(n: usize) -> usize {
+        <u32 as BitCodec<W256v>>::max_compressed_size(n)
+    }
+    fn enc(input: &[i32], output: &mut [u8]) -> usize {
+        <u32 as BitCodec<W256v>>::enc(&zigzag(input), output)
+    }
+    fn try_dec(input: &[u8], n: usize, output: &mut [i32]) -> Result<usize> {
+        let size = <u32 as BitCodec<W256v>>::try_dec(input, n, as_unsigned_mut(output))?;
+        unzigzag(output, n);
+        Ok(size)
+    }
+}
+
+impl BitDeltaCodec<W> for i8 {
+    fn denc(input: &[i8], output: &mut [u8]) -> usize {
+        <u8 as BitDeltaCodec<W>>::denc(as_unsigned(input), output)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [i8]) -> Result<usize> {
+        <u8 as BitDeltaCodec<W>>::try_ddec(input, n, as_unsigned_mut(output))
+    }
+    fn d1enc(input: &[i8], output: &mut [u8]) -> usize {
+        <u8 as BitDeltaCodec<W>>::d1enc(as_unsigned(input), output)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [i8]) -> Result<usize> {
+        <u8 as BitDeltaCodec<W>>::try_d1dec(input, n, as_unsigned_mut(output))
+    }
+    fn fenc(input: &[i8], output: &mut [u8]) -> usize {
+        <u8 as BitDeltaCodec<W>>::fenc(as_unsigned(input), output)
+    }
+    fn try_fdec(input: &[u8], n: usize, output: &mut [i8]) -> Result<usize> {
+        <u8 as BitDeltaCodec<W>>::try_fdec(input, n, as_unsigned_mut(output))
+    }
+    fn zenc(input: &[i8], output: &mut [u8]) -> usize {
+        <u8 as BitDeltaCodec<W>>::zenc(as_unsigned(input), output)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [i8]) -> Result<usize> {
+        <u8 as BitDeltaCodec<W>>::try_zdec(input, n, as_unsigned_mut(output))
+    }
+}
+
+impl BitDeltaCodec<W> for i16 {
+    fn denc(input: &[i16], output: &mut [u8]) -> usize {
+        <u16 as BitDeltaCodec<W>>::denc(as_unsigned(input), output)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [i16]) -> Result<usize> {
+        <u16 as BitDeltaCodec<W>>::try_ddec(input, n, as_unsigned_mut(output))
+    }
+    fn d1enc(input: &[i16], output: &mut [u8]) -> usize {
+        <u16 as BitDeltaCodec<W>>::d1enc(as_unsigned(input), output)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [i16]) -> Result<usize> {
+        <u16 as BitDeltaCodec<W>>::try_d1dec(input, n, as_unsigned_mut(output))
+    }
+    fn fenc(input: &[i16], output: &mut [u8]) -> usize {
+        <u16 as BitDeltaCodec<W>>::fenc(as_unsigned(input), output)
+    }
+    fn try_fdec(input: &[u8], n: usize, output: &mut [i16]) -> Result<usize> {
+        <u16 as BitDeltaCodec<W>>::try_fdec(input, n, as_unsigned_mut(output))
+    }
+    fn zenc(input: &[i16], output: &mut [u8]) -> usize {
+        <u16 as BitDeltaCodec<W>>::zenc(as_unsigned(input), output)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [i16]) -> Result<usize> {
+        <u16 as BitDeltaCodec<W>>::try_zdec(input, n, as_unsigned_mut(output))
+    }
+}
+
+impl BitDeltaCodec<W> for i32 {
+    fn denc(input: &[i32], output: &mut [u8]) -> usize {
+        <u32 as BitDeltaCodec<W>>::denc(as_unsigned(input), output)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [i32]) -> Result<usize> {
+        <u32 as BitDeltaCodec<W>>::try_ddec(input, n, as_unsigned_mut(output))
+    }
+    fn d1enc(input: &[i32], output: &mut [u8]) -> usize {
+        <u32 as BitDeltaCodec<W>>::d1enc(as_unsigned(input), output)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [i32]) -> Result<usize> {
+        <u32 as BitDeltaCodec<W>>::try_d1dec(input, n, as_unsigned_mut(output))
+    }
+    fn fenc(input: &[i32], output: &mut [u8]) -> usize {
+        <u32 as BitDeltaCodec<W>>::fenc(as_unsigned(input), output)
+    }
+    fn try_fdec(input: &[u8], n: usize, output: &mut [i32]) -> Result<usize> {
+        <u32 as BitDeltaCodec<W>>::try_fdec(input, n, as_unsigned_mut(output))
+    }
+    fn zenc(input: &[i32], output: &mut [u8]) -> usize {
+        <u32 as BitDeltaCodec<W>>::zenc(as_unsigned(input), output)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [i32]) -> Result<usize> {
+        <u32 as BitDeltaCodec<W>>::try_zdec(input, n, as_unsigned_mut(output))
+    }
+}
+
+impl BitDeltaCodec<W> for i64 {
+    fn denc(input: &[i64], output: &mut [u8]) -> usize {
+        <u64 as BitDeltaCodec<W>>::denc(as_unsigned(input), output)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [i64]) -> Result<usize> {
+        <u64 as BitDeltaCodec<W>>::try_ddec(input, n, as_unsigned_mut(output))
+    }
+    fn d1enc(input: &[i64], output: &mut [u8]) -> usize {
+        <u64 as BitDeltaCodec<W>>::d1enc(as_unsigned(input), output)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [i64]) -> Result<usize> {
+        <u64 as BitDeltaCodec<W>>::try_d1dec(input, n, as_unsigned_mut(output))
+    }
+    fn fenc(input: &[i64], output: &mut [u8]) -> usize {
+        <u64 as BitDeltaCodec<W>>::fenc(as_unsigned(input), output)
+    }
+    fn try_fdec(input: &[u8], n: usize, output: &mut [i64]) -> Result<usize> {
+        <u64 as BitDeltaCodec<W>>::try_fdec(input, n, as_unsigned_mut(output))
+    }
+    fn zenc(input: &[i64], output: &mut [u8]) -> usize {
+        <u64 as BitDeltaCodec<W>>::zenc(as_unsigned(input), output)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [i64]) -> Result<usize> {
+        <u64 as BitDeltaCodec<W>>::try_zdec(input, n, as_unsigned_mut(output))
+    }
+}
+
+impl BitDeltaCodec<W128v> for i16 {
+    fn denc(input: &[i16], output: &mut [u8]) -> usize {
+        <u16 as BitDeltaCodec<W128v>>::denc(as_unsigned(input), output)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [i16]) -> Result<usize> {
+        <u16 as BitDeltaCodec<W128v>>::try_ddec(input, n, as_unsigned_mut(output))
+    }
+    fn d1enc(input: &[i16], output: &mut [u8]) -> usize {
+        <u16 as BitDeltaCodec<W128v>>::d1enc(as_unsigned(input), output)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [i16]) -> Result<usize> {
+        <u16 as BitDeltaCodec<W128v>>::try_d1dec(input, n, as_unsigned_mut(output))
+    }
+    fn fenc(input: &[i16], output: &mut [u8]) -> usize {
+        <u16 as BitDeltaCodec<W128v>>::fenc(as_unsigned(input), output)
+    }
+    fn try_fdec(input: &[u8], n: usize, output: &mut [i16]) -> Result<usize> {
+        <u16 as BitDeltaCodec<W128v>>::try_fdec(input, n, as_unsigned_mut(output))
+    }
+    fn zenc(input: &[i16], output: &mut [u8]) -> usize {
+        <u16 as BitDeltaCodec<W128v>>::zenc(as_unsigned(input), output)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [i16]) -> Result<usize> {
+        <u16 as BitDeltaCodec<W128v>>::try_zdec(input, n, as_unsigned_mut(output))
+    }
+}
+
+impl BitDeltaCodec<W128v> for i32 {
+    fn denc(input: &[i32], output: &mut [u8]) -> usize {
+        <u32 as BitDeltaCodec<W128v>>::denc(as_unsigned(input), output)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [i32]) -> Result<usize> {
+        <u32 as BitDeltaCodec<W128v>>::try_ddec(input, n, as_unsigned_mut(output))
+    }
+    fn d1enc(input: &[i32], output: &mut [u8]) -> usize {
+        <u32 as BitDeltaCodec<W128v>>::d1enc(as_unsigned(input), output)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [i32]) -> Result<usize> {
+        <u32 as BitDeltaCodec<W128v>>::try_d1dec(input, n, as_unsigned_mut(output))
+    }
+    fn fenc(input: &[i32], output: &mut [u8]) -> usize {
+        <u32 as BitDeltaCodec<W128v>>::fenc(as_unsigned(input), output)
+    }
+    fn try_fdec(input: &[u8], n: usize, output: &mut [i32]) -> Result<usize> {
+        <u32 as BitDeltaCodec<W128v>>::try_fdec(input, n, as_unsigned_mut(output))
+    }
+    fn zenc(input: &[i32], output: &mut [u8]) -> usize {
+        <u32 as BitDeltaCodec<W128v>>::zenc(as_unsigned(input), output)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [i32]) -> Result<usize> {
+        <u32 as BitDeltaCodec<W128v>>::try_zdec(input, n, as_unsigned_mut(output))
+    }
+}
+
+impl BitDeltaCodec<W256v> for i32 {
+    fn denc(input: &[i32], output: &mut [u8]) -> usize {
+        <u32 as BitDeltaCodec<W256v>>::denc(as_unsigned(input), output)
+    }
+    fn try_ddec(input: &[u8], n: usize, output: &mut [i32]) -> Result<usize> {
+        <u32 as BitDeltaCodec<W256v>>::try_ddec(input, n, as_unsigned_mut(output))
+    }
+    fn d1enc(input: &[i32], output: &mut [u8]) -> usize {
+        <u32 as BitDeltaCodec<W256v>>::d1enc(as_unsigned(input), output)
+    }
+    fn try_d1dec(input: &[u8], n: usize, output: &mut [i32]) -> Result<usize> {
+        <u32 as BitDeltaCodec<W256v>>::try_d1dec(input, n, as_unsigned_mut(output))
+    }
+    fn fenc(input: &[i32], output: &mut [u8]) -> usize {
+        <u32 as BitDeltaCodec<W256v>>::fenc(as_unsigned(input), output)
+    }
+    fn try_fdec(input: &[u8], n: usize, output: &mut [i32]) -> Result<usize> {
+        <u32 as BitDeltaCodec<W256v>>::try_fdec(input, n, as_unsigned_mut(output))
+    }
+    fn zenc(input: &[i32], output: &mut [u8]) -> usize {
+        <u32 as BitDeltaCodec<W256v>>::zenc(as_unsigned(input), output)
+    }
+    fn try_zdec(input: &[u8], n: usize, output: &mut [i32]) -> Result<usize> {
+        <u32 as BitDeltaCodec<W256v>>::try_zdec(input, n, as_unsigned_mut(output))
     }
 }
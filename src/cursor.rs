@@ -0,0 +1,229 @@
+/// Seeking in compressed sorted lists, e.g. for posting list intersection.
+///
+/// `SortedCursor::advance_to(key)` moves to the first value `>= key`. It walks the blocks of the compressed
+/// stream with `Blocks` (see `blockwise`) and compares `key` with the last value of each block, skipping the
+/// blocks that end below `key`; the block containing the answer is binary searched.
+///
+/// The deltas of a block are relative to the last value of the previous block, so a `p4n*enc*` stream has no
+/// index of block boundaries: without help, skipped blocks are unpacked with the single block decoders, but not
+/// searched, so a cursor created with `new` decodes the whole list on its way to the end, O(n) like `decode`. `encode_indexed` records a `SkipIndex` with the byte offset and the last value of every block next to
+/// the stream; a cursor created `with_index` binary searches it and decodes only the block containing the answer.
+/// (`p4geqx*` works on `p4dencx` blocks and `vbdgetgeq*` on variable byte streams, neither applies here.)
+use crate::blockwise::*;
+use crate::codec::*;
+use crate::error::*;
+use crate::generic::*;
+use crate::sample::*;
+use crate::validate::*;
+use std::cmp::min;
+use std::mem::size_of;
+use rand::{
+    prelude::Distribution,
+    distributions::Standard,
+};
+
+/// Encodings of sorted integer lists
+pub trait Sorted: Blockwise
+    where Self::T: Codec<Self::W> + Copy + Default
+{}
+
+impl<WT: Width, T: DeltaCodec<WT> + Default> Sorted for IncreasingEncoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic
+{}
+
+impl<WT: Width, T: DeltaCodec<WT> + Default> Sorted for StrictlyIncreasingEncoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic
+{}
+
+/// Byte offset and last value of every block of a stream encoded with a `Sorted` encoding, built by `encode_indexed`.
+/// The first block of the delta encodings also holds the first value, as in `Blocks`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkipIndex<T> {
+    offsets: Vec<usize>,
+    lasts: Vec<T>,
+}
+
+impl<T> SkipIndex<T> {
+    /// Number of blocks
+    pub fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    /// `true` for the index of an empty list
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+}
+
+/// Encode `input` with `E` into `output` and record the block boundaries
+/// # Returns
+/// Number of bytes written to output and the `SkipIndex` of the stream, or `OutputTooSmall` if `output` is shorter
+/// than `E::enc_buf_size(input.len())`
+pub fn encode_indexed<E: Sorted>(input: &[E::T], output: &mut [u8]) -> Result<(usize, SkipIndex<E::T>)>
+    where E::T: Codec<E::W> + Copy + Default
+{
+    let size = E::try_encode(input, output)?;
+    let n = input.len();
+    let bits = 8 * size_of::<E::T>();
+    let mut index = SkipIndex { offsets: Vec::new(), lasts: Vec::new() };
+    let (mut pos, mut done) = (0, 0);
+    if E::HAS_START && n > 0 {
+        pos = start_size::<E::T>(&output[..size], n, E::decode_start)?;
+        done = 1;
+    }
+    while done < n || (index.is_empty() && n > 0) {
+        let len = min(n - done, E::W::BLOCK_LEN);
+        index.offsets.push(if index.is_empty() { 0 } else { pos });
+        pos += block_size(&output[..size], pos, len, bits)?;
+        done += len;
+        index.lasts.push(input[done - 1]);
+    }
+    Ok((size, index))
+}
+
+/// Forward-only cursor over a compressed sorted list
+pub struct SortedCursor<'a, E: Sorted>
+    where E::T: Codec<E::W> + Copy + Default
+{
+    blocks: Blocks<'a, E>,
+    idx: usize,
+    current: Option<E::T>,
+    index: Option<&'a SkipIndex<E::T>>,
+    block: Option<usize>,
+}
+
+impl<'a, E: Sorted> SortedCursor<'a, E>
+    where E::T: Codec<E::W> + Copy + Default + PartialOrd
+{
+    /// Cursor over the `n` integers encoded with `E` in `input`, positioned before the first one.
+    /// Without a `SkipIndex`, `advance_to` unpacks every block it passes: seeking to the end decodes the whole list,
+    /// see `with_index`.
+    pub fn new(input: &'a [u8], n: usize) -> Self {
+        SortedCursor { blocks: E::blocks(input, n), idx: 0, current: None, index: None, block: None }
+    }
+
+    /// Cursor over the `n` integers encoded with `encode_indexed::<E>` in `input`, skipping blocks with `index`
+    pub fn with_index(input: &'a [u8], n: usize, index: &'a SkipIndex<E::T>) -> Self {
+        SortedCursor { index: Some(index), ..Self::new(input, n) }
+    }
+
+    /// The value the cursor points to, `None` before the first `advance_to` and after the end of the list
+    pub fn current(&self) -> Option<E::T> {
+        self.current
+    }
+
+    /// Move to the first value `>= key` at or after the current position
+    /// # Returns
+    /// The new current value, or `None` if there is no such value
+    pub fn advance_to(&mut self, key: E::T) -> Option<E::T> {
+        if let Some(index) = self.index {
+            // jump to the first block not ending below `key`, the loop below then stays in it
+            let from = self.block.unwrap_or(0);
+            let target = from + index.lasts[from..].partition_point(|&last| last < key);
+            if Some(target) != self.block {
+                // past the last block `seek` leaves nothing to decode
+                let start = if target == 0 { E::T::default() } else { index.lasts[target - 1] };
+                self.blocks.seek(target, index.offsets.get(target).copied().unwrap_or(0), start);
+                self.block = Some(target - 1).filter(|_| target > 0);
+                self.idx = 0;
+            }
+        }
+        loop {
+            let block = self.blocks.block();
+            if self.idx < block.len() && block[block.len() - 1] >= key {
+                self.idx += block[self.idx..].partition_point(|&x| x < key);
+                self.current = Some(block[self.idx]);
+                return self.current;
+            }
+            if !self.blocks.advance() {
+                self.idx = self.blocks.block().len();
+                self.current = None;
+                return None;
+            }
+            self.block = Some(self.block.map_or(0, |b| b + 1));
+            self.idx = 0;
+        }
+    }
+}
+
+#[test]
+fn test_sorted_cursor() {
+    type E = StrictlyIncreasingEncoding<W, u32>;
+    let input: Vec<u32> = (0..10_000).map(|i| 3 * i + 1).collect();
+    let mut buf = vec![0u8; E::enc_buf_size(input.len())];
+    let size = E::encode(&input, &mut buf);
+    let mut cursor = SortedCursor::<E>::new(&buf[..size], input.len());
+    assert_eq!(cursor.current(), None);
+    assert_eq!(cursor.advance_to(0), Some(1));
+    assert_eq!(cursor.advance_to(1), Some(1));
+    assert_eq!(cursor.advance_to(2), Some(4));
+    assert_eq!(cursor.advance_to(1000), Some(1000));
+    assert_eq!(cursor.current(), Some(1000));
+    // moving backwards stays put
+    assert_eq!(cursor.advance_to(5), Some(1000));
+    assert_eq!(cursor.advance_to(20_000), Some(20_002));
+    assert_eq!(cursor.advance_to(29_998), Some(29_998));
+    assert_eq!(cursor.advance_to(29_999), None);
+    assert_eq!(cursor.current(), None);
+    assert_eq!(cursor.advance_to(0), None);
+}
+
+#[test]
+fn test_sorted_cursor_intersection() {
+    type E = IncreasingEncoding<W128v, u32>;
+    let a: Vec<u32> = (0..5000).map(|i| 2 * i).collect();
+    let b: Vec<u32> = (0..3000).map(|i| 3 * i).collect();
+    let mut buf_a = vec![0u8; E::enc_buf_size(a.len())];
+    let size_a = E::encode(&a, &mut buf_a);
+    let mut cursor = SortedCursor::<E>::new(&buf_a[..size_a], a.len());
+    let intersection: Vec<u32> = b.iter().filter(|&&x| cursor.advance_to(x) == Some(x)).copied().collect();
+    assert_eq!(intersection, (0..1500).map(|i| 6 * i).collect::<Vec<u32>>());
+}
+
+#[test]
+fn test_sorted_cursor_index() {
+    type E = StrictlyIncreasingEncoding<W, u32>;
+    let input: Vec<u32> = (0..10_000).map(|i| 3 * i + 1).collect();
+    let mut buf = vec![0u8; E::enc_buf_size(input.len())];
+    let (size, index) = encode_indexed::<E>(&input, &mut buf).unwrap();
+    assert_eq!(size, E::encode(&input, &mut vec![0u8; E::enc_buf_size(input.len())]));
+    assert_eq!(index.len(), (input.len() - 1).div_ceil(128));
+    // the skipped blocks are never looked at
+    for block in 1..index.len() - 1 {
+        buf[index.offsets[block]] = 0x3f;
+    }
+    let mut cursor = SortedCursor::<E>::with_index(&buf[..size], input.len(), &index);
+    assert_eq!(cursor.advance_to(2), Some(4));
+    assert_eq!(cursor.advance_to(5), Some(7));
+    assert_eq!(cursor.advance_to(5), Some(7));
+    assert_eq!(cursor.advance_to(29_990), Some(29_992));
+    assert_eq!(cursor.advance_to(29_998), Some(29_998));
+    assert_eq!(cursor.advance_to(29_999), None);
+    assert_eq!(cursor.advance_to(0), None);
+
+    let mut cursor = SortedCursor::<E>::with_index(&buf[..size], input.len(), &index);
+    assert_eq!(cursor.advance_to(40_000), None);
+    assert_eq!(encode_indexed::<E>(&input, &mut buf[..10]), Err(TurboPforError::OutputTooSmall { needed: E::enc_buf_size(input.len()), got: 10 }));
+}
+
+#[test]
+fn test_sorted_cursor_index_matches() {
+    fn check<E: Sorted>(input: &[E::T], keys: &[E::T])
+        where E::T: Codec<E::W> + Copy + Default + PartialOrd + std::fmt::Debug
+    {
+        let mut buf = vec![0u8; E::enc_buf_size(input.len())];
+        let (size, index) = encode_indexed::<E>(input, &mut buf).unwrap();
+        let mut plain = SortedCursor::<E>::new(&buf[..size], input.len());
+        let mut indexed = SortedCursor::<E>::with_index(&buf[..size], input.len(), &index);
+        for &key in keys {
+            assert_eq!(indexed.advance_to(key), plain.advance_to(key));
+        }
+    }
+    let keys: Vec<u32> = (0..3000).map(|i| i * 7 % 5000 + i).collect();
+    for n in [0, 1, 2, 128, 129, 130, 1000] {
+        let input: Vec<u32> = (0..n).map(|i| 2 * i as u32).collect();
+        check::<IncreasingEncoding<W, u32>>(&input, &keys);
+        check::<StrictlyIncreasingEncoding<W128v, u32>>(&input, &keys);
+        check::<IncreasingEncoding<W256v, u32>>(&input, &keys);
+    }
+}
@@ -0,0 +1,214 @@
+/// Random access into p4 compressed arrays.
+///
+/// `P4DirectArray` splits the input into blocks of `BLOCK_LEN` integers and encodes each block with
+/// `p4encx8/16/32`, the direct access variant of the TurboPFor encoder. Element `idx` can then be read
+/// without decompressing its block.
+///
+/// The C access functions `p4ini` and `p4getx*` are `static inline` in vp4.h and aren't exported by the library,
+/// so they are ported here. A `p4encx` block consists of
+/// - the header: `b` if the block has no exceptions, or `0x80|b` followed by the exception bit width `bx`,
+/// - for blocks with exceptions: the exception bitmap of `n` bits and the exceptions bitpacked with `bx` bits,
+/// - the `n` integers bitpacked with `b` bits.
+///
+/// Bitpacking stores integer `i` in bits `b*i..b*(i+1)` of a little endian bit stream.
+/// `P4DirectArray` parses all block headers once in `from_slice`, `get` costs two bit extractions and a popcount.
+use crate::p4;
+use std::ops::Range;
+
+/// Number of integers per block, `P4D_MAX`
+pub const BLOCK_LEN: usize = 256;
+
+/// Integer types supported by `p4encx`
+pub trait P4Direct: Copy + Default {
+    /// Number of bits of the type
+    const BITS: u32;
+    /// Encode a single block of at most `BLOCK_LEN` integers
+    fn encx(input: &[Self], output: &mut [u8]) -> usize;
+    /// Upper bound of the size of a block of `n` integers
+    fn block_bound(n: usize) -> usize;
+    /// Truncate an extracted value to `Self`
+    fn from_u64(v: u64) -> Self;
+}
+
+impl P4Direct for u8 {
+    const BITS: u32 = 8;
+    fn encx(input: &[u8], output: &mut [u8]) -> usize { p4::encx8(input, output) }
+    fn block_bound(n: usize) -> usize { p4::bound8(n) }
+    fn from_u64(v: u64) -> u8 { v as u8 }
+}
+
+impl P4Direct for u16 {
+    const BITS: u32 = 16;
+    fn encx(input: &[u16], output: &mut [u8]) -> usize { p4::encx16(input, output) }
+    fn block_bound(n: usize) -> usize { p4::bound16(n) }
+    fn from_u64(v: u64) -> u16 { v as u16 }
+}
+
+impl P4Direct for u32 {
+    const BITS: u32 = 32;
+    fn encx(input: &[u32], output: &mut [u8]) -> usize { p4::encx32(input, output) }
+    fn block_bound(n: usize) -> usize { p4::bound32(n) }
+    fn from_u64(v: u64) -> u32 { v as u32 }
+}
+
+/// Parsed block header, the equivalent of `struct p4` after `p4ini`
+#[derive(Debug, Clone, Copy)]
+struct Block {
+    /// Bit width of the integers
+    b: u32,
+    /// Bit width of the exceptions, 0 if the block has none
+    bx: u32,
+    /// Byte offset of the bitpacked integers
+    data: usize,
+    /// Byte offset of the exception bitmap
+    xmap: usize,
+    /// Byte offset of the bitpacked exceptions
+    ex: usize,
+    /// Number of exceptions before each 64 bit word of the bitmap
+    cum: [u16; BLOCK_LEN / 64],
+}
+
+/// A compressed array of integers with random access to individual entries
+pub struct P4DirectArray<T: P4Direct> {
+    data: Vec<u8>,
+    blocks: Vec<Block>,
+    len: usize,
+    _marker: std::marker::PhantomData<T>,
+}
+
+/// Number of zero bytes after the data, so that `bitget` can always load 8 bytes
+const PADDING: usize = 8;
+
+/// Read `b` bits starting at bit `bidx` of the little endian bit stream `data` (port of `_bitgetx32`)
+#[inline]
+fn bitget(data: &[u8], bidx: usize, b: u32) -> u64 {
+    if b == 0 {
+        return 0;
+    }
+    let i = bidx / 8;
+    let word = u64::from_le_bytes(data[i..i + 8].try_into().unwrap());
+    (word >> (bidx % 8)) & ((1u64 << b) - 1)
+}
+
+/// 64 bit word `w` of the exception bitmap of `n` bits at byte offset `xmap`
+#[inline]
+fn xmap_word(data: &[u8], xmap: usize, n: usize, w: usize) -> u64 {
+    let start = xmap + 8 * w;
+    let end = xmap + n.div_ceil(8);
+    let mut bytes = [0u8; 8];
+    let len = end.saturating_sub(start).min(8);
+    bytes[..len].copy_from_slice(&data[start..start + len]);
+    u64::from_le_bytes(bytes)
+}
+
+impl<T: P4Direct> P4DirectArray<T> {
+    /// Compress `input`
+    pub fn from_slice(input: &[T]) -> Self {
+        let mut data = Vec::new();
+        let mut blocks = Vec::with_capacity(input.len().div_ceil(BLOCK_LEN));
+        let mut buf = vec![0u8; T::block_bound(BLOCK_LEN)];
+        for chunk in input.chunks(BLOCK_LEN) {
+            let size = T::encx(chunk, &mut buf);
+            blocks.push(Self::parse(&buf[..size], data.len(), chunk.len()));
+            data.extend_from_slice(&buf[..size]);
+        }
+        data.resize(data.len() + PADDING, 0);
+        P4DirectArray { data, blocks, len: input.len(), _marker: std::marker::PhantomData }
+    }
+
+    /// Parse the header of the block of `n` integers in `block`, which starts at byte `offset` (port of `p4ini`)
+    fn parse(block: &[u8], offset: usize, n: usize) -> Block {
+        let b = (block[0] & 0x7f) as u32;
+        assert!(b <= T::BITS, "invalid p4encx block header {:#04x}", block[0]);
+        if block[0] & 0x80 == 0 {
+            return Block { b, bx: 0, data: offset + 1, xmap: 0, ex: 0, cum: [0; BLOCK_LEN / 64] };
+        }
+        let bx = block[1] as u32;
+        let xmap = 2;
+        let mut cum = [0u16; BLOCK_LEN / 64];
+        let mut num = 0;
+        for (w, c) in cum.iter_mut().enumerate().take(n.div_ceil(64)) {
+            *c = num as u16;
+            num += xmap_word(block, xmap, n, w).count_ones() as usize;
+        }
+        let ex = xmap + n.div_ceil(8);
+        let data = ex + (num * bx as usize).div_ceil(8);
+        Block { b, bx, data: offset + data, xmap: offset + xmap, ex: offset + ex, cum }
+    }
+
+    /// Number of integers
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// `true` if the array holds no integers
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Size of the compressed data in bytes
+    pub fn compressed_size(&self) -> usize {
+        self.data.len() - PADDING
+    }
+
+    /// Integer at index `idx`, or `None` if `idx` is out of bounds (port of `p4getx32`)
+    pub fn get(&self, idx: usize) -> Option<T> {
+        if idx >= self.len {
+            return None;
+        }
+        let block = &self.blocks[idx / BLOCK_LEN];
+        let i = idx % BLOCK_LEN;
+        let mut u = bitget(&self.data, block.data * 8 + i * block.b as usize, block.b);
+        if block.bx > 0 {
+            let n = (self.len - idx / BLOCK_LEN * BLOCK_LEN).min(BLOCK_LEN);
+            let word = xmap_word(&self.data, block.xmap, n, i / 64);
+            let bit = 1u64 << (i % 64);
+            if word & bit != 0 {
+                let k = block.cum[i / 64] as usize + (word & (bit - 1)).count_ones() as usize;
+                u += bitget(&self.data, block.ex * 8 + k * block.bx as usize, block.bx) << block.b;
+            }
+        }
+        Some(T::from_u64(u))
+    }
+
+    /// Integers at the indices in `range`, or `None` if `range` is out of bounds
+    pub fn get_range(&self, range: Range<usize>) -> Option<Vec<T>> {
+        if range.start > range.end || range.end > self.len {
+            return None;
+        }
+        Some(range.map(|idx| self.get(idx).unwrap()).collect())
+    }
+}
+
+#[cfg(test)]
+fn test_direct_generic<T>(input: &[T])
+    where T: P4Direct + PartialEq + std::fmt::Debug
+{
+    let array = P4DirectArray::from_slice(input);
+    assert_eq!(array.len(), input.len());
+    for (idx, &x) in input.iter().enumerate() {
+        assert_eq!(array.get(idx), Some(x));
+    }
+    assert_eq!(array.get(input.len()), None);
+    assert_eq!(array.get_range(0..input.len()).unwrap(), input);
+    assert_eq!(array.get_range(input.len()..input.len() + 1), None);
+}
+
+#[test]
+fn test_direct() {
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for len in [0, 1, 100, 256, 257, 1000] {
+        // mostly small values with a few outliers, so that most blocks have exceptions
+        let input: Vec<u32> = (0..len).map(|_| if rng.gen_ratio(1, 20) { rng.gen() } else { rng.gen_range(0..100) }).collect();
+        test_direct_generic(&input);
+        let input: Vec<u16> = input.iter().map(|&x| x as u16).collect();
+        test_direct_generic(&input);
+        let input: Vec<u8> = input.iter().map(|&x| x as u8).collect();
+        test_direct_generic(&input);
+    }
+    let input: Vec<u32> = (0..1000).collect();
+    let array = P4DirectArray::from_slice(&input);
+    assert_eq!(array.get_range(300..310).unwrap(), (300..310).collect::<Vec<u32>>());
+    assert!(array.compressed_size() < 4 * input.len());
+}
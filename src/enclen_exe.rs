@@ -1,4 +1,7 @@
 /// Small exe for debugging/determining correct enclen
+///
+/// Without arguments it prints the excess of the p4 encoded size over the raw size,
+/// with `bitio` the maximum size of the `bvz`/`bvzz` codecs next to the bound `fp::bound*`.
 use generate_random::GenerateRandom;
 use turbopfor_rs::codec::*;
 
@@ -17,7 +20,33 @@ fn enc_len<W:Width, T: GenerateRandom + Eq + Codec<W>>(len: usize, n_iter: usize
     max_enc_size
 }
 
+fn enc_len_bitio<T: GenerateRandom + BitIoCodec + Default + Copy>(len: usize, n_iter: usize) -> usize {
+    let mut rng = rand::thread_rng();
+    let mut encoded: Vec<u8> = vec![0; T::max_compressed_size(len)];
+    let mut max_enc_size: usize = 0;
+    for _ in 0..n_iter {
+        let input: Vec<T> = (0..len).map(|_| T::generate_random(&mut rng)).collect();
+        let enc_size = T::zenc(&input, &mut encoded, T::default()).max(T::zzenc(&input, &mut encoded, T::default()));
+        if enc_size > max_enc_size { max_enc_size = enc_size; }
+    }
+    max_enc_size
+}
+
+fn main_bitio() {
+    println!("len\tu8\tbound\tu16\tbound\tu32\tbound\tu64\tbound");
+    for len in 1..10000 {
+        println!("{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}", len,
+            enc_len_bitio::<u8>(len, 100), <u8 as BitIoCodec>::max_compressed_size(len),
+            enc_len_bitio::<u16>(len, 100), <u16 as BitIoCodec>::max_compressed_size(len),
+            enc_len_bitio::<u32>(len, 100), <u32 as BitIoCodec>::max_compressed_size(len),
+            enc_len_bitio::<u64>(len, 100), <u64 as BitIoCodec>::max_compressed_size(len));
+    }
+}
+
 fn main() {
+    if std::env::args().nth(1).as_deref() == Some("bitio") {
+        return main_bitio();
+    }
     println!("len\tu8\tu16\tu32\tu16 128v\tu32 128v\tu32 256v");
     for len in 1..10000 {
         //let len: usize = l * 10;
@@ -0,0 +1,161 @@
+/// Error type of the checked (`try_*`) encoders and decoders.
+///
+/// The plain wrappers forward raw pointers into the C library, which performs no bounds checks
+/// whatsoever. The checked variants verify the slice lengths against the library's `*bound` functions and
+/// `Width::dec_buf_len` first and return a `TurboPforError` instead of writing beyond the buffers.
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TurboPforError {
+    /// The output buffer is shorter than the safe size required by the encoder/decoder
+    OutputTooSmall { needed: usize, got: usize },
+    /// The compressed input is too short to hold the requested number of integers
+    InputTruncated { needed: usize, got: usize },
+    /// A single block decoder was asked for `n` integers, but a block holds at most `max`
+    BlockTooLong { n: usize, max: usize },
+    /// The block starting at byte `offset` needs `needed` bytes, but only `got` bytes are left
+    BlockTruncated { offset: usize, needed: usize, got: usize },
+    /// The block starting at byte `offset` has a header that no encoder produces
+    InvalidBlockHeader { offset: usize, header: u8 },
+    /// The input doesn't start with the `frame` magic bytes
+    InvalidMagic,
+    /// The `frame` header field `field` is `got`, but `expected` was required
+    FrameMismatch { field: &'static str, expected: u64, got: u64 },
+    /// The `frame` was written with encoding `enc_type` and width `width_id`, which don't exist for the requested type
+    UnknownEncoding { enc_type: u8, width_id: u8 },
+    /// The stored checksum `expected` doesn't match the checksum `got` of the payload
+    ChecksumMismatch { expected: u32, got: u32 },
+    /// The bit width `b` is larger than the `max` bits of the integer type
+    InvalidBitWidth { b: u32, max: u32 },
+    /// `value` needs more than the `b` bits of a bit-packed array
+    ValueTooWide { value: u64, b: u32 },
+    /// Index `idx` is out of bounds for an array of length `len`
+    IndexOutOfBounds { idx: usize, len: usize },
+    /// The C function takes the number of integers as `unsigned`, so it can't handle more than `max`
+    TooManyIntegers { n: usize, max: usize },
+    /// The integer at `idx` is smaller than its predecessor (or `start` for the first one),
+    /// or for the strictly increasing encodings not larger
+    NotIncreasing { idx: usize },
+}
+
+impl fmt::Display for TurboPforError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TurboPforError::OutputTooSmall { needed, got } =>
+                write!(f, "output buffer too small: needed {} but got {}", needed, got),
+            TurboPforError::InputTruncated { needed, got } =>
+                write!(f, "compressed input truncated: needed {} bytes but got {}", needed, got),
+            TurboPforError::BlockTooLong { n, max } =>
+                write!(f, "block too long: {} integers but at most {}", n, max),
+            TurboPforError::BlockTruncated { offset, needed, got } =>
+                write!(f, "block at offset {} truncated: needed {} bytes but got {}", offset, needed, got),
+            TurboPforError::InvalidBlockHeader { offset, header } =>
+                write!(f, "block at offset {} has invalid header {:#04x}", offset, header),
+            TurboPforError::InvalidMagic =>
+                write!(f, "invalid frame magic"),
+            TurboPforError::FrameMismatch { field, expected, got } =>
+                write!(f, "frame header mismatch: {} is {} but expected {}", field, got, expected),
+            TurboPforError::UnknownEncoding { enc_type, width_id } =>
+                write!(f, "unknown frame encoding {} with width {} for this type", enc_type, width_id),
+            TurboPforError::ChecksumMismatch { expected, got } =>
+                write!(f, "checksum mismatch: stored {:#010x} but computed {:#010x}", expected, got),
+            TurboPforError::InvalidBitWidth { b, max } =>
+                write!(f, "invalid bit width {}, at most {}", b, max),
+            TurboPforError::ValueTooWide { value, b } =>
+                write!(f, "value {} doesn't fit into {} bits", value, b),
+            TurboPforError::IndexOutOfBounds { idx, len } =>
+                write!(f, "index {} out of bounds for length {}", idx, len),
+            TurboPforError::TooManyIntegers { n, max } =>
+                write!(f, "too many integers: {} but at most {}", n, max),
+            TurboPforError::NotIncreasing { idx } =>
+                write!(f, "integers not increasing at index {}", idx),
+        }
+    }
+}
+
+impl std::error::Error for TurboPforError {}
+
+pub type Result<T> = std::result::Result<T, TurboPforError>;
+
+/// Check that an output buffer of length `got` holds at least `needed` items
+pub(crate) fn check_output(needed: usize, got: usize) -> Result<()> {
+    if got < needed {
+        return Err(TurboPforError::OutputTooSmall { needed, got });
+    }
+    Ok(())
+}
+
+/// Check that there is any compressed input at all when `n` > 0 integers are to be decoded
+pub(crate) fn check_input(n: usize, got: usize) -> Result<()> {
+    if n > 0 && got == 0 {
+        return Err(TurboPforError::InputTruncated { needed: 1, got });
+    }
+    Ok(())
+}
+
+/// Largest number of integers in a single TurboPFor block (`P4D_MAX`)
+pub const MAX_BLOCK_LEN: usize = 256;
+
+/// Check that a single block decoder is asked for at most `MAX_BLOCK_LEN` integers
+pub(crate) fn check_block_len(n: usize) -> Result<()> {
+    if n > MAX_BLOCK_LEN {
+        return Err(TurboPforError::BlockTooLong { n, max: MAX_BLOCK_LEN });
+    }
+    Ok(())
+}
+
+/// Check that the decoder did not consume more than the `got` bytes of input it was given
+pub(crate) fn check_consumed(consumed: usize, got: usize) -> Result<usize> {
+    if consumed > got {
+        return Err(TurboPforError::InputTruncated { needed: consumed, got });
+    }
+    Ok(consumed)
+}
+
+/// Check that the bit width `b` is at most the `max` bits of the integer type
+pub(crate) fn check_bit_width(b: u32, max: u32) -> Result<()> {
+    if b > max {
+        return Err(TurboPforError::InvalidBitWidth { b, max });
+    }
+    Ok(())
+}
+
+/// Check that `n` fits into the `unsigned n` argument of the C functions
+pub(crate) fn check_unsigned(n: usize) -> Result<()> {
+    if n > u32::MAX as usize {
+        return Err(TurboPforError::TooManyIntegers { n, max: u32::MAX as usize });
+    }
+    Ok(())
+}
+
+/// Fail with `NotIncreasing` unless `input` is increasing, starting at `start`, or strictly increasing if `strict`
+pub(crate) fn check_increasing<T: PartialOrd + Copy>(input: &[T], start: T, strict: bool) -> Result<()> {
+    let mut prev = start;
+    for (idx, &x) in input.iter().enumerate() {
+        if x < prev || (strict && x == prev) {
+            return Err(TurboPforError::NotIncreasing { idx });
+        }
+        prev = x;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_checks() {
+    assert_eq!(check_output(10, 10), Ok(()));
+    assert_eq!(check_output(10, 9), Err(TurboPforError::OutputTooSmall { needed: 10, got: 9 }));
+    assert_eq!(check_input(0, 0), Ok(()));
+    assert_eq!(check_input(1, 0), Err(TurboPforError::InputTruncated { needed: 1, got: 0 }));
+    assert_eq!(check_block_len(256), Ok(()));
+    assert_eq!(check_block_len(257), Err(TurboPforError::BlockTooLong { n: 257, max: 256 }));
+    assert_eq!(check_consumed(5, 5), Ok(5));
+    assert_eq!(check_consumed(6, 5), Err(TurboPforError::InputTruncated { needed: 6, got: 5 }));
+    assert_eq!(check_bit_width(32, 32), Ok(()));
+    assert_eq!(check_bit_width(33, 32), Err(TurboPforError::InvalidBitWidth { b: 33, max: 32 }));
+    assert_eq!(check_unsigned(u32::MAX as usize), Ok(()));
+    assert_eq!(check_unsigned(u32::MAX as usize + 1), Err(TurboPforError::TooManyIntegers { n: 1 << 32, max: u32::MAX as usize }));
+    assert_eq!(check_increasing(&[1u32, 1, 2], 0, false), Ok(()));
+    assert_eq!(check_increasing(&[1u32, 1, 2], 0, true), Err(TurboPforError::NotIncreasing { idx: 1 }));
+    assert_eq!(check_increasing(&[1u32, 2], 1, true), Err(TurboPforError::NotIncreasing { idx: 0 }));
+    assert_eq!(check_increasing(&[3u64, 2], 0, false), Err(TurboPforError::NotIncreasing { idx: 1 }));
+}
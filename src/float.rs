@@ -0,0 +1,259 @@
+/// Floating point compression.
+///
+/// The codecs of fp.h compress the bit patterns of floats, passed as `u32`/`u64`: TurboGorilla (`fpgenc*`),
+/// Chimp (`fphenc*`), TurboFloatLz (`fpcenc*`), the FCM and DFCM predictors (`fpfcmenc*`, `fpdfcmenc*`) and
+/// the XOR with the last value (`fpxenc*`). They are wrapped in the module `fp`.
+/// The `FloatEncoding` instances take `&[f32]`/`&[f64]` directly and reinterpret the slices in place.
+///
+/// All of them predict an integer from its predecessors and store the XOR (or difference) with the prediction,
+/// so they only pay off on the raw floats of smooth series, e.g. sensor readings. Converting floats to integers
+/// (`icapp -Ft.4`) and compressing those with `p4nzenc*` destroys exactly the structure they exploit.
+/// The first integer is predicted from `start`, which the decoder must be given as well;
+/// `encode`/`decode` use 0.0, `encode_with_start`/`decode_with_start` take it as a float.
+use crate::codec::*;
+use crate::error::*;
+use crate::fp;
+use std::marker::PhantomData;
+
+/// `f32` and `f64` with the fp.h codecs for their bit patterns
+pub trait FloatCodec: Copy + Default {
+    /// Upper bound of the encoded size of `n` floats, see `fp::bound32`
+    fn max_compressed_size(n: usize) -> usize;
+    /// TurboGorilla encoding (`fpgenc*`)
+    fn genc(input: &[Self], output: &mut [u8], start: Self) -> usize;
+    /// TurboGorilla decoding (`fpgdec*`), checked
+    fn try_gdec(input: &[u8], n: usize, output: &mut [Self], start: Self) -> Result<usize>;
+    /// Chimp encoding (`fphenc*`)
+    fn henc(input: &[Self], output: &mut [u8], start: Self) -> usize;
+    /// Chimp decoding (`fphdec*`), checked
+    fn try_hdec(input: &[u8], n: usize, output: &mut [Self], start: Self) -> Result<usize>;
+    /// TurboFloatLz encoding (`fpcenc*`)
+    fn cenc(input: &[Self], output: &mut [u8], start: Self) -> usize;
+    /// TurboFloatLz decoding (`fpcdec*`), checked
+    fn try_cdec(input: &[u8], n: usize, output: &mut [Self], start: Self) -> Result<usize>;
+    /// FCM predictor encoding (`fpfcmenc*`)
+    fn fcmenc(input: &[Self], output: &mut [u8], start: Self) -> usize;
+    /// FCM predictor decoding (`fpfcmdec*`), checked
+    fn try_fcmdec(input: &[u8], n: usize, output: &mut [Self], start: Self) -> Result<usize>;
+    /// DFCM predictor encoding (`fpdfcmenc*`)
+    fn dfcmenc(input: &[Self], output: &mut [u8], start: Self) -> usize;
+    /// DFCM predictor decoding (`fpdfcmdec*`), checked
+    fn try_dfcmdec(input: &[u8], n: usize, output: &mut [Self], start: Self) -> Result<usize>;
+    /// XOR with the last value encoding (`fpxenc*`)
+    fn xenc(input: &[Self], output: &mut [u8], start: Self) -> usize;
+    /// XOR with the last value decoding (`fpxdec*`), checked
+    fn try_xdec(input: &[u8], n: usize, output: &mut [Self], start: Self) -> Result<usize>;
+}
+
+// `f32`/`f64` have the size and alignment of `u32`/`u64` and every bit pattern is valid for both types,
+// so the slices can be reinterpreted in place.
+
+fn bits32(input: &[f32]) -> &[u32] {
+    unsafe { std::slice::from_raw_parts(input.as_ptr() as *const u32, input.len()) }
+}
+
+fn bits32_mut(output: &mut [f32]) -> &mut [u32] {
+    unsafe { std::slice::from_raw_parts_mut(output.as_mut_ptr() as *mut u32, output.len()) }
+}
+
+fn bits64(input: &[f64]) -> &[u64] {
+    unsafe { std::slice::from_raw_parts(input.as_ptr() as *const u64, input.len()) }
+}
+
+fn bits64_mut(output: &mut [f64]) -> &mut [u64] {
+    unsafe { std::slice::from_raw_parts_mut(output.as_mut_ptr() as *mut u64, output.len()) }
+}
+
+impl FloatCodec for f32 {
+    fn max_compressed_size(n: usize) -> usize { fp::bound32(n) }
+    fn genc(input: &[f32], output: &mut [u8], start: f32) -> usize { fp::genc32(bits32(input), output, start.to_bits()) }
+    fn try_gdec(input: &[u8], n: usize, output: &mut [f32], start: f32) -> Result<usize> { fp::try_gdec32(input, n, bits32_mut(output), start.to_bits()) }
+    fn henc(input: &[f32], output: &mut [u8], start: f32) -> usize { fp::henc32(bits32(input), output, start.to_bits()) }
+    fn try_hdec(input: &[u8], n: usize, output: &mut [f32], start: f32) -> Result<usize> { fp::try_hdec32(input, n, bits32_mut(output), start.to_bits()) }
+    fn cenc(input: &[f32], output: &mut [u8], start: f32) -> usize { fp::cenc32(bits32(input), output, start.to_bits()) }
+    fn try_cdec(input: &[u8], n: usize, output: &mut [f32], start: f32) -> Result<usize> { fp::try_cdec32(input, n, bits32_mut(output), start.to_bits()) }
+    fn fcmenc(input: &[f32], output: &mut [u8], start: f32) -> usize { fp::fcmenc32(bits32(input), output, start.to_bits()) }
+    fn try_fcmdec(input: &[u8], n: usize, output: &mut [f32], start: f32) -> Result<usize> { fp::try_fcmdec32(input, n, bits32_mut(output), start.to_bits()) }
+    fn dfcmenc(input: &[f32], output: &mut [u8], start: f32) -> usize { fp::dfcmenc32(bits32(input), output, start.to_bits()) }
+    fn try_dfcmdec(input: &[u8], n: usize, output: &mut [f32], start: f32) -> Result<usize> { fp::try_dfcmdec32(input, n, bits32_mut(output), start.to_bits()) }
+    fn xenc(input: &[f32], output: &mut [u8], start: f32) -> usize { fp::xenc32(bits32(input), output, start.to_bits()) }
+    fn try_xdec(input: &[u8], n: usize, output: &mut [f32], start: f32) -> Result<usize> { fp::try_xdec32(input, n, bits32_mut(output), start.to_bits()) }
+}
+
+impl FloatCodec for f64 {
+    fn max_compressed_size(n: usize) -> usize { fp::bound64(n) }
+    fn genc(input: &[f64], output: &mut [u8], start: f64) -> usize { fp::genc64(bits64(input), output, start.to_bits()) }
+    fn try_gdec(input: &[u8], n: usize, output: &mut [f64], start: f64) -> Result<usize> { fp::try_gdec64(input, n, bits64_mut(output), start.to_bits()) }
+    fn henc(input: &[f64], output: &mut [u8], start: f64) -> usize { fp::henc64(bits64(input), output, start.to_bits()) }
+    fn try_hdec(input: &[u8], n: usize, output: &mut [f64], start: f64) -> Result<usize> { fp::try_hdec64(input, n, bits64_mut(output), start.to_bits()) }
+    fn cenc(input: &[f64], output: &mut [u8], start: f64) -> usize { fp::cenc64(bits64(input), output, start.to_bits()) }
+    fn try_cdec(input: &[u8], n: usize, output: &mut [f64], start: f64) -> Result<usize> { fp::try_cdec64(input, n, bits64_mut(output), start.to_bits()) }
+    fn fcmenc(input: &[f64], output: &mut [u8], start: f64) -> usize { fp::fcmenc64(bits64(input), output, start.to_bits()) }
+    fn try_fcmdec(input: &[u8], n: usize, output: &mut [f64], start: f64) -> Result<usize> { fp::try_fcmdec64(input, n, bits64_mut(output), start.to_bits()) }
+    fn dfcmenc(input: &[f64], output: &mut [u8], start: f64) -> usize { fp::dfcmenc64(bits64(input), output, start.to_bits()) }
+    fn try_dfcmdec(input: &[u8], n: usize, output: &mut [f64], start: f64) -> Result<usize> { fp::try_dfcmdec64(input, n, bits64_mut(output), start.to_bits()) }
+    fn xenc(input: &[f64], output: &mut [u8], start: f64) -> usize { fp::xenc64(bits64(input), output, start.to_bits()) }
+    fn try_xdec(input: &[u8], n: usize, output: &mut [f64], start: f64) -> Result<usize> { fp::try_xdec64(input, n, bits64_mut(output), start.to_bits()) }
+}
+
+/// Trait for float encodings, the counterpart of `generic::Encoding`
+pub trait FloatEncoding {
+    type F: FloatCodec;
+    /// Numeric ID of the encoding, distinct from the `Encoding::ENC_TYPE`s
+    const ENC_TYPE: u8;
+    /// Encode `input` into `output`, predicting the first float from `start`
+    fn encode_with_start(input: &[Self::F], output: &mut [u8], start: Self::F) -> usize;
+    /// Decode `n` floats from `input` into `output`, `start` as given to the encoder, checking the buffer size
+    /// against `dec_buf_len` and the input for truncation
+    fn try_decode_with_start(input: &[u8], n: usize, output: &mut [Self::F], start: Self::F) -> Result<usize>;
+    /// Decode `n` floats from `input` into `output`, `start` as given to the encoder
+    /// # Panics
+    /// If `try_decode_with_start` fails
+    fn decode_with_start(input: &[u8], n: usize, output: &mut [Self::F], start: Self::F) -> usize {
+        Self::try_decode_with_start(input, n, output, start).unwrap_or_else(|e| panic!("FloatEncoding::decode: {}", e))
+    }
+    /// Encode `input` into `output` with `start` 0.0
+    fn encode(input: &[Self::F], output: &mut [u8]) -> usize {
+        Self::encode_with_start(input, output, Self::F::default())
+    }
+    /// Decode `n` floats encoded with `encode`
+    fn decode(input: &[u8], n: usize, output: &mut [Self::F]) -> usize {
+        Self::decode_with_start(input, n, output, Self::F::default())
+    }
+    /// Upper bound of the compressed size of `n` floats, the minimum safe [u8] size for encoding
+    fn max_compressed_size(n: usize) -> usize {
+        Self::F::max_compressed_size(n)
+    }
+    /// Minimum safe [F] length required for decoding `n` floats
+    fn dec_buf_len(n: usize) -> usize {
+        W::dec_buf_len::<Self::F>(n)
+    }
+    /// Encode `input` into `output`, checking the buffer size against `max_compressed_size`
+    fn try_encode(input: &[Self::F], output: &mut [u8]) -> Result<usize> {
+        check_output(Self::max_compressed_size(input.len()), output.len())?;
+        Ok(Self::encode(input, output))
+    }
+    /// Decode `n` floats encoded with `encode`, checking the buffer size against `dec_buf_len`
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::F]) -> Result<usize> {
+        Self::try_decode_with_start(input, n, output, Self::F::default())
+    }
+}
+
+pub struct GorillaEncoding<F>  { _marker_f: PhantomData<F> }
+pub struct ChimpEncoding<F>    { _marker_f: PhantomData<F> }
+pub struct FloatLzEncoding<F>  { _marker_f: PhantomData<F> }
+pub struct FcmEncoding<F>      { _marker_f: PhantomData<F> }
+pub struct DfcmEncoding<F>     { _marker_f: PhantomData<F> }
+pub struct FloatXorEncoding<F> { _marker_f: PhantomData<F> }
+
+impl<F: FloatCodec> FloatEncoding for GorillaEncoding<F> {
+    type F = F;
+    const ENC_TYPE: u8 = 18;
+    fn encode_with_start(input: &[F], output: &mut [u8], start: F) -> usize { F::genc(input, output, start) }
+    fn try_decode_with_start(input: &[u8], n: usize, output: &mut [F], start: F) -> Result<usize> { F::try_gdec(input, n, output, start) }
+}
+
+impl<F: FloatCodec> FloatEncoding for ChimpEncoding<F> {
+    type F = F;
+    const ENC_TYPE: u8 = 19;
+    fn encode_with_start(input: &[F], output: &mut [u8], start: F) -> usize { F::henc(input, output, start) }
+    fn try_decode_with_start(input: &[u8], n: usize, output: &mut [F], start: F) -> Result<usize> { F::try_hdec(input, n, output, start) }
+}
+
+impl<F: FloatCodec> FloatEncoding for FloatLzEncoding<F> {
+    type F = F;
+    const ENC_TYPE: u8 = 20;
+    fn encode_with_start(input: &[F], output: &mut [u8], start: F) -> usize { F::cenc(input, output, start) }
+    fn try_decode_with_start(input: &[u8], n: usize, output: &mut [F], start: F) -> Result<usize> { F::try_cdec(input, n, output, start) }
+}
+
+impl<F: FloatCodec> FloatEncoding for FcmEncoding<F> {
+    type F = F;
+    const ENC_TYPE: u8 = 21;
+    fn encode_with_start(input: &[F], output: &mut [u8], start: F) -> usize { F::fcmenc(input, output, start) }
+    fn try_decode_with_start(input: &[u8], n: usize, output: &mut [F], start: F) -> Result<usize> { F::try_fcmdec(input, n, output, start) }
+}
+
+impl<F: FloatCodec> FloatEncoding for DfcmEncoding<F> {
+    type F = F;
+    const ENC_TYPE: u8 = 22;
+    fn encode_with_start(input: &[F], output: &mut [u8], start: F) -> usize { F::dfcmenc(input, output, start) }
+    fn try_decode_with_start(input: &[u8], n: usize, output: &mut [F], start: F) -> Result<usize> { F::try_dfcmdec(input, n, output, start) }
+}
+
+impl<F: FloatCodec> FloatEncoding for FloatXorEncoding<F> {
+    type F = F;
+    const ENC_TYPE: u8 = 23;
+    fn encode_with_start(input: &[F], output: &mut [u8], start: F) -> usize { F::xenc(input, output, start) }
+    fn try_decode_with_start(input: &[u8], n: usize, output: &mut [F], start: F) -> Result<usize> { F::try_xdec(input, n, output, start) }
+}
+
+#[cfg(test)]
+fn test_float_generic<E: FloatEncoding>(input: &[E::F], start: E::F) -> usize {
+    let mut buf = vec![0u8; E::max_compressed_size(input.len())];
+    let size = E::encode_with_start(input, &mut buf, start);
+    let mut output = vec![E::F::default(); E::dec_buf_len(input.len())];
+    assert_eq!(E::decode_with_start(&buf[..size], input.len(), &mut output, start), size);
+    assert_eq!(bits_of(input), bits_of(&output[..input.len()]));
+    assert_eq!(E::try_encode(input, &mut buf), Ok(E::encode(input, &mut buf)));
+    size
+}
+
+/// The bit patterns of `input`, so that NaNs compare equal
+#[cfg(test)]
+fn bits_of<F: FloatCodec>(input: &[F]) -> Vec<u8> {
+    let size = std::mem::size_of_val(input);
+    unsafe { std::slice::from_raw_parts(input.as_ptr() as *const u8, size) }.to_vec()
+}
+
+#[cfg(test)]
+fn test_float_all<F: FloatCodec>(input: &[F], start: F) -> Vec<usize> {
+    vec![
+        test_float_generic::<GorillaEncoding<F>>(input, start),
+        test_float_generic::<ChimpEncoding<F>>(input, start),
+        test_float_generic::<FloatLzEncoding<F>>(input, start),
+        test_float_generic::<FcmEncoding<F>>(input, start),
+        test_float_generic::<DfcmEncoding<F>>(input, start),
+        test_float_generic::<FloatXorEncoding<F>>(input, start),
+    ]
+}
+
+#[test]
+fn test_float() {
+    // a slowly drifting temperature read with two decimals
+    let input: Vec<f64> = (0..10_000).map(|i| (2000.0 + 300.0 * (i as f64 / 500.0).sin()).round() / 100.0).collect();
+    for size in test_float_all(&input, 0.0) {
+        assert!(size < 8 * input.len());
+    }
+    test_float_all(&input, input[0]);
+    let input: Vec<f32> = input.iter().map(|&x| x as f32).collect();
+    test_float_all(&input, 0.0);
+    test_float_all(&input, -1.5);
+    let input: Vec<f32> = vec![0.0, -0.0, f32::NAN, f32::INFINITY, f32::NEG_INFINITY, f32::MIN_POSITIVE, 1e-40, f32::MAX];
+    test_float_all(&input, f32::NAN);
+    test_float_all::<f64>(&[], 0.0);
+}
+
+#[test]
+fn test_float_vs_integer() {
+    // a periodic signal: the FCM/DFCM hash tables learn the cycle, while the differences of the bit patterns stay
+    // as wide as the patterns themselves for the integer codecs
+    let input: Vec<f64> = (0..10_000).map(|i| [1.5, 2.75, -0.125][i % 3]).collect();
+    let bits: Vec<u64> = input.iter().map(|x| x.to_bits()).collect();
+    let mut buf = vec![0u8; crate::p4::bound64(bits.len())];
+    let integer_size = crate::p4::zenc64(&bits, &mut buf);
+    assert!(integer_size > 6 * input.len());
+    assert!(4 * test_float_generic::<FcmEncoding<f64>>(&input, 0.0) < integer_size);
+    assert!(4 * test_float_generic::<DfcmEncoding<f64>>(&input, 0.0) < integer_size);
+}
+
+#[test]
+fn test_float_try_decode_truncated() {
+    let input: Vec<f64> = (0..1000).map(|i| (i as f64 / 10.0).sin()).collect();
+    let mut buf = vec![0u8; GorillaEncoding::<f64>::max_compressed_size(input.len())];
+    let size = GorillaEncoding::<f64>::encode(&input, &mut buf);
+    let mut output = vec![0f64; GorillaEncoding::<f64>::dec_buf_len(input.len())];
+    let truncated = buf[..size / 2].to_vec();
+    assert!(GorillaEncoding::<f64>::try_decode(&truncated, input.len(), &mut output).is_err());
+    assert_eq!(GorillaEncoding::<f64>::try_decode(&buf[..size], input.len(), &mut output), Ok(size));
+}
@@ -0,0 +1,125 @@
+/// Benchmark of the float encodings on synthetic sensor series
+///
+/// Each series is compressed with every `FloatEncoding` and, for comparison, with `ZigZagEncoding` on the
+/// bit patterns, i.e. treating the floats as plain integers. Prints the compression ratio and the
+/// encoding/decoding speed in MB/s of uncompressed data.
+use rand::Rng;
+use std::time::Instant;
+use turbopfor_rs::codec::*;
+use turbopfor_rs::float::*;
+use turbopfor_rs::generic::*;
+use turbopfor_rs::sample::Arithmetic;
+
+const LEN: usize = 1_000_000;
+const ROUNDS: usize = 10;
+
+/// Round `x` to `decimals` decimals, like a sensor with a fixed resolution
+fn quantize(x: f64, decimals: i32) -> f64 {
+    let scale = 10f64.powi(decimals);
+    (x * scale).round() / scale
+}
+
+/// Temperature in °C sampled every minute: daily cycle, slow weather drift and noise, two decimals
+fn temperature() -> Vec<f64> {
+    let mut rng = rand::thread_rng();
+    let mut drift = 0.0;
+    (0..LEN).map(|i| {
+        drift += rng.gen_range(-0.01..0.01);
+        let day = (i as f64 / 1440.0 * std::f64::consts::TAU).sin();
+        quantize(15.0 + 5.0 * day + drift + rng.gen_range(-0.05..0.05), 2)
+    }).collect()
+}
+
+/// Air pressure in hPa, changing slowly, one decimal
+fn pressure() -> Vec<f32> {
+    let mut rng = rand::thread_rng();
+    let mut p = 1013.0f64;
+    (0..LEN).map(|_| {
+        p += rng.gen_range(-0.02..0.02);
+        quantize(p, 1) as f32
+    }).collect()
+}
+
+/// Humidity in %, constant for long stretches with occasional steps
+fn humidity() -> Vec<f32> {
+    let mut rng = rand::thread_rng();
+    let mut h = 50.0f32;
+    (0..LEN).map(|_| {
+        if rng.gen_range(0..200) == 0 {
+            h = (h + rng.gen_range(-2..=2) as f32).clamp(0.0, 100.0);
+        }
+        h
+    }).collect()
+}
+
+/// Print size and speed of `E` on `input`
+fn bench<E: FloatEncoding>(name: &str, input: &[E::F]) {
+    let mut buf = vec![0u8; E::max_compressed_size(input.len())];
+    let mut output = vec![E::F::default(); E::dec_buf_len(input.len())];
+    let t = Instant::now();
+    let mut size = 0;
+    for _ in 0..ROUNDS {
+        size = E::encode(input, &mut buf);
+    }
+    let t_enc = t.elapsed().as_secs_f64();
+    let t = Instant::now();
+    for _ in 0..ROUNDS {
+        E::decode(&buf[..size], input.len(), &mut output);
+    }
+    let t_dec = t.elapsed().as_secs_f64();
+    report(name, std::mem::size_of_val(input), size, t_enc, t_dec);
+}
+
+/// Print size and speed of `ZigZagEncoding` on the bit patterns in `input`
+fn bench_integer<T: DeltaCodec<W> + Arithmetic + Default>(name: &str, input: &[T])
+    where rand::distributions::Standard: rand::prelude::Distribution<T>
+{
+    type E<T> = ZigZagEncoding<W, T>;
+    let mut buf = vec![0u8; E::<T>::enc_buf_size(input.len())];
+    let mut output = vec![T::default(); E::<T>::dec_buf_len(input.len())];
+    let t = Instant::now();
+    let mut size = 0;
+    for _ in 0..ROUNDS {
+        size = E::<T>::encode(input, &mut buf);
+    }
+    let t_enc = t.elapsed().as_secs_f64();
+    let t = Instant::now();
+    for _ in 0..ROUNDS {
+        E::<T>::decode(&buf[..size], input.len(), &mut output);
+    }
+    let t_dec = t.elapsed().as_secs_f64();
+    report(name, std::mem::size_of_val(input), size, t_enc, t_dec);
+}
+
+fn report(name: &str, raw: usize, size: usize, t_enc: f64, t_dec: f64) {
+    let mb = (raw * ROUNDS) as f64 / 1e6;
+    println!("{:<24} {:>10} {:>7.2}% {:>9.1} {:>9.1}", name, size, 100.0 * size as f64 / raw as f64, mb / t_enc, mb / t_dec);
+}
+
+fn bench_all<F: FloatCodec>(input: &[F]) {
+    bench::<GorillaEncoding<F>>("GorillaEncoding", input);
+    bench::<ChimpEncoding<F>>("ChimpEncoding", input);
+    bench::<FloatLzEncoding<F>>("FloatLzEncoding", input);
+    bench::<FcmEncoding<F>>("FcmEncoding", input);
+    bench::<DfcmEncoding<F>>("DfcmEncoding", input);
+    bench::<FloatXorEncoding<F>>("FloatXorEncoding", input);
+}
+
+fn main() {
+    println!("{:<24} {:>10} {:>8} {:>9} {:>9}", "encoding", "size", "ratio", "E MB/s", "D MB/s");
+    println!("temperature (f64, {} values)", LEN);
+    let input = temperature();
+    bench_all(&input);
+    let bits: Vec<u64> = input.iter().map(|x| x.to_bits()).collect();
+    bench_integer("ZigZagEncoding (bits)", &bits);
+    println!("pressure (f32, {} values)", LEN);
+    let input = pressure();
+    bench_all(&input);
+    let bits: Vec<u32> = input.iter().map(|x| x.to_bits()).collect();
+    bench_integer("ZigZagEncoding (bits)", &bits);
+    println!("humidity (f32, {} values)", LEN);
+    let input = humidity();
+    bench_all(&input);
+    let bits: Vec<u32> = input.iter().map(|x| x.to_bits()).collect();
+    bench_integer("ZigZagEncoding (bits)", &bits);
+}
@@ -0,0 +1,628 @@
+/// Self-describing frames: a small versioned header followed by the encoded payload.
+///
+/// `decode` needs the number of integers, the element type, the width and the encoding of the data.
+/// A frame records all of them, so a compressed blob becomes a self-contained value that can be stored,
+/// e.g., in a database column. The header is `Header::LEN` bytes long, all integers are little endian:
+///
+/// | offset | size | field                                    |
+/// |--------|------|------------------------------------------|
+/// | 0      | 4    | magic `TPFR`                             |
+/// | 4      | 1    | format version, currently 1              |
+/// | 5      | 1    | `Encoding::ENC_TYPE`                     |
+/// | 6      | 1    | `Width::WIDTH_ID`                        |
+/// | 7      | 1    | bit width of the element type            |
+/// | 8      | 1    | flags, `FLAG_CHECKSUM`, `FLAG_SIGNED`    |
+/// | 9      | 8    | number of integers                       |
+/// | 17     | 8    | payload length in bytes                  |
+///
+/// With `FLAG_CHECKSUM` set the payload is followed by the CRC32C of header and payload. `FLAG_SIGNED` marks frames of
+/// signed integers, which share the encodings and widths of the unsigned ones.
+///
+/// `decode::<T>` reads a frame of any encoding of `T` (see `FrameType`), `decode_as::<E>` only frames written with `E`.
+/// Frames are meant to be read back from untrusted storage: a count above `Encoding::max_count` of the payload length
+/// is rejected and the `p4` payloads are validated (see `validate`) before the output is allocated, the other
+/// encodings rely on the checks of the safe decoders.
+use crate::checksum::*;
+use crate::codec::*;
+use crate::error::*;
+use crate::generic::*;
+use crate::validate::*;
+use std::mem::size_of;
+
+/// Magic bytes at the start of every frame
+pub const MAGIC: [u8; 4] = *b"TPFR";
+/// Current frame format version
+pub const VERSION: u8 = 1;
+/// Flag: the payload is followed by a CRC32C of header and payload
+pub const FLAG_CHECKSUM: u8 = 0x01;
+/// Flag: the integers are signed, set from `FrameType::SIGNED`
+pub const FLAG_SIGNED: u8 = 0x02;
+/// All flags known to this version
+const FLAGS: u8 = FLAG_CHECKSUM | FLAG_SIGNED;
+
+/// Frame header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Header {
+    pub version: u8,
+    pub enc_type: u8,
+    pub width_id: u8,
+    pub bits: u8,
+    pub flags: u8,
+    pub count: u64,
+    pub payload_len: u64,
+}
+
+impl Header {
+    /// Length of the serialized header in bytes
+    pub const LEN: usize = 25;
+
+    /// Header for `count` integers encoded with `E` into `payload_len` bytes, `FLAG_SIGNED` is added for signed types
+    pub fn new<E: Encoding>(count: usize, payload_len: usize, flags: u8) -> Header
+        where E::T: FrameType
+    {
+        let flags = if E::T::SIGNED { flags | FLAG_SIGNED } else { flags };
+        Header {
+            version: VERSION,
+            enc_type: E::ENC_TYPE,
+            width_id: E::W::WIDTH_ID,
+            bits: (8 * size_of::<E::T>()) as u8,
+            flags,
+            count: count as u64,
+            payload_len: payload_len as u64,
+        }
+    }
+
+    /// Serialize the header into `Header::LEN` bytes
+    pub fn to_bytes(&self) -> [u8; Header::LEN] {
+        let mut bytes = [0u8; Header::LEN];
+        bytes[0..4].copy_from_slice(&MAGIC);
+        bytes[4] = self.version;
+        bytes[5] = self.enc_type;
+        bytes[6] = self.width_id;
+        bytes[7] = self.bits;
+        bytes[8] = self.flags;
+        bytes[9..17].copy_from_slice(&self.count.to_le_bytes());
+        bytes[17..25].copy_from_slice(&self.payload_len.to_le_bytes());
+        bytes
+    }
+
+    /// Read the header at the start of `input`, e.g. to inspect a frame before decoding it
+    /// # Returns
+    /// The header, or `InputTruncated`, `InvalidMagic` or `FrameMismatch` for an unsupported version
+    pub fn read(input: &[u8]) -> Result<Header> {
+        if input.len() < Header::LEN {
+            return Err(TurboPforError::InputTruncated { needed: Header::LEN, got: input.len() });
+        }
+        if input[0..4] != MAGIC {
+            return Err(TurboPforError::InvalidMagic);
+        }
+        let header = Header {
+            version: input[4],
+            enc_type: input[5],
+            width_id: input[6],
+            bits: input[7],
+            flags: input[8],
+            count: u64::from_le_bytes(input[9..17].try_into().unwrap()),
+            payload_len: u64::from_le_bytes(input[17..25].try_into().unwrap()),
+        };
+        check_field("version", VERSION as u64, header.version as u64)?;
+        Ok(header)
+    }
+
+    /// Check that the frame was written with encoding `E`
+    pub fn check<E: Encoding>(&self) -> Result<()>
+        where E::T: FrameType
+    {
+        check_field("encoding", E::ENC_TYPE as u64, self.enc_type as u64)?;
+        check_field("width", E::W::WIDTH_ID as u64, self.width_id as u64)?;
+        self.check_type::<E::T>()
+    }
+
+    /// Check that the frame holds integers of type `T` and has no unknown flags
+    pub fn check_type<T: FrameType>(&self) -> Result<()> {
+        check_field("bits", 8 * size_of::<T>() as u64, self.bits as u64)?;
+        check_field("signed", T::SIGNED as u64, (self.flags & FLAG_SIGNED != 0) as u64)?;
+        check_field("flags", (self.flags & FLAGS) as u64, self.flags as u64)
+    }
+
+    /// `true` if the encoding and width of the frame are those of `E`
+    pub fn is<E: Encoding>(&self) -> bool {
+        self.enc_type == E::ENC_TYPE && self.width_id == E::W::WIDTH_ID
+    }
+}
+
+fn check_field(field: &'static str, expected: u64, got: u64) -> Result<()> {
+    if expected != got {
+        return Err(TurboPforError::FrameMismatch { field, expected, got });
+    }
+    Ok(())
+}
+
+/// Encode `input` with `E` into a frame
+pub fn encode<E: Encoding>(input: &[E::T]) -> Vec<u8>
+    where E::T: FrameType
+{
+    encode_with_flags::<E>(input, 0)
+}
+
+/// Encode `input` with `E` into a frame with `FLAG_CHECKSUM` set
+pub fn encode_with_checksum<E: Encoding>(input: &[E::T]) -> Vec<u8>
+    where E::T: FrameType
+{
+    encode_with_flags::<E>(input, FLAG_CHECKSUM)
+}
+
+fn encode_with_flags<E: Encoding>(input: &[E::T], flags: u8) -> Vec<u8>
+    where E::T: FrameType
+{
+    let mut output = vec![0u8; Header::LEN + E::enc_buf_size(input.len()) + CHECKSUM_LEN];
+    let size = E::encode(input, &mut output[Header::LEN..]);
+    output[..Header::LEN].copy_from_slice(&Header::new::<E>(input.len(), size, flags).to_bytes());
+    let mut len = Header::LEN + size;
+    if flags & FLAG_CHECKSUM != 0 {
+        len = put_checksum(&mut output, len);
+    }
+    output.truncate(len);
+    output
+}
+
+/// Element types of frames, `decode` looks up the encoding of a frame among the encodings of the type
+pub trait FrameType: Sized {
+    /// Signed type, recorded as `FLAG_SIGNED`: a frame of `i32` doesn't decode as `u32` or vice versa
+    const SIGNED: bool;
+    /// Decode the payload of `n` integers of a frame written with the encoding and width given by `header`
+    /// # Returns
+    /// The decoded integers, or `UnknownEncoding` if the type has no such encoding
+    fn decode_payload(header: &Header, payload: &[u8], n: usize) -> Result<Vec<Self>>;
+}
+
+/// Decode a frame written by `encode` or `encode_with_checksum` with any encoding of `T`
+/// # Returns
+/// The decoded integers, or `FrameMismatch` if the frame holds another type, `UnknownEncoding` if its encoding
+/// doesn't exist for `T`, or `ChecksumMismatch` if the frame has a checksum that doesn't match
+pub fn decode<T: FrameType>(input: &[u8]) -> Result<Vec<T>> {
+    let header = Header::read(input)?;
+    header.check_type::<T>()?;
+    let (payload, n) = read_payload(input, &header)?;
+    T::decode_payload(&header, payload, n)
+}
+
+/// Decode a frame written by `encode::<E>` or `encode_with_checksum::<E>`
+/// # Returns
+/// The decoded integers, or `FrameMismatch` if the frame was written with a different encoding, width or type,
+/// or `ChecksumMismatch` if the frame has a checksum that doesn't match
+pub fn decode_as<E: Encoding>(input: &[u8]) -> Result<Vec<E::T>>
+    where E::T: FrameType + Copy + Default
+{
+    let header = Header::read(input)?;
+    header.check::<E>()?;
+    let (payload, n) = read_payload(input, &header)?;
+    decode_payload_with::<E>(payload, n, None)
+}
+
+/// The payload of the frame `input` after checking its length and checksum, and the number of integers
+fn read_payload<'a>(input: &'a [u8], header: &Header) -> Result<(&'a [u8], usize)> {
+    let checksum_len = if header.flags & FLAG_CHECKSUM != 0 { CHECKSUM_LEN } else { 0 };
+    let frame_len = usize::try_from(header.payload_len).unwrap_or(usize::MAX)
+        .saturating_add(Header::LEN + checksum_len);
+    if input.len() < frame_len {
+        return Err(TurboPforError::InputTruncated { needed: frame_len, got: input.len() });
+    }
+    let mut frame = &input[..frame_len];
+    if checksum_len > 0 {
+        frame = check_checksum(frame)?;
+    }
+    let n = usize::try_from(header.count).map_err(|_| TurboPforError::FrameMismatch { field: "count", expected: usize::MAX as u64, got: header.count })?;
+    Ok((&frame[Header::LEN..], n))
+}
+
+/// Block header walk of a `p4` payload, `validate::<E>`
+type Walk = fn(&[u8], usize) -> Result<usize>;
+
+/// Decode the `n` integers of a payload written with `E`, which must fill the whole payload. The count is checked
+/// against `Encoding::max_count` and a `p4` payload is walked with `walk` before the output is allocated.
+fn decode_payload_with<E: Encoding>(payload: &[u8], n: usize, walk: Option<Walk>) -> Result<Vec<E::T>>
+    where E::T: Copy + Default
+{
+    let max = E::max_count(payload.len());
+    let too_many = || TurboPforError::FrameMismatch { field: "count", expected: max as u64, got: n as u64 };
+    if n > max {
+        return Err(too_many());
+    }
+    if let Some(walk) = walk {
+        let size = walk(payload, n)?;
+        check_field("payload length", payload.len() as u64, size as u64)?;
+    }
+    let len = E::dec_buf_len(n);
+    let mut output = Vec::new();
+    output.try_reserve_exact(len).map_err(|_| too_many())?;
+    output.resize(len, E::T::default());
+    let size = E::try_decode(payload, n, &mut output)?;
+    check_field("payload length", payload.len() as u64, size as u64)?;
+    output.truncate(n);
+    Ok(output)
+}
+
+/// `FrameType` for `$t`, looking up the encoding of a frame by `(ENC_TYPE, WIDTH_ID)` among those listed.
+/// The `p4` encodings under `validated` are validated before decoding, see `decode_payload_with`.
+macro_rules! frame_type {
+    ($t:ty, $signed:expr, validated: [$($v:ty),* $(,)?], unvalidated: [$($u:ty),* $(,)?]) => {
+        impl FrameType for $t {
+            const SIGNED: bool = $signed;
+            fn decode_payload(header: &Header, payload: &[u8], n: usize) -> Result<Vec<$t>> {
+                match (header.enc_type, header.width_id) {
+                    $((<$v as Encoding>::ENC_TYPE, <<$v as Encoding>::W as Width>::WIDTH_ID) =>
+                        decode_payload_with::<$v>(payload, n, Some(validate::<$v>)),)*
+                    $((<$u as Encoding>::ENC_TYPE, <<$u as Encoding>::W as Width>::WIDTH_ID) =>
+                        decode_payload_with::<$u>(payload, n, None),)*
+                    (enc_type, width_id) => Err(TurboPforError::UnknownEncoding { enc_type, width_id }),
+                }
+            }
+        }
+    };
+}
+
+frame_type!(u8, false,
+    validated: [
+        StandardEncoding<W, u8>,
+        IncreasingEncoding<W, u8>,
+        StrictlyIncreasingEncoding<W, u8>,
+        ZigZagEncoding<W, u8>,
+    ],
+    unvalidated: [
+        SimpleVEncoding<u8>,
+        DeltaOfDeltaEncoding<W128v, u8>,
+        BitIoZigZagEncoding<u8>,
+        BitIoDeltaOfDeltaEncoding<u8>,
+        XorEncoding<W, u8>,
+        BitPackEncoding<W, u8>,
+        BitPackDeltaEncoding<W, u8>,
+        BitPackStrictDeltaEncoding<W, u8>,
+        BitPackForEncoding<W, u8>,
+        BitPackZigZagEncoding<W, u8>,
+    ]);
+
+frame_type!(u16, false,
+    validated: [
+        StandardEncoding<W, u16>,
+        IncreasingEncoding<W, u16>,
+        StrictlyIncreasingEncoding<W, u16>,
+        ZigZagEncoding<W, u16>,
+        StandardEncoding<W128v, u16>,
+        IncreasingEncoding<W128v, u16>,
+        StrictlyIncreasingEncoding<W128v, u16>,
+        ZigZagEncoding<W128v, u16>,
+    ],
+    unvalidated: [
+        VByteEncoding<u16>,
+        VByteDeltaEncoding<u16>,
+        VByteDelta1Encoding<u16>,
+        VByteZigZagEncoding<u16>,
+        VByteXorEncoding<u16>,
+        VByteDeltaOfDeltaEncoding<u16>,
+        TurboByteEncoding<W, u16>,
+        TurboByteDeltaEncoding<W, u16>,
+        TurboByteDelta1Encoding<W, u16>,
+        TurboByteZigZagEncoding<W, u16>,
+        TurboByteXorEncoding<W, u16>,
+        TurboByteEncoding<W128v, u16>,
+        TurboByteDeltaEncoding<W128v, u16>,
+        TurboByteDelta1Encoding<W128v, u16>,
+        TurboByteZigZagEncoding<W128v, u16>,
+        TurboByteXorEncoding<W128v, u16>,
+        SimpleVEncoding<u16>,
+        DeltaOfDeltaEncoding<W128v, u16>,
+        BitIoZigZagEncoding<u16>,
+        BitIoDeltaOfDeltaEncoding<u16>,
+        XorEncoding<W, u16>,
+        XorEncoding<W128v, u16>,
+        BitPackEncoding<W, u16>,
+        BitPackDeltaEncoding<W, u16>,
+        BitPackStrictDeltaEncoding<W, u16>,
+        BitPackForEncoding<W, u16>,
+        BitPackZigZagEncoding<W, u16>,
+        BitPackEncoding<W128v, u16>,
+        BitPackDeltaEncoding<W128v, u16>,
+        BitPackStrictDeltaEncoding<W128v, u16>,
+        BitPackForEncoding<W128v, u16>,
+        BitPackZigZagEncoding<W128v, u16>,
+    ]);
+
+frame_type!(u32, false,
+    validated: [
+        StandardEncoding<W, u32>,
+        IncreasingEncoding<W, u32>,
+        StrictlyIncreasingEncoding<W, u32>,
+        ZigZagEncoding<W, u32>,
+        StandardEncoding<W128v, u32>,
+        IncreasingEncoding<W128v, u32>,
+        StrictlyIncreasingEncoding<W128v, u32>,
+        ZigZagEncoding<W128v, u32>,
+        StandardEncoding<W256v, u32>,
+        IncreasingEncoding<W256v, u32>,
+        StrictlyIncreasingEncoding<W256v, u32>,
+        ZigZagEncoding<W256v, u32>,
+    ],
+    unvalidated: [
+        VByteEncoding<u32>,
+        VByteDeltaEncoding<u32>,
+        VByteDelta1Encoding<u32>,
+        VByteZigZagEncoding<u32>,
+        VByteXorEncoding<u32>,
+        VByteDeltaOfDeltaEncoding<u32>,
+        TurboByteEncoding<W, u32>,
+        TurboByteDeltaEncoding<W, u32>,
+        TurboByteDelta1Encoding<W, u32>,
+        TurboByteZigZagEncoding<W, u32>,
+        TurboByteXorEncoding<W, u32>,
+        TurboByteEncoding<W128v, u32>,
+        TurboByteDeltaEncoding<W128v, u32>,
+        TurboByteDelta1Encoding<W128v, u32>,
+        TurboByteZigZagEncoding<W128v, u32>,
+        TurboByteXorEncoding<W128v, u32>,
+        TurboByteEncoding<W256v, u32>,
+        TurboByteDeltaEncoding<W256v, u32>,
+        TurboByteDelta1Encoding<W256v, u32>,
+        TurboByteZigZagEncoding<W256v, u32>,
+        TurboByteXorEncoding<W256v, u32>,
+        SimpleVEncoding<u32>,
+        EliasFanoEncoding<W, u32>,
+        StrictEliasFanoEncoding<W, u32>,
+        EliasFanoEncoding<W128v, u32>,
+        StrictEliasFanoEncoding<W128v, u32>,
+        EliasFanoEncoding<W256v, u32>,
+        StrictEliasFanoEncoding<W256v, u32>,
+        DeltaOfDeltaEncoding<W128v, u32>,
+        BitIoZigZagEncoding<u32>,
+        BitIoDeltaOfDeltaEncoding<u32>,
+        XorEncoding<W, u32>,
+        XorEncoding<W128v, u32>,
+        XorEncoding<W256v, u32>,
+        BitPackEncoding<W, u32>,
+        BitPackDeltaEncoding<W, u32>,
+        BitPackStrictDeltaEncoding<W, u32>,
+        BitPackForEncoding<W, u32>,
+        BitPackZigZagEncoding<W, u32>,
+        BitPackEncoding<W128v, u32>,
+        BitPackDeltaEncoding<W128v, u32>,
+        BitPackStrictDeltaEncoding<W128v, u32>,
+        BitPackForEncoding<W128v, u32>,
+        BitPackZigZagEncoding<W128v, u32>,
+        BitPackEncoding<W256v, u32>,
+        BitPackDeltaEncoding<W256v, u32>,
+        BitPackStrictDeltaEncoding<W256v, u32>,
+        BitPackForEncoding<W256v, u32>,
+        BitPackZigZagEncoding<W256v, u32>,
+    ]);
+
+frame_type!(u64, false,
+    validated: [
+        StandardEncoding<W, u64>,
+        IncreasingEncoding<W, u64>,
+        StrictlyIncreasingEncoding<W, u64>,
+        ZigZagEncoding<W, u64>,
+        StandardEncoding<W128v, u64>,
+    ],
+    unvalidated: [
+        VByteEncoding<u64>,
+        VByteDeltaEncoding<u64>,
+        VByteDelta1Encoding<u64>,
+        VByteZigZagEncoding<u64>,
+        VByteXorEncoding<u64>,
+        VByteDeltaOfDeltaEncoding<u64>,
+        SimpleVEncoding<u64>,
+        EliasFanoEncoding<W, u64>,
+        StrictEliasFanoEncoding<W, u64>,
+        DeltaOfDeltaEncoding<W128v, u64>,
+        BitIoZigZagEncoding<u64>,
+        BitIoDeltaOfDeltaEncoding<u64>,
+        XorEncoding<W, u64>,
+        BitPackEncoding<W, u64>,
+        BitPackEncoding<W128v, u64>,
+        BitPackDeltaEncoding<W, u64>,
+        BitPackStrictDeltaEncoding<W, u64>,
+        BitPackForEncoding<W, u64>,
+        BitPackZigZagEncoding<W, u64>,
+    ]);
+
+frame_type!(i8, true,
+    validated: [
+        StandardEncoding<W, i8>,
+        IncreasingEncoding<W, i8>,
+        StrictlyIncreasingEncoding<W, i8>,
+        ZigZagEncoding<W, i8>,
+    ],
+    unvalidated: [
+        BitPackEncoding<W, i8>,
+        BitPackDeltaEncoding<W, i8>,
+        BitPackStrictDeltaEncoding<W, i8>,
+        BitPackForEncoding<W, i8>,
+        BitPackZigZagEncoding<W, i8>,
+    ]);
+
+frame_type!(i16, true,
+    validated: [
+        StandardEncoding<W, i16>,
+        IncreasingEncoding<W, i16>,
+        StrictlyIncreasingEncoding<W, i16>,
+        ZigZagEncoding<W, i16>,
+        StandardEncoding<W128v, i16>,
+        IncreasingEncoding<W128v, i16>,
+        StrictlyIncreasingEncoding<W128v, i16>,
+        ZigZagEncoding<W128v, i16>,
+    ],
+    unvalidated: [
+        BitPackEncoding<W, i16>,
+        BitPackDeltaEncoding<W, i16>,
+        BitPackStrictDeltaEncoding<W, i16>,
+        BitPackForEncoding<W, i16>,
+        BitPackZigZagEncoding<W, i16>,
+        BitPackEncoding<W128v, i16>,
+        BitPackDeltaEncoding<W128v, i16>,
+        BitPackStrictDeltaEncoding<W128v, i16>,
+        BitPackForEncoding<W128v, i16>,
+        BitPackZigZagEncoding<W128v, i16>,
+    ]);
+
+frame_type!(i32, true,
+    validated: [
+        StandardEncoding<W, i32>,
+        IncreasingEncoding<W, i32>,
+        StrictlyIncreasingEncoding<W, i32>,
+        ZigZagEncoding<W, i32>,
+        StandardEncoding<W128v, i32>,
+        IncreasingEncoding<W128v, i32>,
+        StrictlyIncreasingEncoding<W128v, i32>,
+        ZigZagEncoding<W128v, i32>,
+        StandardEncoding<W256v, i32>,
+        IncreasingEncoding<W256v, i32>,
+        StrictlyIncreasingEncoding<W256v, i32>,
+        ZigZagEncoding<W256v, i32>,
+    ],
+    unvalidated: [
+        BitPackEncoding<W, i32>,
+        BitPackDeltaEncoding<W, i32>,
+        BitPackStrictDeltaEncoding<W, i32>,
+        BitPackForEncoding<W, i32>,
+        BitPackZigZagEncoding<W, i32>,
+        BitPackEncoding<W128v, i32>,
+        BitPackDeltaEncoding<W128v, i32>,
+        BitPackStrictDeltaEncoding<W128v, i32>,
+        BitPackForEncoding<W128v, i32>,
+        BitPackZigZagEncoding<W128v, i32>,
+        BitPackEncoding<W256v, i32>,
+        BitPackDeltaEncoding<W256v, i32>,
+        BitPackStrictDeltaEncoding<W256v, i32>,
+        BitPackForEncoding<W256v, i32>,
+        BitPackZigZagEncoding<W256v, i32>,
+    ]);
+
+frame_type!(i64, true,
+    validated: [
+        StandardEncoding<W, i64>,
+        IncreasingEncoding<W, i64>,
+        StrictlyIncreasingEncoding<W, i64>,
+        ZigZagEncoding<W, i64>,
+        StandardEncoding<W128v, i64>,
+    ],
+    unvalidated: [
+        BitPackEncoding<W, i64>,
+        BitPackEncoding<W128v, i64>,
+        BitPackDeltaEncoding<W, i64>,
+        BitPackStrictDeltaEncoding<W, i64>,
+        BitPackForEncoding<W, i64>,
+        BitPackZigZagEncoding<W, i64>,
+    ]);
+
+#[test]
+fn test_frame_roundtrip() {
+    type E = IncreasingEncoding<W128v, u32>;
+    for len in [0, 1, 1000] {
+        let input: Vec<u32> = E::sample(len.max(1))[..len].to_vec();
+        let frame = encode::<E>(&input);
+        let header = Header::read(&frame).unwrap();
+        assert_eq!(header, Header::new::<E>(len, frame.len() - Header::LEN, 0));
+        assert_eq!(decode_as::<E>(&frame), Ok(input.clone()));
+        let frame = encode_with_checksum::<E>(&input);
+        assert_eq!(Header::read(&frame).unwrap().flags, FLAG_CHECKSUM);
+        assert_eq!(decode_as::<E>(&frame), Ok(input));
+    }
+}
+
+#[test]
+fn test_frame_errors() {
+    type E = StandardEncoding<W, u16>;
+    let input: Vec<u16> = E::sample(300);
+    let mut frame = encode::<E>(&input);
+    assert_eq!(decode_as::<StandardEncoding<W, u32>>(&frame), Err(TurboPforError::FrameMismatch { field: "bits", expected: 32, got: 16 }));
+    assert_eq!(decode_as::<ZigZagEncoding<W, u16>>(&frame), Err(TurboPforError::FrameMismatch { field: "encoding", expected: 3, got: 0 }));
+    assert_eq!(decode_as::<StandardEncoding<W128v, u16>>(&frame), Err(TurboPforError::FrameMismatch { field: "width", expected: 1, got: 0 }));
+    assert!(matches!(decode_as::<E>(&frame[..frame.len() - 1]), Err(TurboPforError::InputTruncated { .. })));
+    assert_eq!(decode_as::<E>(&frame[..10]), Err(TurboPforError::InputTruncated { needed: Header::LEN, got: 10 }));
+    frame[4] = 2;
+    assert_eq!(decode_as::<E>(&frame), Err(TurboPforError::FrameMismatch { field: "version", expected: 1, got: 2 }));
+    frame[0] = b'X';
+    assert_eq!(decode_as::<E>(&frame), Err(TurboPforError::InvalidMagic));
+}
+
+#[test]
+fn test_frame_checksum() {
+    type E = StrictlyIncreasingEncoding<W, u64>;
+    let input: Vec<u64> = E::sample(500);
+    let mut frame = encode_with_checksum::<E>(&input);
+    let i = Header::LEN + 10;
+    frame[i] ^= 0x04;
+    assert!(matches!(decode_as::<E>(&frame), Err(TurboPforError::ChecksumMismatch { .. })));
+    frame[i] ^= 0x04;
+    frame[8] = 0x04;  // unknown flag
+    assert_eq!(decode_as::<E>(&frame), Err(TurboPforError::FrameMismatch { field: "flags", expected: 0, got: 4 }));
+}
+
+#[cfg(test)]
+fn test_frame_count_generic<E: Encoding>() where E::T: FrameType + Copy + Default + std::fmt::Debug + PartialEq {
+    let mut frame = encode::<E>(&E::sample(200));
+    let payload_len = frame.len() - Header::LEN;
+    let max = E::max_count(payload_len) as u64;
+    for count in [max + 1, u64::MAX >> 1, u64::MAX] {
+        frame[9..17].copy_from_slice(&count.to_le_bytes());
+        let err = decode::<E::T>(&frame).unwrap_err();
+        assert!(matches!(err, TurboPforError::FrameMismatch { field: "count", .. }), "{}", err);
+    }
+}
+
+#[test]
+fn test_frame_count() {
+    test_frame_count_generic::<StandardEncoding<W, u32>>();
+    test_frame_count_generic::<ZigZagEncoding<W128v, i16>>();
+    test_frame_count_generic::<VByteEncoding<u64>>();
+    test_frame_count_generic::<SimpleVEncoding<u32>>();
+    test_frame_count_generic::<BitIoDeltaOfDeltaEncoding<u16>>();
+    test_frame_count_generic::<BitPackForEncoding<W128v, u32>>();
+}
+
+#[cfg(test)]
+fn test_frame_dispatch_generic<E: Encoding>() where E::T: FrameType + std::fmt::Debug + PartialEq {
+    for len in [0, 1, 300] {
+        let input: Vec<E::T> = E::sample(len.max(1)).into_iter().take(len).collect();
+        assert_eq!(decode::<E::T>(&encode::<E>(&input)), Ok(input));
+    }
+}
+
+#[test]
+fn test_frame_dispatch() {
+    test_frame_dispatch_generic::<StandardEncoding<W, u8>>();
+    test_frame_dispatch_generic::<IncreasingEncoding<W128v, u16>>();
+    test_frame_dispatch_generic::<ZigZagEncoding<W256v, u32>>();
+    test_frame_dispatch_generic::<StandardEncoding<W128v, u64>>();
+    test_frame_dispatch_generic::<VByteDeltaEncoding<u32>>();
+    test_frame_dispatch_generic::<TurboByteEncoding<W128v, u16>>();
+    test_frame_dispatch_generic::<SimpleVEncoding<u64>>();
+    test_frame_dispatch_generic::<EliasFanoEncoding<W256v, u32>>();
+    test_frame_dispatch_generic::<DeltaOfDeltaEncoding<W128v, u32>>();
+    test_frame_dispatch_generic::<BitIoZigZagEncoding<u16>>();
+    test_frame_dispatch_generic::<XorEncoding<W, u64>>();
+    test_frame_dispatch_generic::<BitPackForEncoding<W256v, u32>>();
+    test_frame_dispatch_generic::<ZigZagEncoding<W128v, i16>>();
+    test_frame_dispatch_generic::<BitPackEncoding<W, i64>>();
+
+    let frame = encode::<EliasFanoEncoding<W, u32>>(&[1, 5, 9]);
+    assert_eq!(decode::<u16>(&frame), Err(TurboPforError::FrameMismatch { field: "bits", expected: 16, got: 32 }));
+    assert_eq!(decode::<i32>(&frame), Err(TurboPforError::FrameMismatch { field: "signed", expected: 1, got: 0 }));
+    let mut frame = encode::<VByteEncoding<u64>>(&[1, 5, 9]);
+    frame[6] = 2;
+    assert_eq!(decode::<u64>(&frame), Err(TurboPforError::UnknownEncoding { enc_type: 4, width_id: 2 }));
+}
+
+#[test]
+fn test_frame_signed() {
+    let frame = encode::<StandardEncoding<W, i32>>(&[-3, 5, -12]);
+    assert_eq!(Header::read(&frame).unwrap().flags, FLAG_SIGNED);
+    assert_eq!(decode::<i32>(&frame), Ok(vec![-3, 5, -12]));
+    assert_eq!(decode::<u32>(&frame), Err(TurboPforError::FrameMismatch { field: "signed", expected: 0, got: 1 }));
+    assert_eq!(decode_as::<StandardEncoding<W, u32>>(&frame), Err(TurboPforError::FrameMismatch { field: "signed", expected: 0, got: 1 }));
+
+    let frame = encode_with_checksum::<BitPackEncoding<W128v, u64>>(&[3, 5, 12]);
+    assert_eq!(Header::read(&frame).unwrap().flags, FLAG_CHECKSUM);
+    assert_eq!(decode::<i64>(&frame), Err(TurboPforError::FrameMismatch { field: "signed", expected: 1, got: 0 }));
+    assert_eq!(decode_as::<BitPackEncoding<W128v, i64>>(&frame), Err(TurboPforError::FrameMismatch { field: "signed", expected: 1, got: 0 }));
+}
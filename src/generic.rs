@@ -9,6 +9,26 @@
 /// - `IncreasingEncoding` for increasing integer lists (using codec::ddec and denc)
 /// - `StrictlyIncreasingEncoding` for strictly increasing integer lists (using codec::d1dec and d1enc)
 /// - `ZigZagEncoding` for unsorted integer lists (using codec::zdec and zenc)
+///
+/// and the variable byte `Encoding` instances using `codec::VByteCodec`: `VByteEncoding`, `VByteDeltaEncoding`,
+/// `VByteDelta1Encoding`, `VByteZigZagEncoding`, `VByteXorEncoding` and `VByteDeltaOfDeltaEncoding`.
+/// Variable byte coding needs no blocks and often beats PFor on short lists.
+/// The SIMD TurboByte instances using `codec::V8Codec` are `TurboByteEncoding`, `TurboByteDeltaEncoding`,
+/// `TurboByteDelta1Encoding`, `TurboByteZigZagEncoding` and `TurboByteXorEncoding`.
+/// `SimpleVEncoding` uses `codec::SimpleVCodec`, which run length encodes repeated integers.
+/// `EliasFanoEncoding` and `StrictEliasFanoEncoding` use `codec::EliasFanoCodec` for sorted lists;
+/// their `try_encode` checks the order up front and returns `NotIncreasing` for unsorted input.
+/// `DeltaOfDeltaEncoding` uses `codec::DeltaOfDeltaCodec` (TurboPFor on the zigzag of delta) for timestamps and
+/// other nearly increasing lists; `encode_with_start`/`decode_with_start` take the predecessor of the first integer.
+/// `BitIoZigZagEncoding` and `BitIoDeltaOfDeltaEncoding` use `codec::BitIoCodec`, the bit/io counterparts without
+/// blocks for slowly varying signals, with the same `encode_with_start`/`decode_with_start`.
+/// `XorEncoding` uses `codec::XorCodec`, bit packing of the XOR of neighbours, for hashes and bit flags whose
+/// neighbours share the high bits; `TurboByteXorEncoding` is its variable byte counterpart.
+/// The bit packing instances without exceptions are `BitPackEncoding` (using `codec::BitCodec`) and
+/// `BitPackDeltaEncoding`, `BitPackStrictDeltaEncoding`, `BitPackForEncoding` and `BitPackZigZagEncoding`
+/// (using `codec::BitDeltaCodec`).
+/// All of them accept the signed integers `i8` to `i64` as well, see `codec::SignedInteger`; `ZigZagEncoding` of a
+/// signed type is the zigzag of delta encoding for signed non-monotone lists.
 /// 
 /// Along with those two function we have `dec_buf_len` and `enc_buf_size` that are used to calculate
 /// the safe buffer sizes.
@@ -16,7 +36,9 @@
 /// Each encoding gets an u8 identifier `ENC_TYPE` that can be used for lookup tables, etc.
 /// 
 /// The sample function is used to generate random data for testing the encodings.
+use crate::checksum::*;
 use crate::codec::*;
+use crate::error::*;
 use crate::sample::*;
 use std::marker::PhantomData;
 use rand::{ // for testing
@@ -24,22 +46,56 @@ use rand::{ // for testing
     distributions::Standard,
 };
 
+/// Order an encoding requires of its input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    /// Any order
+    Any,
+    /// Increasing (sorted w/ duplicate)
+    Increasing,
+    /// Strictly increasing (sorted unique)
+    StrictlyIncreasing,
+}
+
+/// Integers per byte `Encoding::max_count` allows the run length encoded `SimpleVEncoding` and the bit/io encodings,
+/// where a run of any length takes a few bytes
+const MAX_RUN: usize = u32::MAX as usize;
+
 /// Trait for encoding types with width.
 pub trait Encoding {
     type W: Width;
     type T;
     /// Numeric ID useful for identifying the encoding type in lookup tables, etc.
     const ENC_TYPE: u8; 
+    /// Order the input must be in
+    const ORDER: Order = Order::Any;
     /// Encode input data into output buffer
     fn encode(input: &[Self::T], output: &mut [u8]) -> usize;
     /// Decode input buffer into output data
-    fn decode(input: &[u8], n: usize,  output: &mut [Self::T]) -> usize;
+    /// # Panics
+    /// If `try_decode` fails
+    fn decode(input: &[u8], n: usize, output: &mut [Self::T]) -> usize {
+        Self::try_decode(input, n, output).unwrap_or_else(|e| panic!("Encoding::decode: {}", e))
+    }
     /// Minimum safe [T] array length required for decoding n T's
     fn dec_buf_len(n: usize) -> usize;
+    /// Upper bound of the compressed size of n T's, as computed by the C library
+    fn max_compressed_size(n: usize) -> usize;
     /// Minimum safe u8 size required for encoding n T's
     fn enc_buf_size(n: usize) -> usize;
+    /// Most integers a stream of `size` bytes can hold, `frame` rejects a larger count before allocating.
+    /// The default assumes at least one byte per block of `W::BLOCK_LEN` integers (a block header)
+    fn max_count(size: usize) -> usize { size.saturating_mul(Self::W::BLOCK_LEN) }
     /// Sample random data for Self::T suitable for testing this encoding
     fn sample(len: usize) -> Vec<Self::T>;
+    /// Encode input data into output buffer, checking the buffer size against `enc_buf_size`
+    fn try_encode(input: &[Self::T], output: &mut [u8]) -> Result<usize> {
+        check_output(Self::enc_buf_size(input.len()), output.len())?;
+        Ok(Self::encode(input, output))
+    }
+    /// Decode input buffer into output data, checking the buffer size against `dec_buf_len` and the input for
+    /// truncation and corruption as far as the format allows
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize>;
 }
 
 pub struct StandardEncoding<WT, T>           { _marker_w: PhantomData<WT>, _marker_t: PhantomData<T> }
@@ -57,53 +113,58 @@ impl<WT: Width, T: Codec<WT>> Encoding for StandardEncoding<WT, T>
     fn encode(input: &[Self::T], output: &mut [u8]) -> usize {
         T::enc(input, output)
     }
-    fn decode(input: &[u8], n: usize, output: &mut [Self::T]) -> usize {
-        T::dec(input, n, output)
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize> {
+        T::try_dec(input, n, output)
     }
     fn dec_buf_len(n: usize) -> usize { Self::W::dec_buf_len::<Self::T>(n) }
-    fn enc_buf_size(n: usize) -> usize { Self::W::enc_buf_size::<Self::T>(n) }
+    fn max_compressed_size(n: usize) -> usize { T::max_compressed_size(n) }
+    fn enc_buf_size(n: usize) -> usize { Self::max_compressed_size(n) }
     fn sample(len: usize) -> Vec<Self::T> { sample_standard(len) }
 } 
 
-impl<WT: Width, T: Codec<WT>> Encoding for IncreasingEncoding<WT, T> 
+impl<WT: Width, T: DeltaCodec<WT>> Encoding for IncreasingEncoding<WT, T> 
 where Standard: Distribution<T>, T: Arithmetic
 {
     type W = WT;
     type T = T;
     const ENC_TYPE: u8 = 1;
+    const ORDER: Order = Order::Increasing;
     fn encode(input: &[Self::T], output: &mut [u8]) -> usize {
         T::denc(input, output)
     }
-    fn decode(input: &[u8], n: usize, output: &mut [Self::T]) -> usize {
-        T::ddec(input, n, output)
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize> {
+        T::try_ddec(input, n, output)
     }
     fn dec_buf_len(n: usize) -> usize { Self::W::dec_buf_len::<Self::T>(n) }
-    fn enc_buf_size(n: usize) -> usize { Self::W::enc_buf_size::<Self::T>(n) }
+    fn max_compressed_size(n: usize) -> usize { T::max_compressed_size(n) }
+    fn enc_buf_size(n: usize) -> usize { Self::max_compressed_size(n) }
     fn sample(len: usize) -> Vec<Self::T>
         where Standard: Distribution<T>, T: Arithmetic
     { sample_increasing(len, 0, 10) }
 }
 
-impl<WT: Width, T: Codec<WT>> Encoding for StrictlyIncreasingEncoding<WT, T> 
+impl<WT: Width, T: DeltaCodec<WT>> Encoding for StrictlyIncreasingEncoding<WT, T> 
     where Standard: Distribution<T>, T: Arithmetic
 {
     type W = WT;
     type T = T;
     const ENC_TYPE: u8 = 2;
+    const ORDER: Order = Order::StrictlyIncreasing;
     fn encode(input: &[Self::T], output: &mut [u8]) -> usize {
         T::d1enc(input, output)
     }
-    fn decode(input: &[u8], n: usize, output: &mut [Self::T]) -> usize {
-        T::d1dec(input, n, output)
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize> {
+        T::try_d1dec(input, n, output)
     }
     fn dec_buf_len(n: usize) -> usize { Self::W::dec_buf_len::<Self::T>(n) }
-    fn enc_buf_size(n: usize) -> usize { Self::W::enc_buf_size::<Self::T>(n) }
+    fn max_compressed_size(n: usize) -> usize { T::max_compressed_size(n) }
+    fn enc_buf_size(n: usize) -> usize { Self::max_compressed_size(n) }
     fn sample(len: usize) -> Vec<Self::T>
         where Standard: Distribution<T>, T: Arithmetic
     { sample_increasing(len, 1, 10) }
 }
 
-impl<WT: Width, T: Codec<WT>> Encoding for ZigZagEncoding<WT, T> 
+impl<WT: Width, T: DeltaCodec<WT>> Encoding for ZigZagEncoding<WT, T> 
     where Standard: Distribution<T>, T: Arithmetic
 {
     type W = WT;
@@ -112,11 +173,525 @@ impl<WT: Width, T: Codec<WT>> Encoding for ZigZagEncoding<WT, T>
     fn encode(input: &[Self::T], output: &mut [u8]) -> usize {
         T::zenc(input, output)
     }
-    fn decode(input: &[u8], n: usize, output: &mut [Self::T]) -> usize {
-        T::zdec(input, n, output)
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize> {
+        T::try_zdec(input, n, output)
+    }
+    fn dec_buf_len(n: usize) -> usize { Self::W::dec_buf_len::<Self::T>(n) }
+    fn max_compressed_size(n: usize) -> usize { T::max_compressed_size(n) }
+    fn enc_buf_size(n: usize) -> usize { Self::max_compressed_size(n) }
+    fn sample(len: usize) -> Vec<Self::T> { sample_standard(len) }
+}
+
+pub struct VByteEncoding<T>             { _marker_t: PhantomData<T> }
+pub struct VByteDeltaEncoding<T>        { _marker_t: PhantomData<T> }
+pub struct VByteDelta1Encoding<T>       { _marker_t: PhantomData<T> }
+pub struct VByteZigZagEncoding<T>       { _marker_t: PhantomData<T> }
+pub struct VByteXorEncoding<T>          { _marker_t: PhantomData<T> }
+pub struct VByteDeltaOfDeltaEncoding<T> { _marker_t: PhantomData<T> }
+// The variable byte encodings, linking to the `VByteCodec` methods. The delta encodings start from 0.
+
+impl<T: VByteCodec> Encoding for VByteEncoding<T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    type W = W;
+    type T = T;
+    const ENC_TYPE: u8 = 4;
+    fn encode(input: &[Self::T], output: &mut [u8]) -> usize {
+        T::enc(input, output)
+    }
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize> {
+        T::try_dec(input, n, output)
+    }
+    fn dec_buf_len(n: usize) -> usize { n }
+    fn max_compressed_size(n: usize) -> usize { T::max_compressed_size(n) }
+    fn enc_buf_size(n: usize) -> usize { Self::max_compressed_size(n) }
+    fn max_count(size: usize) -> usize { size } // at least one byte per integer
+    fn sample(len: usize) -> Vec<Self::T> { sample_standard(len) }
+}
+
+impl<T: VByteCodec> Encoding for VByteDeltaEncoding<T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    type W = W;
+    type T = T;
+    const ENC_TYPE: u8 = 5;
+    const ORDER: Order = Order::Increasing;
+    fn encode(input: &[Self::T], output: &mut [u8]) -> usize {
+        T::denc(input, output, T::zero())
+    }
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize> {
+        T::try_ddec(input, n, output, T::zero())
+    }
+    fn dec_buf_len(n: usize) -> usize { n }
+    fn max_compressed_size(n: usize) -> usize { T::max_compressed_size(n) }
+    fn enc_buf_size(n: usize) -> usize { Self::max_compressed_size(n) }
+    fn max_count(size: usize) -> usize { size } // at least one byte per integer
+    fn sample(len: usize) -> Vec<Self::T> { sample_increasing(len, 0, 10) }
+}
+
+impl<T: VByteCodec> Encoding for VByteDelta1Encoding<T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    type W = W;
+    type T = T;
+    const ENC_TYPE: u8 = 6;
+    const ORDER: Order = Order::StrictlyIncreasing;
+    fn encode(input: &[Self::T], output: &mut [u8]) -> usize {
+        T::d1enc(input, output, T::zero())
+    }
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize> {
+        T::try_d1dec(input, n, output, T::zero())
+    }
+    fn dec_buf_len(n: usize) -> usize { n }
+    fn max_compressed_size(n: usize) -> usize { T::max_compressed_size(n) }
+    fn enc_buf_size(n: usize) -> usize { Self::max_compressed_size(n) }
+    fn max_count(size: usize) -> usize { size } // at least one byte per integer
+    fn sample(len: usize) -> Vec<Self::T> { sample_increasing(len, 1, 10) }
+}
+
+impl<T: VByteCodec> Encoding for VByteZigZagEncoding<T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    type W = W;
+    type T = T;
+    const ENC_TYPE: u8 = 7;
+    fn encode(input: &[Self::T], output: &mut [u8]) -> usize {
+        T::zenc(input, output, T::zero())
+    }
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize> {
+        T::try_zdec(input, n, output, T::zero())
+    }
+    fn dec_buf_len(n: usize) -> usize { n }
+    fn max_compressed_size(n: usize) -> usize { T::max_compressed_size(n) }
+    fn enc_buf_size(n: usize) -> usize { Self::max_compressed_size(n) }
+    fn max_count(size: usize) -> usize { size } // at least one byte per integer
+    fn sample(len: usize) -> Vec<Self::T> { sample_standard(len) }
+}
+
+impl<T: VByteCodec> Encoding for VByteXorEncoding<T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    type W = W;
+    type T = T;
+    const ENC_TYPE: u8 = 8;
+    fn encode(input: &[Self::T], output: &mut [u8]) -> usize {
+        T::xenc(input, output, T::zero())
+    }
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize> {
+        T::try_xdec(input, n, output, T::zero())
+    }
+    fn dec_buf_len(n: usize) -> usize { n }
+    fn max_compressed_size(n: usize) -> usize { T::max_compressed_size(n) }
+    fn enc_buf_size(n: usize) -> usize { Self::max_compressed_size(n) }
+    fn max_count(size: usize) -> usize { size } // at least one byte per integer
+    fn sample(len: usize) -> Vec<Self::T> { sample_standard(len) }
+}
+
+impl<T: VByteCodec> Encoding for VByteDeltaOfDeltaEncoding<T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    type W = W;
+    type T = T;
+    const ENC_TYPE: u8 = 9;
+    fn encode(input: &[Self::T], output: &mut [u8]) -> usize {
+        T::ddenc(input, output, T::zero())
+    }
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize> {
+        T::try_dddec(input, n, output, T::zero())
+    }
+    fn dec_buf_len(n: usize) -> usize { n }
+    fn max_compressed_size(n: usize) -> usize { T::max_compressed_size(n) }
+    fn enc_buf_size(n: usize) -> usize { Self::max_compressed_size(n) }
+    fn sample(len: usize) -> Vec<Self::T> { sample_standard(len) }
+}
+
+pub struct TurboByteEncoding<WT, T>       { _marker_w: PhantomData<WT>, _marker_t: PhantomData<T> }
+pub struct TurboByteDeltaEncoding<WT, T>  { _marker_w: PhantomData<WT>, _marker_t: PhantomData<T> }
+pub struct TurboByteDelta1Encoding<WT, T> { _marker_w: PhantomData<WT>, _marker_t: PhantomData<T> }
+pub struct TurboByteZigZagEncoding<WT, T> { _marker_w: PhantomData<WT>, _marker_t: PhantomData<T> }
+pub struct TurboByteXorEncoding<WT, T>    { _marker_w: PhantomData<WT>, _marker_t: PhantomData<T> }
+// The SIMD TurboByte encodings, linking to the `V8Codec` methods
+
+impl<WT: Width, T: V8Codec<WT>> Encoding for TurboByteEncoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    type W = WT;
+    type T = T;
+    const ENC_TYPE: u8 = 10;
+    fn encode(input: &[Self::T], output: &mut [u8]) -> usize {
+        T::enc(input, output)
+    }
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize> {
+        T::try_dec(input, n, output)
+    }
+    fn dec_buf_len(n: usize) -> usize { Self::W::dec_buf_len::<Self::T>(n) }
+    fn max_compressed_size(n: usize) -> usize { T::max_compressed_size(n) }
+    fn enc_buf_size(n: usize) -> usize { Self::max_compressed_size(n) }
+    fn max_count(size: usize) -> usize { size } // at least one byte per integer
+    fn sample(len: usize) -> Vec<Self::T> { sample_standard(len) }
+}
+
+impl<WT: Width, T: V8Codec<WT>> Encoding for TurboByteDeltaEncoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    type W = WT;
+    type T = T;
+    const ENC_TYPE: u8 = 11;
+    const ORDER: Order = Order::Increasing;
+    fn encode(input: &[Self::T], output: &mut [u8]) -> usize {
+        T::denc(input, output)
+    }
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize> {
+        T::try_ddec(input, n, output)
+    }
+    fn dec_buf_len(n: usize) -> usize { Self::W::dec_buf_len::<Self::T>(n) }
+    fn max_compressed_size(n: usize) -> usize { T::max_compressed_size(n) }
+    fn enc_buf_size(n: usize) -> usize { Self::max_compressed_size(n) }
+    fn max_count(size: usize) -> usize { size } // at least one byte per integer
+    fn sample(len: usize) -> Vec<Self::T> { sample_increasing(len, 0, 10) }
+}
+
+impl<WT: Width, T: V8Codec<WT>> Encoding for TurboByteDelta1Encoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    type W = WT;
+    type T = T;
+    const ENC_TYPE: u8 = 12;
+    const ORDER: Order = Order::StrictlyIncreasing;
+    fn encode(input: &[Self::T], output: &mut [u8]) -> usize {
+        T::d1enc(input, output)
+    }
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize> {
+        T::try_d1dec(input, n, output)
+    }
+    fn dec_buf_len(n: usize) -> usize { Self::W::dec_buf_len::<Self::T>(n) }
+    fn max_compressed_size(n: usize) -> usize { T::max_compressed_size(n) }
+    fn enc_buf_size(n: usize) -> usize { Self::max_compressed_size(n) }
+    fn max_count(size: usize) -> usize { size } // at least one byte per integer
+    fn sample(len: usize) -> Vec<Self::T> { sample_increasing(len, 1, 10) }
+}
+
+impl<WT: Width, T: V8Codec<WT>> Encoding for TurboByteZigZagEncoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    type W = WT;
+    type T = T;
+    const ENC_TYPE: u8 = 13;
+    fn encode(input: &[Self::T], output: &mut [u8]) -> usize {
+        T::zenc(input, output)
+    }
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize> {
+        T::try_zdec(input, n, output)
+    }
+    fn dec_buf_len(n: usize) -> usize { Self::W::dec_buf_len::<Self::T>(n) }
+    fn max_compressed_size(n: usize) -> usize { T::max_compressed_size(n) }
+    fn enc_buf_size(n: usize) -> usize { Self::max_compressed_size(n) }
+    fn max_count(size: usize) -> usize { size } // at least one byte per integer
+    fn sample(len: usize) -> Vec<Self::T> { sample_standard(len) }
+}
+
+impl<WT: Width, T: V8Codec<WT>> Encoding for TurboByteXorEncoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    type W = WT;
+    type T = T;
+    const ENC_TYPE: u8 = 14;
+    fn encode(input: &[Self::T], output: &mut [u8]) -> usize {
+        T::xenc(input, output)
+    }
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize> {
+        T::try_xdec(input, n, output)
+    }
+    fn dec_buf_len(n: usize) -> usize { Self::W::dec_buf_len::<Self::T>(n) }
+    fn max_compressed_size(n: usize) -> usize { T::max_compressed_size(n) }
+    fn enc_buf_size(n: usize) -> usize { Self::max_compressed_size(n) }
+    fn max_count(size: usize) -> usize { size } // at least one byte per integer
+    fn sample(len: usize) -> Vec<Self::T> { sample_standard(len) }
+}
+
+pub struct SimpleVEncoding<T> { _marker_t: PhantomData<T> }
+
+impl<T: SimpleVCodec> Encoding for SimpleVEncoding<T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    type W = W;
+    type T = T;
+    const ENC_TYPE: u8 = 15;
+    fn encode(input: &[Self::T], output: &mut [u8]) -> usize {
+        T::enc(input, output)
+    }
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize> {
+        T::try_dec(input, n, output)
+    }
+    fn dec_buf_len(n: usize) -> usize { Self::W::dec_buf_len::<Self::T>(n) }
+    fn max_compressed_size(n: usize) -> usize { T::max_compressed_size(n) }
+    fn enc_buf_size(n: usize) -> usize { Self::max_compressed_size(n) }
+    fn max_count(size: usize) -> usize { size.saturating_mul(MAX_RUN) }
+    fn sample(len: usize) -> Vec<Self::T> { sample_runs(len, 64) }
+}
+
+pub struct EliasFanoEncoding<WT, T>       { _marker_w: PhantomData<WT>, _marker_t: PhantomData<T> }
+pub struct StrictEliasFanoEncoding<WT, T> { _marker_w: PhantomData<WT>, _marker_t: PhantomData<T> }
+// The Elias-Fano encodings start from 0, i.e. the first integer of `StrictEliasFanoEncoding` must be larger than 0
+
+impl<WT: Width, T: EliasFanoCodec<WT>> Encoding for EliasFanoEncoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    type W = WT;
+    type T = T;
+    const ENC_TYPE: u8 = 16;
+    const ORDER: Order = Order::Increasing;
+    fn encode(input: &[Self::T], output: &mut [u8]) -> usize {
+        T::enc(input, output, T::zero())
+    }
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize> {
+        T::try_dec(input, n, output, T::zero())
+    }
+    fn dec_buf_len(n: usize) -> usize { Self::W::dec_buf_len::<Self::T>(n) }
+    fn max_compressed_size(n: usize) -> usize { T::max_compressed_size(n) }
+    fn enc_buf_size(n: usize) -> usize { Self::max_compressed_size(n) }
+    fn sample(len: usize) -> Vec<Self::T> { sample_increasing(len, 0, 10) }
+    fn try_encode(input: &[Self::T], output: &mut [u8]) -> Result<usize> {
+        check_increasing(input, T::zero(), false)?;
+        check_output(Self::enc_buf_size(input.len()), output.len())?;
+        Ok(Self::encode(input, output))
+    }
+}
+
+impl<WT: Width, T: EliasFanoCodec<WT>> Encoding for StrictEliasFanoEncoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    type W = WT;
+    type T = T;
+    const ENC_TYPE: u8 = 17;
+    const ORDER: Order = Order::StrictlyIncreasing;
+    fn encode(input: &[Self::T], output: &mut [u8]) -> usize {
+        T::d1enc(input, output, T::zero())
+    }
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize> {
+        T::try_d1dec(input, n, output, T::zero())
+    }
+    fn dec_buf_len(n: usize) -> usize { Self::W::dec_buf_len::<Self::T>(n) }
+    fn max_compressed_size(n: usize) -> usize { T::max_compressed_size(n) }
+    fn enc_buf_size(n: usize) -> usize { Self::max_compressed_size(n) }
+    fn sample(len: usize) -> Vec<Self::T> { sample_increasing(len, 1, 10) }
+    fn try_encode(input: &[Self::T], output: &mut [u8]) -> Result<usize> {
+        check_increasing(input, T::zero(), true)?;
+        check_output(Self::enc_buf_size(input.len()), output.len())?;
+        Ok(Self::encode(input, output))
+    }
+}
+
+pub struct DeltaOfDeltaEncoding<WT, T> { _marker_w: PhantomData<WT>, _marker_t: PhantomData<T> }
+
+impl<WT: Width, T: DeltaOfDeltaCodec<WT>> DeltaOfDeltaEncoding<WT, T> {
+    /// Encode `input` with `start` as the predecessor of the first integer
+    pub fn encode_with_start(input: &[T], output: &mut [u8], start: T) -> usize {
+        T::enc(input, output, start)
+    }
+    /// Decode `n` integers, `start` as given to `encode_with_start`
+    pub fn decode_with_start(input: &[u8], n: usize, output: &mut [T], start: T) -> usize {
+        T::dec(input, n, output, start)
+    }
+}
+
+impl<WT: Width, T: DeltaOfDeltaCodec<WT>> Encoding for DeltaOfDeltaEncoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    type W = WT;
+    type T = T;
+    const ENC_TYPE: u8 = 24;
+    fn encode(input: &[Self::T], output: &mut [u8]) -> usize {
+        T::enc(input, output, T::zero())
+    }
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize> {
+        T::try_dec(input, n, output, T::zero())
+    }
+    fn dec_buf_len(n: usize) -> usize { Self::W::dec_buf_len::<Self::T>(n) }
+    fn max_compressed_size(n: usize) -> usize { T::max_compressed_size(n) }
+    fn enc_buf_size(n: usize) -> usize { Self::max_compressed_size(n) }
+    fn sample(len: usize) -> Vec<Self::T> { sample_increasing(len, 0, 10) }
+}
+
+pub struct BitIoZigZagEncoding<T>       { _marker_t: PhantomData<T> }
+pub struct BitIoDeltaOfDeltaEncoding<T> { _marker_t: PhantomData<T> }
+
+impl<T: BitIoCodec> BitIoZigZagEncoding<T> {
+    /// Encode `input` with `start` as the predecessor of the first integer
+    pub fn encode_with_start(input: &[T], output: &mut [u8], start: T) -> usize {
+        T::zenc(input, output, start)
+    }
+    /// Decode `n` integers, `start` as given to `encode_with_start`
+    pub fn decode_with_start(input: &[u8], n: usize, output: &mut [T], start: T) -> usize {
+        T::zdec(input, n, output, start)
+    }
+}
+
+impl<T: BitIoCodec> BitIoDeltaOfDeltaEncoding<T> {
+    /// Encode `input` with `start` as the predecessor of the first integer
+    pub fn encode_with_start(input: &[T], output: &mut [u8], start: T) -> usize {
+        T::zzenc(input, output, start)
+    }
+    /// Decode `n` integers, `start` as given to `encode_with_start`
+    pub fn decode_with_start(input: &[u8], n: usize, output: &mut [T], start: T) -> usize {
+        T::zzdec(input, n, output, start)
+    }
+}
+
+impl<T: BitIoCodec> Encoding for BitIoZigZagEncoding<T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    type W = W;
+    type T = T;
+    const ENC_TYPE: u8 = 25;
+    fn encode(input: &[Self::T], output: &mut [u8]) -> usize {
+        T::zenc(input, output, T::zero())
+    }
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize> {
+        T::try_zdec(input, n, output, T::zero())
+    }
+    fn dec_buf_len(n: usize) -> usize { Self::W::dec_buf_len::<Self::T>(n) }
+    fn max_compressed_size(n: usize) -> usize { T::max_compressed_size(n) }
+    fn enc_buf_size(n: usize) -> usize { Self::max_compressed_size(n) }
+    fn max_count(size: usize) -> usize { size.saturating_mul(MAX_RUN) }
+    fn sample(len: usize) -> Vec<Self::T> { sample_standard(len) }
+}
+
+impl<T: BitIoCodec> Encoding for BitIoDeltaOfDeltaEncoding<T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    type W = W;
+    type T = T;
+    const ENC_TYPE: u8 = 26;
+    fn encode(input: &[Self::T], output: &mut [u8]) -> usize {
+        T::zzenc(input, output, T::zero())
+    }
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize> {
+        T::try_zzdec(input, n, output, T::zero())
+    }
+    fn dec_buf_len(n: usize) -> usize { Self::W::dec_buf_len::<Self::T>(n) }
+    fn max_compressed_size(n: usize) -> usize { T::max_compressed_size(n) }
+    fn enc_buf_size(n: usize) -> usize { Self::max_compressed_size(n) }
+    fn max_count(size: usize) -> usize { size.saturating_mul(MAX_RUN) }
+    fn sample(len: usize) -> Vec<Self::T> { sample_increasing(len, 0, 10) }
+}
+
+pub struct XorEncoding<WT, T> { _marker_w: PhantomData<WT>, _marker_t: PhantomData<T> }
+
+impl<WT: Width, T: XorCodec<WT>> Encoding for XorEncoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    type W = WT;
+    type T = T;
+    const ENC_TYPE: u8 = 27;
+    fn encode(input: &[Self::T], output: &mut [u8]) -> usize {
+        T::xenc(input, output)
+    }
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize> {
+        T::try_xdec(input, n, output)
+    }
+    fn dec_buf_len(n: usize) -> usize { Self::W::dec_buf_len::<Self::T>(n) }
+    fn max_compressed_size(n: usize) -> usize { T::max_compressed_size(n) }
+    fn enc_buf_size(n: usize) -> usize { Self::max_compressed_size(n) }
+    fn sample(len: usize) -> Vec<Self::T> { sample_standard(len) }
+}
+
+pub struct BitPackEncoding<WT, T>            { _marker_w: PhantomData<WT>, _marker_t: PhantomData<T> }
+pub struct BitPackDeltaEncoding<WT, T>       { _marker_w: PhantomData<WT>, _marker_t: PhantomData<T> }
+pub struct BitPackStrictDeltaEncoding<WT, T> { _marker_w: PhantomData<WT>, _marker_t: PhantomData<T> }
+pub struct BitPackForEncoding<WT, T>         { _marker_w: PhantomData<WT>, _marker_t: PhantomData<T> }
+pub struct BitPackZigZagEncoding<WT, T>      { _marker_w: PhantomData<WT>, _marker_t: PhantomData<T> }
+
+impl<WT: Width, T: BitCodec<WT>> Encoding for BitPackEncoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    type W = WT;
+    type T = T;
+    const ENC_TYPE: u8 = 28;
+    fn encode(input: &[Self::T], output: &mut [u8]) -> usize {
+        T::enc(input, output)
+    }
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize> {
+        T::try_dec(input, n, output)
+    }
+    fn dec_buf_len(n: usize) -> usize { Self::W::dec_buf_len::<Self::T>(n) }
+    fn max_compressed_size(n: usize) -> usize { T::max_compressed_size(n) }
+    fn enc_buf_size(n: usize) -> usize { Self::max_compressed_size(n) }
+    fn sample(len: usize) -> Vec<Self::T> { sample_standard(len) }
+}
+
+impl<WT: Width, T: BitDeltaCodec<WT>> Encoding for BitPackDeltaEncoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    type W = WT;
+    type T = T;
+    const ENC_TYPE: u8 = 29;
+    const ORDER: Order = Order::Increasing;
+    fn encode(input: &[Self::T], output: &mut [u8]) -> usize {
+        T::denc(input, output)
+    }
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize> {
+        T::try_ddec(input, n, output)
+    }
+    fn dec_buf_len(n: usize) -> usize { Self::W::dec_buf_len::<Self::T>(n) }
+    fn max_compressed_size(n: usize) -> usize { T::max_compressed_size(n) }
+    fn enc_buf_size(n: usize) -> usize { Self::max_compressed_size(n) }
+    fn sample(len: usize) -> Vec<Self::T> { sample_increasing(len, 0, 10) }
+}
+
+impl<WT: Width, T: BitDeltaCodec<WT>> Encoding for BitPackStrictDeltaEncoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    type W = WT;
+    type T = T;
+    const ENC_TYPE: u8 = 30;
+    const ORDER: Order = Order::StrictlyIncreasing;
+    fn encode(input: &[Self::T], output: &mut [u8]) -> usize {
+        T::d1enc(input, output)
+    }
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize> {
+        T::try_d1dec(input, n, output)
     }
     fn dec_buf_len(n: usize) -> usize { Self::W::dec_buf_len::<Self::T>(n) }
-    fn enc_buf_size(n: usize) -> usize { Self::W::enc_buf_size::<Self::T>(n) }
+    fn max_compressed_size(n: usize) -> usize { T::max_compressed_size(n) }
+    fn enc_buf_size(n: usize) -> usize { Self::max_compressed_size(n) }
+    fn sample(len: usize) -> Vec<Self::T> { sample_increasing(len, 1, 10) }
+}
+
+impl<WT: Width, T: BitDeltaCodec<WT>> Encoding for BitPackForEncoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    type W = WT;
+    type T = T;
+    const ENC_TYPE: u8 = 31;
+    const ORDER: Order = Order::Increasing;
+    fn encode(input: &[Self::T], output: &mut [u8]) -> usize {
+        T::fenc(input, output)
+    }
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize> {
+        T::try_fdec(input, n, output)
+    }
+    fn dec_buf_len(n: usize) -> usize { Self::W::dec_buf_len::<Self::T>(n) }
+    fn max_compressed_size(n: usize) -> usize { T::max_compressed_size(n) }
+    fn enc_buf_size(n: usize) -> usize { Self::max_compressed_size(n) }
+    fn sample(len: usize) -> Vec<Self::T> { sample_increasing(len, 0, 10) }
+}
+
+impl<WT: Width, T: BitDeltaCodec<WT>> Encoding for BitPackZigZagEncoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    type W = WT;
+    type T = T;
+    const ENC_TYPE: u8 = 32;
+    fn encode(input: &[Self::T], output: &mut [u8]) -> usize {
+        T::zenc(input, output)
+    }
+    fn try_decode(input: &[u8], n: usize, output: &mut [Self::T]) -> Result<usize> {
+        T::try_zdec(input, n, output)
+    }
+    fn dec_buf_len(n: usize) -> usize { Self::W::dec_buf_len::<Self::T>(n) }
+    fn max_compressed_size(n: usize) -> usize { T::max_compressed_size(n) }
+    fn enc_buf_size(n: usize) -> usize { Self::max_compressed_size(n) }
     fn sample(len: usize) -> Vec<Self::T> { sample_standard(len) }
 }
 
@@ -130,6 +705,38 @@ pub fn decode<E: Encoding>(input: &[u8], n: usize, output: &mut [E::T]) -> usize
     E::decode(input, n, output)
 }
 
+/// Generic checked encoding function
+pub fn try_encode<E: Encoding>(input: &[E::T], output: &mut [u8]) -> Result<usize> {
+    E::try_encode(input, output)
+}
+
+/// Generic checked decoding function
+pub fn try_decode<E: Encoding>(input: &[u8], n: usize, output: &mut [E::T]) -> Result<usize> {
+    E::try_decode(input, n, output)
+}
+
+/// Generic encoding function that appends a CRC32C of the payload
+/// # Arguments
+/// * `input` - `&[T]` containing the uncompressed input
+/// * `output` - `[u8]` containing the compressed output, at least `E::enc_buf_size(n) + CHECKSUM_LEN` bytes
+/// # Returns
+/// Number of output bytes used, including the checksum
+pub fn encode_with_checksum<E: Encoding>(input: &[E::T], output: &mut [u8]) -> Result<usize> {
+    check_output(E::enc_buf_size(input.len()) + CHECKSUM_LEN, output.len())?;
+    let size = E::encode(input, output);
+    Ok(put_checksum(output, size))
+}
+
+/// Generic decoding function for the output of `encode_with_checksum`.
+/// The checksum is verified before decoding, so `input` must be exactly the bytes written by the encoder.
+/// # Returns
+/// Number of input bytes used, including the checksum, or `ChecksumMismatch` if the payload is corrupted
+pub fn decode_with_checksum<E: Encoding>(input: &[u8], n: usize, output: &mut [E::T]) -> Result<usize> {
+    let payload = check_checksum(input)?;
+    E::try_decode(payload, n, output)?;
+    Ok(input.len())
+}
+
 #[cfg(test)]
 use rand::Rng;
 
@@ -158,6 +765,56 @@ where
     }
 }
 
+#[test]
+fn test_try_encode_decode() {
+    type E = IncreasingEncoding<W, u16>;
+    let input: Vec<u16> = E::sample(1000);
+    let mut buf = vec![0u8; E::enc_buf_size(input.len()) - 1];
+    assert!(matches!(try_encode::<E>(&input, &mut buf), Err(TurboPforError::OutputTooSmall { .. })));
+    buf.push(0);
+    let size = try_encode::<E>(&input, &mut buf).unwrap();
+    let mut output = vec![0u16; E::dec_buf_len(input.len())];
+    assert_eq!(try_decode::<E>(&buf[..size], input.len(), &mut output), Ok(size));
+    assert_eq!(input, output[..input.len()]);
+}
+
+#[cfg(test)]
+fn test_try_decode_truncated_generic<E: Encoding>() where E::T: Default + Copy {
+    let input = E::sample(1000);
+    let mut buf = vec![0u8; E::enc_buf_size(input.len())];
+    let size = E::encode(&input, &mut buf);
+    let mut output = vec![E::T::default(); E::dec_buf_len(input.len())];
+    let truncated = buf[..size / 2].to_vec();
+    assert!(E::try_decode(&truncated, input.len(), &mut output).is_err());
+    assert!(try_decode::<E>(&truncated, input.len(), &mut output).is_err());
+}
+
+#[test]
+fn test_try_decode_truncated() {
+    // `try_decode` returns the error of the checked decoders instead of panicking in `decode`
+    test_try_decode_truncated_generic::<StandardEncoding<W, u32>>();
+    test_try_decode_truncated_generic::<IncreasingEncoding<W128v, u16>>();
+    test_try_decode_truncated_generic::<ZigZagEncoding<W, i32>>();
+    test_try_decode_truncated_generic::<StandardEncoding<W, i64>>();
+    test_try_decode_truncated_generic::<VByteEncoding<u32>>();
+    test_try_decode_truncated_generic::<TurboByteEncoding<W128v, u32>>();
+    test_try_decode_truncated_generic::<SimpleVEncoding<u16>>();
+    test_try_decode_truncated_generic::<EliasFanoEncoding<W, u32>>();
+    test_try_decode_truncated_generic::<DeltaOfDeltaEncoding<W128v, u64>>();
+    test_try_decode_truncated_generic::<BitIoZigZagEncoding<u32>>();
+    test_try_decode_truncated_generic::<XorEncoding<W, u32>>();
+    test_try_decode_truncated_generic::<BitPackEncoding<W, u64>>();
+    test_try_decode_truncated_generic::<BitPackForEncoding<W256v, u32>>();
+
+    let input: Vec<u32> = (0..1000).collect();
+    let mut buf = vec![0u8; <u32 as Codec<W>>::max_compressed_size(input.len())];
+    let size = Codec::<W>::enc(&input, &mut buf);
+    let mut output = vec![0u32; W::dec_buf_len::<u32>(input.len())];
+    assert!(Codec::<W>::try_dec(&buf[..size / 2], input.len(), &mut output).is_err());
+    buf[0] = 0x3f;
+    assert!(matches!(Codec::<W>::try_dec(&buf[..size], input.len(), &mut output), Err(TurboPforError::InvalidBlockHeader { .. })));
+}
+
 #[test]
 fn test_enc_dec_u8_standard() {
     test_enc_dec_generic::<StandardEncoding<W, u8>>()
@@ -236,4 +893,507 @@ fn test_enc_dec_u32_zigzag() {
 #[test]
 fn test_enc_dec_u64_zigzag() {
     test_enc_dec_generic::<ZigZagEncoding<W, u64>>()
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_enc_dec_128v() {
+    test_enc_dec_generic::<StandardEncoding<W128v, u16>>();
+    test_enc_dec_generic::<StandardEncoding<W128v, u32>>();
+    test_enc_dec_generic::<StandardEncoding<W128v, u64>>();
+    test_enc_dec_generic::<IncreasingEncoding<W128v, u16>>();
+    test_enc_dec_generic::<IncreasingEncoding<W128v, u32>>();
+    test_enc_dec_generic::<StrictlyIncreasingEncoding<W128v, u16>>();
+    test_enc_dec_generic::<StrictlyIncreasingEncoding<W128v, u32>>();
+    test_enc_dec_generic::<ZigZagEncoding<W128v, u16>>();
+    test_enc_dec_generic::<ZigZagEncoding<W128v, u32>>();
+}
+
+#[test]
+fn test_enc_dec_256v() {
+    test_enc_dec_generic::<StandardEncoding<W256v, u32>>();
+    test_enc_dec_generic::<IncreasingEncoding<W256v, u32>>();
+    test_enc_dec_generic::<StrictlyIncreasingEncoding<W256v, u32>>();
+    test_enc_dec_generic::<ZigZagEncoding<W256v, u32>>();
+}
+
+#[test]
+fn test_checksum_encode_decode() {
+    type E = ZigZagEncoding<W, u32>;
+    let input: Vec<u32> = E::sample(1000);
+    let mut buf = vec![0u8; E::enc_buf_size(input.len()) + CHECKSUM_LEN];
+    let size = encode_with_checksum::<E>(&input, &mut buf).unwrap();
+    let mut output = vec![0u32; E::dec_buf_len(input.len())];
+    assert_eq!(decode_with_checksum::<E>(&buf[..size], input.len(), &mut output), Ok(size));
+    assert_eq!(input, output[..input.len()]);
+    buf[size / 2] ^= 0x01;
+    assert!(matches!(decode_with_checksum::<E>(&buf[..size], input.len(), &mut output), Err(TurboPforError::ChecksumMismatch { .. })));
+}
+
+#[test]
+fn test_enc_dec_u16_vbyte() {
+    test_enc_dec_generic::<VByteEncoding<u16>>()
+}
+
+#[test]
+fn test_enc_dec_u32_vbyte() {
+    test_enc_dec_generic::<VByteEncoding<u32>>()
+}
+
+#[test]
+fn test_enc_dec_u64_vbyte() {
+    test_enc_dec_generic::<VByteEncoding<u64>>()
+}
+
+#[test]
+fn test_enc_dec_u16_vbyte_delta() {
+    test_enc_dec_generic::<VByteDeltaEncoding<u16>>()
+}
+
+#[test]
+fn test_enc_dec_u32_vbyte_delta() {
+    test_enc_dec_generic::<VByteDeltaEncoding<u32>>()
+}
+
+#[test]
+fn test_enc_dec_u64_vbyte_delta() {
+    test_enc_dec_generic::<VByteDeltaEncoding<u64>>()
+}
+
+#[test]
+fn test_enc_dec_u16_vbyte_delta1() {
+    test_enc_dec_generic::<VByteDelta1Encoding<u16>>()
+}
+
+#[test]
+fn test_enc_dec_u32_vbyte_delta1() {
+    test_enc_dec_generic::<VByteDelta1Encoding<u32>>()
+}
+
+#[test]
+fn test_enc_dec_u64_vbyte_delta1() {
+    test_enc_dec_generic::<VByteDelta1Encoding<u64>>()
+}
+
+#[test]
+fn test_enc_dec_u16_vbyte_zigzag() {
+    test_enc_dec_generic::<VByteZigZagEncoding<u16>>()
+}
+
+#[test]
+fn test_enc_dec_u32_vbyte_zigzag() {
+    test_enc_dec_generic::<VByteZigZagEncoding<u32>>()
+}
+
+#[test]
+fn test_enc_dec_u64_vbyte_zigzag() {
+    test_enc_dec_generic::<VByteZigZagEncoding<u64>>()
+}
+
+#[test]
+fn test_enc_dec_u16_vbyte_xor() {
+    test_enc_dec_generic::<VByteXorEncoding<u16>>()
+}
+
+#[test]
+fn test_enc_dec_u32_vbyte_xor() {
+    test_enc_dec_generic::<VByteXorEncoding<u32>>()
+}
+
+#[test]
+fn test_enc_dec_u64_vbyte_xor() {
+    test_enc_dec_generic::<VByteXorEncoding<u64>>()
+}
+
+#[test]
+fn test_enc_dec_u16_vbyte_delta_of_delta() {
+    test_enc_dec_generic::<VByteDeltaOfDeltaEncoding<u16>>()
+}
+
+#[test]
+fn test_enc_dec_u32_vbyte_delta_of_delta() {
+    test_enc_dec_generic::<VByteDeltaOfDeltaEncoding<u32>>()
+}
+
+#[test]
+fn test_enc_dec_u64_vbyte_delta_of_delta() {
+    test_enc_dec_generic::<VByteDeltaOfDeltaEncoding<u64>>()
+}
+
+#[test]
+fn test_enc_dec_turbobyte() {
+    test_enc_dec_generic::<TurboByteEncoding<W, u16>>();
+    test_enc_dec_generic::<TurboByteEncoding<W, u32>>();
+    test_enc_dec_generic::<TurboByteEncoding<W128v, u16>>();
+    test_enc_dec_generic::<TurboByteEncoding<W128v, u32>>();
+    test_enc_dec_generic::<TurboByteEncoding<W256v, u32>>();
+}
+
+#[test]
+fn test_enc_dec_turbobyte_delta() {
+    test_enc_dec_generic::<TurboByteDeltaEncoding<W, u16>>();
+    test_enc_dec_generic::<TurboByteDeltaEncoding<W, u32>>();
+    test_enc_dec_generic::<TurboByteDeltaEncoding<W128v, u16>>();
+    test_enc_dec_generic::<TurboByteDeltaEncoding<W128v, u32>>();
+    test_enc_dec_generic::<TurboByteDeltaEncoding<W256v, u32>>();
+}
+
+#[test]
+fn test_enc_dec_turbobyte_delta1() {
+    test_enc_dec_generic::<TurboByteDelta1Encoding<W, u16>>();
+    test_enc_dec_generic::<TurboByteDelta1Encoding<W, u32>>();
+    test_enc_dec_generic::<TurboByteDelta1Encoding<W128v, u16>>();
+    test_enc_dec_generic::<TurboByteDelta1Encoding<W128v, u32>>();
+    test_enc_dec_generic::<TurboByteDelta1Encoding<W256v, u32>>();
+}
+
+#[test]
+fn test_enc_dec_turbobyte_zigzag() {
+    test_enc_dec_generic::<TurboByteZigZagEncoding<W, u16>>();
+    test_enc_dec_generic::<TurboByteZigZagEncoding<W, u32>>();
+    test_enc_dec_generic::<TurboByteZigZagEncoding<W128v, u16>>();
+    test_enc_dec_generic::<TurboByteZigZagEncoding<W128v, u32>>();
+    test_enc_dec_generic::<TurboByteZigZagEncoding<W256v, u32>>();
+}
+
+#[test]
+fn test_enc_dec_turbobyte_xor() {
+    test_enc_dec_generic::<TurboByteXorEncoding<W, u16>>();
+    test_enc_dec_generic::<TurboByteXorEncoding<W, u32>>();
+    test_enc_dec_generic::<TurboByteXorEncoding<W128v, u16>>();
+    test_enc_dec_generic::<TurboByteXorEncoding<W128v, u32>>();
+    test_enc_dec_generic::<TurboByteXorEncoding<W256v, u32>>();
+}
+
+#[test]
+fn test_enc_dec_u8_simplev() {
+    test_enc_dec_generic::<SimpleVEncoding<u8>>()
+}
+
+#[test]
+fn test_enc_dec_u16_simplev() {
+    test_enc_dec_generic::<SimpleVEncoding<u16>>()
+}
+
+#[test]
+fn test_enc_dec_u32_simplev() {
+    test_enc_dec_generic::<SimpleVEncoding<u32>>()
+}
+
+#[test]
+fn test_enc_dec_u64_simplev() {
+    test_enc_dec_generic::<SimpleVEncoding<u64>>()
+}
+
+#[test]
+fn test_enc_dec_elias_fano() {
+    test_enc_dec_generic::<EliasFanoEncoding<W, u32>>();
+    test_enc_dec_generic::<EliasFanoEncoding<W, u64>>();
+    test_enc_dec_generic::<EliasFanoEncoding<W128v, u32>>();
+    test_enc_dec_generic::<EliasFanoEncoding<W256v, u32>>();
+}
+
+#[test]
+fn test_enc_dec_strict_elias_fano() {
+    test_enc_dec_generic::<StrictEliasFanoEncoding<W, u32>>();
+    test_enc_dec_generic::<StrictEliasFanoEncoding<W, u64>>();
+    test_enc_dec_generic::<StrictEliasFanoEncoding<W128v, u32>>();
+    test_enc_dec_generic::<StrictEliasFanoEncoding<W256v, u32>>();
+}
+
+#[test]
+fn test_turbobyte_len() {
+    use crate::v8;
+    let input: Vec<u32> = TurboByteEncoding::<W, u32>::sample(10_000);
+    let mut buf = vec![0u8; v8::bound32(input.len())];
+    assert_eq!(v8::len32(&input), v8::enc32(&input, &mut buf));
+    let input: Vec<u16> = input.iter().map(|&x| x as u16).collect();
+    assert_eq!(v8::len16(&input), v8::enc16(&input, &mut buf));
+    assert!(v8::len16(&input) <= v8::bound16(input.len()));
+}
+
+#[test]
+fn test_simplev_runs() {
+    let input: Vec<u32> = (0..10_000).map(|i| i / 1000).collect();
+    let mut buf = vec![0u8; SimpleVEncoding::<u32>::enc_buf_size(input.len())];
+    let size = SimpleVEncoding::<u32>::encode(&input, &mut buf);
+    // ten runs take far less than a byte per integer
+    assert!(size < input.len() / 10);
+    let mut output = vec![0u32; SimpleVEncoding::<u32>::dec_buf_len(input.len())];
+    assert_eq!(SimpleVEncoding::<u32>::decode(&buf[..size], input.len(), &mut output), size);
+    assert_eq!(input, output[..input.len()]);
+}
+
+#[test]
+fn test_elias_fano_sorted() {
+    let mut buf = vec![0u8; EliasFanoEncoding::<W, u32>::enc_buf_size(4)];
+    assert_eq!(EliasFanoEncoding::<W, u32>::try_encode(&[1, 5, 5, 3], &mut buf), Err(TurboPforError::NotIncreasing { idx: 3 }));
+    assert_eq!(StrictEliasFanoEncoding::<W, u32>::try_encode(&[1, 5, 5, 6], &mut buf), Err(TurboPforError::NotIncreasing { idx: 2 }));
+    assert_eq!(StrictEliasFanoEncoding::<W, u32>::try_encode(&[0, 5], &mut buf), Err(TurboPforError::NotIncreasing { idx: 0 }));
+    // sparse document ids
+    let input: Vec<u32> = (0..10_000).map(|i| 1000 * i + i % 7).collect();
+    let mut buf = vec![0u8; EliasFanoEncoding::<W, u32>::enc_buf_size(input.len())];
+    let size = EliasFanoEncoding::<W, u32>::try_encode(&input, &mut buf).unwrap();
+    assert!(size < 2 * input.len());
+    let mut output = vec![0u32; EliasFanoEncoding::<W, u32>::dec_buf_len(input.len())];
+    assert_eq!(EliasFanoEncoding::<W, u32>::try_decode(&buf[..size], input.len(), &mut output), Ok(size));
+    assert_eq!(input, output[..input.len()]);
+}
+
+#[test]
+fn test_enc_dec_u8_delta_of_delta() {
+    test_enc_dec_generic::<DeltaOfDeltaEncoding<W128v, u8>>()
+}
+
+#[test]
+fn test_enc_dec_u16_delta_of_delta() {
+    test_enc_dec_generic::<DeltaOfDeltaEncoding<W128v, u16>>()
+}
+
+#[test]
+fn test_enc_dec_u32_delta_of_delta() {
+    test_enc_dec_generic::<DeltaOfDeltaEncoding<W128v, u32>>()
+}
+
+#[test]
+fn test_enc_dec_u64_delta_of_delta() {
+    test_enc_dec_generic::<DeltaOfDeltaEncoding<W128v, u64>>()
+}
+
+#[test]
+fn test_delta_of_delta_timestamps() {
+    type E = DeltaOfDeltaEncoding<W128v, u64>;
+    // millisecond timestamps every second with a few ms jitter, sometimes going back
+    let mut rng = rand::thread_rng();
+    let start = 1_700_000_000_000u64;
+    let input: Vec<u64> = (0..10_000).map(|i| start + 1000 * i + rng.gen_range(0..8)).collect();
+    let mut buf = vec![0u8; E::enc_buf_size(input.len())];
+    let size = E::encode_with_start(&input, &mut buf, start);
+    let mut output = vec![0u64; E::dec_buf_len(input.len())];
+    assert_eq!(E::decode_with_start(&buf[..size], input.len(), &mut output, start), size);
+    assert_eq!(input, output[..input.len()]);
+    // zigzag of the raw deltas still spends 10 bits per integer on the 1000ms step
+    let mut zbuf = vec![0u8; ZigZagEncoding::<W, u64>::enc_buf_size(input.len())];
+    assert!(size < ZigZagEncoding::<W, u64>::encode(&input, &mut zbuf));
+}
+
+#[test]
+fn test_enc_dec_u8_bitio_zigzag() {
+    test_enc_dec_generic::<BitIoZigZagEncoding<u8>>()
+}
+
+#[test]
+fn test_enc_dec_u16_bitio_zigzag() {
+    test_enc_dec_generic::<BitIoZigZagEncoding<u16>>()
+}
+
+#[test]
+fn test_enc_dec_u32_bitio_zigzag() {
+    test_enc_dec_generic::<BitIoZigZagEncoding<u32>>()
+}
+
+#[test]
+fn test_enc_dec_u64_bitio_zigzag() {
+    test_enc_dec_generic::<BitIoZigZagEncoding<u64>>()
+}
+
+#[test]
+fn test_enc_dec_u8_bitio_delta_of_delta() {
+    test_enc_dec_generic::<BitIoDeltaOfDeltaEncoding<u8>>()
+}
+
+#[test]
+fn test_enc_dec_u16_bitio_delta_of_delta() {
+    test_enc_dec_generic::<BitIoDeltaOfDeltaEncoding<u16>>()
+}
+
+#[test]
+fn test_enc_dec_u32_bitio_delta_of_delta() {
+    test_enc_dec_generic::<BitIoDeltaOfDeltaEncoding<u32>>()
+}
+
+#[test]
+fn test_enc_dec_u64_bitio_delta_of_delta() {
+    test_enc_dec_generic::<BitIoDeltaOfDeltaEncoding<u64>>()
+}
+
+#[test]
+fn test_bitio_sensor() {
+    // a slowly varying sensor reading in 0.1 units, compared with the block based `ZigZagEncoding`
+    let mut rng = rand::thread_rng();
+    let mut x = 2000i32;
+    let input: Vec<u16> = (0..10_000).map(|_| { x += rng.gen_range(-2..=2); x as u16 }).collect();
+    let mut buf = vec![0u8; BitIoZigZagEncoding::<u16>::enc_buf_size(input.len())];
+    let size = BitIoZigZagEncoding::<u16>::encode_with_start(&input, &mut buf, 2000);
+    let mut output = vec![0u16; BitIoZigZagEncoding::<u16>::dec_buf_len(input.len())];
+    assert_eq!(BitIoZigZagEncoding::<u16>::decode_with_start(&buf[..size], input.len(), &mut output, 2000), size);
+    assert_eq!(input, output[..input.len()]);
+    assert!(size < input.len());
+    let size = BitIoDeltaOfDeltaEncoding::<u16>::encode_with_start(&input, &mut buf, 2000);
+    assert_eq!(BitIoDeltaOfDeltaEncoding::<u16>::decode_with_start(&buf[..size], input.len(), &mut output, 2000), size);
+    assert_eq!(input, output[..input.len()]);
+}
+
+#[test]
+fn test_enc_dec_xor() {
+    test_enc_dec_generic::<XorEncoding<W, u8>>();
+    test_enc_dec_generic::<XorEncoding<W, u16>>();
+    test_enc_dec_generic::<XorEncoding<W, u32>>();
+    test_enc_dec_generic::<XorEncoding<W, u64>>();
+    test_enc_dec_generic::<XorEncoding<W128v, u16>>();
+    test_enc_dec_generic::<XorEncoding<W128v, u32>>();
+    test_enc_dec_generic::<XorEncoding<W256v, u32>>();
+}
+
+#[test]
+fn test_xor_shared_high_bits() {
+    use crate::bit;
+    // neighbours differ in the low byte only: the XOR needs 8 bits, the zigzag of the delta 9
+    let mut rng = rand::thread_rng();
+    let input: Vec<u32> = (0..10_000).map(|_| 0xdead_be00 | rng.gen_range(0..256)).collect();
+    let mut buf = vec![0u8; XorEncoding::<W, u32>::enc_buf_size(input.len())];
+    let size = XorEncoding::<W, u32>::encode(&input, &mut buf);
+    assert!(size < bit::zpack32(&input, &mut buf));
+}
+
+#[test]
+fn test_enc_dec_bitpack() {
+    test_enc_dec_generic::<BitPackEncoding<W, u8>>();
+    test_enc_dec_generic::<BitPackEncoding<W, u16>>();
+    test_enc_dec_generic::<BitPackEncoding<W, u32>>();
+    test_enc_dec_generic::<BitPackEncoding<W, u64>>();
+    test_enc_dec_generic::<BitPackEncoding<W128v, u16>>();
+    test_enc_dec_generic::<BitPackEncoding<W128v, u32>>();
+    test_enc_dec_generic::<BitPackEncoding<W128v, u64>>();
+    test_enc_dec_generic::<BitPackEncoding<W256v, u32>>();
+}
+
+#[test]
+fn test_enc_dec_bitpack_delta() {
+    test_enc_dec_generic::<BitPackDeltaEncoding<W, u8>>();
+    test_enc_dec_generic::<BitPackDeltaEncoding<W, u16>>();
+    test_enc_dec_generic::<BitPackDeltaEncoding<W, u32>>();
+    test_enc_dec_generic::<BitPackDeltaEncoding<W, u64>>();
+    test_enc_dec_generic::<BitPackDeltaEncoding<W128v, u16>>();
+    test_enc_dec_generic::<BitPackDeltaEncoding<W128v, u32>>();
+    test_enc_dec_generic::<BitPackDeltaEncoding<W256v, u32>>();
+}
+
+#[test]
+fn test_enc_dec_bitpack_strict_delta() {
+    test_enc_dec_generic::<BitPackStrictDeltaEncoding<W, u8>>();
+    test_enc_dec_generic::<BitPackStrictDeltaEncoding<W, u16>>();
+    test_enc_dec_generic::<BitPackStrictDeltaEncoding<W, u32>>();
+    test_enc_dec_generic::<BitPackStrictDeltaEncoding<W, u64>>();
+    test_enc_dec_generic::<BitPackStrictDeltaEncoding<W128v, u16>>();
+    test_enc_dec_generic::<BitPackStrictDeltaEncoding<W128v, u32>>();
+    test_enc_dec_generic::<BitPackStrictDeltaEncoding<W256v, u32>>();
+}
+
+#[test]
+fn test_enc_dec_bitpack_for() {
+    test_enc_dec_generic::<BitPackForEncoding<W, u8>>();
+    test_enc_dec_generic::<BitPackForEncoding<W, u16>>();
+    test_enc_dec_generic::<BitPackForEncoding<W, u32>>();
+    test_enc_dec_generic::<BitPackForEncoding<W, u64>>();
+    test_enc_dec_generic::<BitPackForEncoding<W128v, u16>>();
+    test_enc_dec_generic::<BitPackForEncoding<W128v, u32>>();
+    test_enc_dec_generic::<BitPackForEncoding<W256v, u32>>();
+}
+
+#[test]
+fn test_enc_dec_bitpack_zigzag() {
+    test_enc_dec_generic::<BitPackZigZagEncoding<W, u8>>();
+    test_enc_dec_generic::<BitPackZigZagEncoding<W, u16>>();
+    test_enc_dec_generic::<BitPackZigZagEncoding<W, u32>>();
+    test_enc_dec_generic::<BitPackZigZagEncoding<W, u64>>();
+    test_enc_dec_generic::<BitPackZigZagEncoding<W128v, u16>>();
+    test_enc_dec_generic::<BitPackZigZagEncoding<W128v, u32>>();
+    test_enc_dec_generic::<BitPackZigZagEncoding<W256v, u32>>();
+}
+
+#[test]
+fn test_bitpack_uniform() {
+    // uniform 20 bit integers: no exceptions to patch, bit packing needs 20 bits per integer plus the block headers
+    let mut rng = rand::thread_rng();
+    let input: Vec<u32> = (0..12_800).map(|_| rng.gen_range(0..1 << 20)).collect();
+    let mut buf = vec![0u8; BitPackEncoding::<W, u32>::enc_buf_size(input.len())];
+    let size = try_encode::<BitPackEncoding<W, u32>>(&input, &mut buf).unwrap();
+    assert!(size <= input.len() * 20 / 8 + 100);
+    let mut output = vec![0u32; BitPackEncoding::<W, u32>::dec_buf_len(input.len())];
+    assert_eq!(try_decode::<BitPackEncoding<W, u32>>(&buf[..size], input.len(), &mut output), Ok(size));
+    assert_eq!(input, output[..input.len()]);
+}
+
+#[test]
+fn test_enc_dec_signed_standard() {
+    test_enc_dec_generic::<StandardEncoding<W, i8>>();
+    test_enc_dec_generic::<StandardEncoding<W, i16>>();
+    test_enc_dec_generic::<StandardEncoding<W, i32>>();
+    test_enc_dec_generic::<StandardEncoding<W, i64>>();
+    test_enc_dec_generic::<StandardEncoding<W128v, i16>>();
+    test_enc_dec_generic::<StandardEncoding<W128v, i32>>();
+    test_enc_dec_generic::<StandardEncoding<W128v, i64>>();
+    test_enc_dec_generic::<StandardEncoding<W256v, i32>>();
+}
+
+#[test]
+fn test_enc_dec_signed_increasing() {
+    test_enc_dec_generic::<IncreasingEncoding<W, i8>>();
+    test_enc_dec_generic::<IncreasingEncoding<W, i16>>();
+    test_enc_dec_generic::<IncreasingEncoding<W, i32>>();
+    test_enc_dec_generic::<IncreasingEncoding<W, i64>>();
+    test_enc_dec_generic::<IncreasingEncoding<W128v, i16>>();
+    test_enc_dec_generic::<IncreasingEncoding<W128v, i32>>();
+    test_enc_dec_generic::<IncreasingEncoding<W256v, i32>>();
+}
+
+#[test]
+fn test_enc_dec_signed_strictly_increasing() {
+    test_enc_dec_generic::<StrictlyIncreasingEncoding<W, i8>>();
+    test_enc_dec_generic::<StrictlyIncreasingEncoding<W, i16>>();
+    test_enc_dec_generic::<StrictlyIncreasingEncoding<W, i32>>();
+    test_enc_dec_generic::<StrictlyIncreasingEncoding<W, i64>>();
+    test_enc_dec_generic::<StrictlyIncreasingEncoding<W128v, i16>>();
+    test_enc_dec_generic::<StrictlyIncreasingEncoding<W128v, i32>>();
+    test_enc_dec_generic::<StrictlyIncreasingEncoding<W256v, i32>>();
+}
+
+#[test]
+fn test_enc_dec_signed_zigzag() {
+    test_enc_dec_generic::<ZigZagEncoding<W, i8>>();
+    test_enc_dec_generic::<ZigZagEncoding<W, i16>>();
+    test_enc_dec_generic::<ZigZagEncoding<W, i32>>();
+    test_enc_dec_generic::<ZigZagEncoding<W, i64>>();
+    test_enc_dec_generic::<ZigZagEncoding<W128v, i16>>();
+    test_enc_dec_generic::<ZigZagEncoding<W128v, i32>>();
+    test_enc_dec_generic::<ZigZagEncoding<W256v, i32>>();
+}
+
+#[test]
+fn test_enc_dec_signed_bitpack() {
+    test_enc_dec_generic::<BitPackEncoding<W, i8>>();
+    test_enc_dec_generic::<BitPackEncoding<W, i64>>();
+    test_enc_dec_generic::<BitPackEncoding<W128v, i64>>();
+    test_enc_dec_generic::<BitPackZigZagEncoding<W, i16>>();
+    test_enc_dec_generic::<BitPackZigZagEncoding<W256v, i32>>();
+    test_enc_dec_generic::<BitPackDeltaEncoding<W128v, i32>>();
+}
+
+#[test]
+fn test_signed_small() {
+    // small offsets around 0 take 8 bits instead of the 32 of their two's complement
+    let mut rng = rand::thread_rng();
+    let input: Vec<i32> = (0..10_000).map(|_| rng.gen_range(-100..100)).collect();
+    let mut buf = vec![0u8; StandardEncoding::<W, i32>::enc_buf_size(input.len())];
+    let size = StandardEncoding::<W, i32>::encode(&input, &mut buf);
+    assert!(size <= input.len() + 200);
+    let mut output = vec![0i32; StandardEncoding::<W, i32>::dec_buf_len(input.len())];
+    assert_eq!(StandardEncoding::<W, i32>::decode(&buf[..size], input.len(), &mut output), size);
+    assert_eq!(input, output[..input.len()]);
+    // increasing lists may cross 0
+    let input: Vec<i64> = (-5000..5000).map(|i| 3 * i).collect();
+    let size = StrictlyIncreasingEncoding::<W, i64>::encode(&input, &mut buf);
+    let mut output = vec![0i64; StrictlyIncreasingEncoding::<W, i64>::dec_buf_len(input.len())];
+    StrictlyIncreasingEncoding::<W, i64>::decode(&buf[..size], input.len(), &mut output);
+    assert_eq!(input, output[..input.len()]);
+}
@@ -1,4 +1,3 @@
-// auto-generated by make.py
 // Here are the unsafe wrappers:
 #[link(name = "ic", kind = "static")]
 extern "C" {
@@ -134,4 +133,345 @@ extern "C" {
     pub fn bitnzunpack128v16(inp: *const u8, n: usize, out: *mut u16) -> usize;
     pub fn bitnzunpack128v32(inp: *const u8, n: usize, out: *mut u32) -> usize;
     pub fn bitnzunpack256v32(inp: *const u8, n: usize, out: *mut u32) -> usize;
+    pub fn bitnxpack8(inp: *const u8, n: usize, out: *mut u8) -> usize;
+    pub fn bitnxpack16(inp: *const u16, n: usize, out: *mut u8) -> usize;
+    pub fn bitnxpack32(inp: *const u32, n: usize, out: *mut u8) -> usize;
+    pub fn bitnxpack64(inp: *const u64, n: usize, out: *mut u8) -> usize;
+    pub fn bitnxpack128v16(inp: *const u16, n: usize, out: *mut u8) -> usize;
+    pub fn bitnxpack128v32(inp: *const u32, n: usize, out: *mut u8) -> usize;
+    pub fn bitnxpack256v32(inp: *const u32, n: usize, out: *mut u8) -> usize;
+    pub fn bitnxunpack8(inp: *const u8, n: usize, out: *mut u8) -> usize;
+    pub fn bitnxunpack16(inp: *const u8, n: usize, out: *mut u16) -> usize;
+    pub fn bitnxunpack32(inp: *const u8, n: usize, out: *mut u32) -> usize;
+    pub fn bitnxunpack64(inp: *const u8, n: usize, out: *mut u64) -> usize;
+    pub fn bitnxunpack128v16(inp: *const u8, n: usize, out: *mut u16) -> usize;
+    pub fn bitnxunpack128v32(inp: *const u8, n: usize, out: *mut u32) -> usize;
+    pub fn bitnxunpack256v32(inp: *const u8, n: usize, out: *mut u32) -> usize;
+    pub fn p4dec8(inp: *const u8, n: u32, out: *mut u8) -> *const u8;
+    pub fn p4dec16(inp: *const u8, n: u32, out: *mut u16) -> *const u8;
+    pub fn p4dec32(inp: *const u8, n: u32, out: *mut u32) -> *const u8;
+    pub fn p4dec64(inp: *const u8, n: u32, out: *mut u64) -> *const u8;
+    pub fn p4dec128v16(inp: *const u8, n: u32, out: *mut u16) -> *const u8;
+    pub fn p4dec128v32(inp: *const u8, n: u32, out: *mut u32) -> *const u8;
+    pub fn p4dec128v64(inp: *const u8, n: u32, out: *mut u64) -> *const u8;
+    pub fn p4dec256v32(inp: *const u8, n: u32, out: *mut u32) -> *const u8;
+    pub fn p4ddec8(inp: *const u8, n: u32, out: *mut u8, start: u8) -> *const u8;
+    pub fn p4ddec16(inp: *const u8, n: u32, out: *mut u16, start: u16) -> *const u8;
+    pub fn p4ddec32(inp: *const u8, n: u32, out: *mut u32, start: u32) -> *const u8;
+    pub fn p4ddec64(inp: *const u8, n: u32, out: *mut u64, start: u64) -> *const u8;
+    pub fn p4ddec128v16(inp: *const u8, n: u32, out: *mut u16, start: u16) -> *const u8;
+    pub fn p4ddec128v32(inp: *const u8, n: u32, out: *mut u32, start: u32) -> *const u8;
+    pub fn p4ddec256v32(inp: *const u8, n: u32, out: *mut u32, start: u32) -> *const u8;
+    pub fn p4d1dec8(inp: *const u8, n: u32, out: *mut u8, start: u8) -> *const u8;
+    pub fn p4d1dec16(inp: *const u8, n: u32, out: *mut u16, start: u16) -> *const u8;
+    pub fn p4d1dec32(inp: *const u8, n: u32, out: *mut u32, start: u32) -> *const u8;
+    pub fn p4d1dec64(inp: *const u8, n: u32, out: *mut u64, start: u64) -> *const u8;
+    pub fn p4d1dec128v16(inp: *const u8, n: u32, out: *mut u16, start: u16) -> *const u8;
+    pub fn p4d1dec128v32(inp: *const u8, n: u32, out: *mut u32, start: u32) -> *const u8;
+    pub fn p4d1dec256v32(inp: *const u8, n: u32, out: *mut u32, start: u32) -> *const u8;
+    pub fn p4zdec8(inp: *const u8, n: u32, out: *mut u8, start: u8) -> *const u8;
+    pub fn p4zdec16(inp: *const u8, n: u32, out: *mut u16, start: u16) -> *const u8;
+    pub fn p4zdec32(inp: *const u8, n: u32, out: *mut u32, start: u32) -> *const u8;
+    pub fn p4zdec64(inp: *const u8, n: u32, out: *mut u64, start: u64) -> *const u8;
+    pub fn p4zdec128v16(inp: *const u8, n: u32, out: *mut u16, start: u16) -> *const u8;
+    pub fn p4zdec128v32(inp: *const u8, n: u32, out: *mut u32, start: u32) -> *const u8;
+    pub fn p4zdec256v32(inp: *const u8, n: u32, out: *mut u32, start: u32) -> *const u8;
+    pub fn p4encx8(inp: *const u8, n: u32, out: *mut u8) -> *const u8;
+    pub fn p4encx16(inp: *const u16, n: u32, out: *mut u8) -> *const u8;
+    pub fn p4encx32(inp: *const u32, n: u32, out: *mut u8) -> *const u8;
+    pub fn bitpack8(inp: *const u8, n: u32, out: *mut u8, b: u32) -> *const u8;
+    pub fn bitunpack8(inp: *const u8, n: u32, out: *mut u8, b: u32) -> *const u8;
+    pub fn bitpack16(inp: *const u16, n: u32, out: *mut u8, b: u32) -> *const u8;
+    pub fn bitunpack16(inp: *const u8, n: u32, out: *mut u16, b: u32) -> *const u8;
+    pub fn bitpack32(inp: *const u32, n: u32, out: *mut u8, b: u32) -> *const u8;
+    pub fn bitunpack32(inp: *const u8, n: u32, out: *mut u32, b: u32) -> *const u8;
+    pub fn bitpack64(inp: *const u64, n: u32, out: *mut u8, b: u32) -> *const u8;
+    pub fn bitunpack64(inp: *const u8, n: u32, out: *mut u64, b: u32) -> *const u8;
+    pub fn p4enc8(inp: *const u8, n: u32, out: *mut u8) -> *const u8;
+    pub fn p4enc16(inp: *const u16, n: u32, out: *mut u8) -> *const u8;
+    pub fn p4enc32(inp: *const u32, n: u32, out: *mut u8) -> *const u8;
+    pub fn p4enc64(inp: *const u64, n: u32, out: *mut u8) -> *const u8;
+    pub fn p4enc128v16(inp: *const u16, n: u32, out: *mut u8) -> *const u8;
+    pub fn p4enc128v32(inp: *const u32, n: u32, out: *mut u8) -> *const u8;
+    pub fn p4enc128v64(inp: *const u64, n: u32, out: *mut u8) -> *const u8;
+    pub fn p4enc256v32(inp: *const u32, n: u32, out: *mut u8) -> *const u8;
+    pub fn p4denc8(inp: *const u8, n: u32, out: *mut u8, start: u8) -> *const u8;
+    pub fn p4denc16(inp: *const u16, n: u32, out: *mut u8, start: u16) -> *const u8;
+    pub fn p4denc32(inp: *const u32, n: u32, out: *mut u8, start: u32) -> *const u8;
+    pub fn p4denc64(inp: *const u64, n: u32, out: *mut u8, start: u64) -> *const u8;
+    pub fn p4denc128v16(inp: *const u16, n: u32, out: *mut u8, start: u16) -> *const u8;
+    pub fn p4denc128v32(inp: *const u32, n: u32, out: *mut u8, start: u32) -> *const u8;
+    pub fn p4denc256v32(inp: *const u32, n: u32, out: *mut u8, start: u32) -> *const u8;
+    pub fn p4d1enc8(inp: *const u8, n: u32, out: *mut u8, start: u8) -> *const u8;
+    pub fn p4d1enc16(inp: *const u16, n: u32, out: *mut u8, start: u16) -> *const u8;
+    pub fn p4d1enc32(inp: *const u32, n: u32, out: *mut u8, start: u32) -> *const u8;
+    pub fn p4d1enc64(inp: *const u64, n: u32, out: *mut u8, start: u64) -> *const u8;
+    pub fn p4d1enc128v16(inp: *const u16, n: u32, out: *mut u8, start: u16) -> *const u8;
+    pub fn p4d1enc128v32(inp: *const u32, n: u32, out: *mut u8, start: u32) -> *const u8;
+    pub fn p4d1enc256v32(inp: *const u32, n: u32, out: *mut u8, start: u32) -> *const u8;
+    pub fn p4zenc8(inp: *const u8, n: u32, out: *mut u8, start: u8) -> *const u8;
+    pub fn p4zenc16(inp: *const u16, n: u32, out: *mut u8, start: u16) -> *const u8;
+    pub fn p4zenc32(inp: *const u32, n: u32, out: *mut u8, start: u32) -> *const u8;
+    pub fn p4zenc64(inp: *const u64, n: u32, out: *mut u8, start: u64) -> *const u8;
+    pub fn p4zenc128v16(inp: *const u16, n: u32, out: *mut u8, start: u16) -> *const u8;
+    pub fn p4zenc128v32(inp: *const u32, n: u32, out: *mut u8, start: u32) -> *const u8;
+    pub fn p4zenc256v32(inp: *const u32, n: u32, out: *mut u8, start: u32) -> *const u8;
+    pub fn _p4bits8(inp: *const u8, n: u32, pbx: *mut u32) -> u32;
+    pub fn _p4bits16(inp: *const u16, n: u32, pbx: *mut u32) -> u32;
+    pub fn _p4bits32(inp: *const u32, n: u32, pbx: *mut u32) -> u32;
+    pub fn _p4bits64(inp: *const u64, n: u32, pbx: *mut u32) -> u32;
+    pub fn _p4enc8(inp: *const u8, n: u32, out: *mut u8, b: u32, bx: u32) -> *const u8;
+    pub fn _p4enc16(inp: *const u16, n: u32, out: *mut u8, b: u32, bx: u32) -> *const u8;
+    pub fn _p4enc32(inp: *const u32, n: u32, out: *mut u8, b: u32, bx: u32) -> *const u8;
+    pub fn _p4enc64(inp: *const u64, n: u32, out: *mut u8, b: u32, bx: u32) -> *const u8;
+    pub fn _p4enc128v16(inp: *const u16, n: u32, out: *mut u8, b: u32, bx: u32) -> *const u8;
+    pub fn _p4enc128v32(inp: *const u32, n: u32, out: *mut u8, b: u32, bx: u32) -> *const u8;
+    pub fn _p4enc128v64(inp: *const u64, n: u32, out: *mut u8, b: u32, bx: u32) -> *const u8;
+    pub fn _p4enc256v32(inp: *const u32, n: u32, out: *mut u8, b: u32, bx: u32) -> *const u8;
+    pub fn _p4dec8(inp: *const u8, n: u32, out: *mut u8, b: u32, bx: u32) -> *const u8;
+    pub fn _p4dec16(inp: *const u8, n: u32, out: *mut u16, b: u32, bx: u32) -> *const u8;
+    pub fn _p4dec32(inp: *const u8, n: u32, out: *mut u32, b: u32, bx: u32) -> *const u8;
+    pub fn _p4dec64(inp: *const u8, n: u32, out: *mut u64, b: u32, bx: u32) -> *const u8;
+    pub fn _p4dec128v16(inp: *const u8, n: u32, out: *mut u16, b: u32, bx: u32) -> *const u8;
+    pub fn _p4dec128v32(inp: *const u8, n: u32, out: *mut u32, b: u32, bx: u32) -> *const u8;
+    pub fn _p4dec128v64(inp: *const u8, n: u32, out: *mut u64, b: u32, bx: u32) -> *const u8;
+    pub fn _p4ddec8(inp: *const u8, n: u32, out: *mut u8, start: u8, b: u32, bx: u32) -> *const u8;
+    pub fn _p4ddec16(inp: *const u8, n: u32, out: *mut u16, start: u16, b: u32, bx: u32) -> *const u8;
+    pub fn _p4ddec32(inp: *const u8, n: u32, out: *mut u32, start: u32, b: u32, bx: u32) -> *const u8;
+    pub fn _p4ddec64(inp: *const u8, n: u32, out: *mut u64, start: u64, b: u32, bx: u32) -> *const u8;
+    pub fn _p4ddec128v16(inp: *const u8, n: u32, out: *mut u16, start: u16, b: u32, bx: u32) -> *const u8;
+    pub fn _p4ddec128v32(inp: *const u8, n: u32, out: *mut u32, start: u32, b: u32, bx: u32) -> *const u8;
+    pub fn _p4ddec256v32(inp: *const u8, n: u32, out: *mut u32, start: u32, b: u32, bx: u32) -> *const u8;
+    pub fn _p4d1dec8(inp: *const u8, n: u32, out: *mut u8, start: u8, b: u32, bx: u32) -> *const u8;
+    pub fn _p4d1dec16(inp: *const u8, n: u32, out: *mut u16, start: u16, b: u32, bx: u32) -> *const u8;
+    pub fn _p4d1dec32(inp: *const u8, n: u32, out: *mut u32, start: u32, b: u32, bx: u32) -> *const u8;
+    pub fn _p4d1dec64(inp: *const u8, n: u32, out: *mut u64, start: u64, b: u32, bx: u32) -> *const u8;
+    pub fn _p4d1dec128v16(inp: *const u8, n: u32, out: *mut u16, start: u16, b: u32, bx: u32) -> *const u8;
+    pub fn _p4d1dec128v32(inp: *const u8, n: u32, out: *mut u32, start: u32, b: u32, bx: u32) -> *const u8;
+    pub fn _p4d1dec256v32(inp: *const u8, n: u32, out: *mut u32, start: u32, b: u32, bx: u32) -> *const u8;
+    pub fn _p4zdec8(inp: *const u8, n: u32, out: *mut u8, start: u8, b: u32, bx: u32) -> *const u8;
+    pub fn _p4zdec16(inp: *const u8, n: u32, out: *mut u16, start: u16, b: u32, bx: u32) -> *const u8;
+    pub fn _p4zdec32(inp: *const u8, n: u32, out: *mut u32, start: u32, b: u32, bx: u32) -> *const u8;
+    pub fn _p4zdec64(inp: *const u8, n: u32, out: *mut u64, start: u64, b: u32, bx: u32) -> *const u8;
+    pub fn _p4zdec128v16(inp: *const u8, n: u32, out: *mut u16, start: u16, b: u32, bx: u32) -> *const u8;
+    pub fn _p4zdec128v32(inp: *const u8, n: u32, out: *mut u32, start: u32, b: u32, bx: u32) -> *const u8;
+    pub fn _p4zdec256v32(inp: *const u8, n: u32, out: *mut u32, start: u32, b: u32, bx: u32) -> *const u8;
+    pub fn vbenc16(inp: *const u16, n: u32, out: *mut u8) -> *const u8;
+    pub fn vbdec16(inp: *const u8, n: u32, out: *mut u16) -> *const u8;
+    pub fn vbenc32(inp: *const u32, n: u32, out: *mut u8) -> *const u8;
+    pub fn vbdec32(inp: *const u8, n: u32, out: *mut u32) -> *const u8;
+    pub fn vbenc64(inp: *const u64, n: u32, out: *mut u8) -> *const u8;
+    pub fn vbdec64(inp: *const u8, n: u32, out: *mut u64) -> *const u8;
+    pub fn vbdenc16(inp: *const u16, n: u32, out: *mut u8, start: u16) -> *const u8;
+    pub fn vbddec16(inp: *const u8, n: u32, out: *mut u16, start: u16) -> *const u8;
+    pub fn vbdenc32(inp: *const u32, n: u32, out: *mut u8, start: u32) -> *const u8;
+    pub fn vbddec32(inp: *const u8, n: u32, out: *mut u32, start: u32) -> *const u8;
+    pub fn vbdenc64(inp: *const u64, n: u32, out: *mut u8, start: u64) -> *const u8;
+    pub fn vbddec64(inp: *const u8, n: u32, out: *mut u64, start: u64) -> *const u8;
+    pub fn vbd1enc16(inp: *const u16, n: u32, out: *mut u8, start: u16) -> *const u8;
+    pub fn vbd1dec16(inp: *const u8, n: u32, out: *mut u16, start: u16) -> *const u8;
+    pub fn vbd1enc32(inp: *const u32, n: u32, out: *mut u8, start: u32) -> *const u8;
+    pub fn vbd1dec32(inp: *const u8, n: u32, out: *mut u32, start: u32) -> *const u8;
+    pub fn vbd1enc64(inp: *const u64, n: u32, out: *mut u8, start: u64) -> *const u8;
+    pub fn vbd1dec64(inp: *const u8, n: u32, out: *mut u64, start: u64) -> *const u8;
+    pub fn vbzenc8(inp: *const u8, n: u32, out: *mut u8, start: u8) -> *const u8;
+    pub fn vbzdec8(inp: *const u8, n: u32, out: *mut u8, start: u8) -> *const u8;
+    pub fn vbzenc16(inp: *const u16, n: u32, out: *mut u8, start: u16) -> *const u8;
+    pub fn vbzdec16(inp: *const u8, n: u32, out: *mut u16, start: u16) -> *const u8;
+    pub fn vbzenc32(inp: *const u32, n: u32, out: *mut u8, start: u32) -> *const u8;
+    pub fn vbzdec32(inp: *const u8, n: u32, out: *mut u32, start: u32) -> *const u8;
+    pub fn vbzenc64(inp: *const u64, n: u32, out: *mut u8, start: u64) -> *const u8;
+    pub fn vbzdec64(inp: *const u8, n: u32, out: *mut u64, start: u64) -> *const u8;
+    pub fn vbxenc8(inp: *const u8, n: u32, out: *mut u8, start: u8) -> *const u8;
+    pub fn vbxdec8(inp: *const u8, n: u32, out: *mut u8, start: u8) -> *const u8;
+    pub fn vbxenc16(inp: *const u16, n: u32, out: *mut u8, start: u16) -> *const u8;
+    pub fn vbxdec16(inp: *const u8, n: u32, out: *mut u16, start: u16) -> *const u8;
+    pub fn vbxenc32(inp: *const u32, n: u32, out: *mut u8, start: u32) -> *const u8;
+    pub fn vbxdec32(inp: *const u8, n: u32, out: *mut u32, start: u32) -> *const u8;
+    pub fn vbxenc64(inp: *const u64, n: u32, out: *mut u8, start: u64) -> *const u8;
+    pub fn vbxdec64(inp: *const u8, n: u32, out: *mut u64, start: u64) -> *const u8;
+    pub fn vbddenc16(inp: *const u16, n: u32, out: *mut u8, start: u16) -> *const u8;
+    pub fn vbdddec16(inp: *const u8, n: u32, out: *mut u16, start: u16) -> *const u8;
+    pub fn vbddenc32(inp: *const u32, n: u32, out: *mut u8, start: u32) -> *const u8;
+    pub fn vbdddec32(inp: *const u8, n: u32, out: *mut u32, start: u32) -> *const u8;
+    pub fn vbddenc64(inp: *const u64, n: u32, out: *mut u8, start: u64) -> *const u8;
+    pub fn vbdddec64(inp: *const u8, n: u32, out: *mut u64, start: u64) -> *const u8;
+    pub fn vbgetx16(inp: *const u8, idx: u32) -> u16;
+    pub fn vbgeteq16(inp: *mut *const u8, n: u32, idx: u32, key: u16) -> u32;
+    pub fn vbgetx32(inp: *const u8, idx: u32) -> u32;
+    pub fn vbgeteq32(inp: *mut *const u8, n: u32, idx: u32, key: u32) -> u32;
+    pub fn vbgetx64(inp: *const u8, idx: u32) -> u64;
+    pub fn vbgeteq64(inp: *mut *const u8, n: u32, idx: u32, key: u64) -> u32;
+    pub fn vbdgetx16(inp: *const u8, idx: u32, start: u16) -> u16;
+    pub fn vbdgetgeq16(inp: *mut *const u8, n: u32, idx: u32, key: *mut u16, start: u16) -> u32;
+    pub fn vbdgetx32(inp: *const u8, idx: u32, start: u32) -> u32;
+    pub fn vbdgetgeq32(inp: *mut *const u8, n: u32, idx: u32, key: *mut u32, start: u32) -> u32;
+    pub fn vbdgetx64(inp: *const u8, idx: u32, start: u64) -> u64;
+    pub fn vbdgetgeq64(inp: *mut *const u8, n: u32, idx: u32, key: *mut u64, start: u64) -> u32;
+    pub fn vbd1getx16(inp: *const u8, idx: u32, start: u16) -> u16;
+    pub fn vbd1getgeq16(inp: *mut *const u8, n: u32, idx: u32, key: *mut u16, start: u16) -> u32;
+    pub fn vbd1getx32(inp: *const u8, idx: u32, start: u32) -> u32;
+    pub fn vbd1getgeq32(inp: *mut *const u8, n: u32, idx: u32, key: *mut u32, start: u32) -> u32;
+    pub fn vbd1getx64(inp: *const u8, idx: u32, start: u64) -> u64;
+    pub fn vbd1getgeq64(inp: *mut *const u8, n: u32, idx: u32, key: *mut u64, start: u64) -> u32;
+    pub fn v8len16(inp: *const u16, n: usize) -> usize;
+    pub fn v8len32(inp: *const u32, n: usize) -> usize;
+    pub fn v8nenc16(inp: *const u16, n: usize, out: *mut u8) -> usize;
+    pub fn v8ndec16(inp: *const u8, n: usize, out: *mut u16) -> usize;
+    pub fn v8nenc32(inp: *const u32, n: usize, out: *mut u8) -> usize;
+    pub fn v8ndec32(inp: *const u8, n: usize, out: *mut u32) -> usize;
+    pub fn v8nenc128v16(inp: *const u16, n: usize, out: *mut u8) -> usize;
+    pub fn v8ndec128v16(inp: *const u8, n: usize, out: *mut u16) -> usize;
+    pub fn v8nenc128v32(inp: *const u32, n: usize, out: *mut u8) -> usize;
+    pub fn v8ndec128v32(inp: *const u8, n: usize, out: *mut u32) -> usize;
+    pub fn v8nenc256v32(inp: *const u32, n: usize, out: *mut u8) -> usize;
+    pub fn v8ndec256v32(inp: *const u8, n: usize, out: *mut u32) -> usize;
+    pub fn v8ndenc16(inp: *const u16, n: usize, out: *mut u8) -> usize;
+    pub fn v8nddec16(inp: *const u8, n: usize, out: *mut u16) -> usize;
+    pub fn v8ndenc32(inp: *const u32, n: usize, out: *mut u8) -> usize;
+    pub fn v8nddec32(inp: *const u8, n: usize, out: *mut u32) -> usize;
+    pub fn v8ndenc128v16(inp: *const u16, n: usize, out: *mut u8) -> usize;
+    pub fn v8nddec128v16(inp: *const u8, n: usize, out: *mut u16) -> usize;
+    pub fn v8ndenc128v32(inp: *const u32, n: usize, out: *mut u8) -> usize;
+    pub fn v8nddec128v32(inp: *const u8, n: usize, out: *mut u32) -> usize;
+    pub fn v8ndenc256v32(inp: *const u32, n: usize, out: *mut u8) -> usize;
+    pub fn v8nddec256v32(inp: *const u8, n: usize, out: *mut u32) -> usize;
+    pub fn v8nd1enc16(inp: *const u16, n: usize, out: *mut u8) -> usize;
+    pub fn v8nd1dec16(inp: *const u8, n: usize, out: *mut u16) -> usize;
+    pub fn v8nd1enc32(inp: *const u32, n: usize, out: *mut u8) -> usize;
+    pub fn v8nd1dec32(inp: *const u8, n: usize, out: *mut u32) -> usize;
+    pub fn v8nd1enc128v16(inp: *const u16, n: usize, out: *mut u8) -> usize;
+    pub fn v8nd1dec128v16(inp: *const u8, n: usize, out: *mut u16) -> usize;
+    pub fn v8nd1enc128v32(inp: *const u32, n: usize, out: *mut u8) -> usize;
+    pub fn v8nd1dec128v32(inp: *const u8, n: usize, out: *mut u32) -> usize;
+    pub fn v8nd1enc256v32(inp: *const u32, n: usize, out: *mut u8) -> usize;
+    pub fn v8nd1dec256v32(inp: *const u8, n: usize, out: *mut u32) -> usize;
+    pub fn v8nzenc16(inp: *const u16, n: usize, out: *mut u8) -> usize;
+    pub fn v8nzdec16(inp: *const u8, n: usize, out: *mut u16) -> usize;
+    pub fn v8nzenc32(inp: *const u32, n: usize, out: *mut u8) -> usize;
+    pub fn v8nzdec32(inp: *const u8, n: usize, out: *mut u32) -> usize;
+    pub fn v8nzenc128v16(inp: *const u16, n: usize, out: *mut u8) -> usize;
+    pub fn v8nzdec128v16(inp: *const u8, n: usize, out: *mut u16) -> usize;
+    pub fn v8nzenc128v32(inp: *const u32, n: usize, out: *mut u8) -> usize;
+    pub fn v8nzdec128v32(inp: *const u8, n: usize, out: *mut u32) -> usize;
+    pub fn v8nzenc256v32(inp: *const u32, n: usize, out: *mut u8) -> usize;
+    pub fn v8nzdec256v32(inp: *const u8, n: usize, out: *mut u32) -> usize;
+    pub fn v8nxenc16(inp: *const u16, n: usize, out: *mut u8) -> usize;
+    pub fn v8nxdec16(inp: *const u8, n: usize, out: *mut u16) -> usize;
+    pub fn v8nxenc32(inp: *const u32, n: usize, out: *mut u8) -> usize;
+    pub fn v8nxdec32(inp: *const u8, n: usize, out: *mut u32) -> usize;
+    pub fn v8nxenc128v16(inp: *const u16, n: usize, out: *mut u8) -> usize;
+    pub fn v8nxdec128v16(inp: *const u8, n: usize, out: *mut u16) -> usize;
+    pub fn v8nxenc128v32(inp: *const u32, n: usize, out: *mut u8) -> usize;
+    pub fn v8nxdec128v32(inp: *const u8, n: usize, out: *mut u32) -> usize;
+    pub fn v8nxenc256v32(inp: *const u32, n: usize, out: *mut u8) -> usize;
+    pub fn v8nxdec256v32(inp: *const u8, n: usize, out: *mut u32) -> usize;
+    pub fn vsenc8(inp: *const u8, n: usize, out: *mut u8) -> *const u8;
+    pub fn vsdec8(inp: *const u8, n: usize, out: *mut u8) -> *const u8;
+    pub fn vsenc16(inp: *const u16, n: usize, out: *mut u8) -> *const u8;
+    pub fn vsdec16(inp: *const u8, n: usize, out: *mut u16) -> *const u8;
+    pub fn vsenc32(inp: *const u32, n: usize, out: *mut u8) -> *const u8;
+    pub fn vsdec32(inp: *const u8, n: usize, out: *mut u32) -> *const u8;
+    pub fn vsenc64(inp: *const u64, n: usize, out: *mut u8) -> *const u8;
+    pub fn vsdec64(inp: *const u8, n: usize, out: *mut u64) -> *const u8;
+    pub fn efanoenc32(inp: *const u32, n: u32, out: *mut u8, start: u32) -> *const u8;
+    pub fn efanodec32(inp: *const u8, n: u32, out: *mut u32, start: u32) -> *const u8;
+    pub fn efanoenc64(inp: *const u64, n: u32, out: *mut u8, start: u64) -> *const u8;
+    pub fn efanodec64(inp: *const u8, n: u32, out: *mut u64, start: u64) -> *const u8;
+    pub fn efanoenc128v32(inp: *const u32, n: u32, out: *mut u8, start: u32) -> *const u8;
+    pub fn efanodec128v32(inp: *const u8, n: u32, out: *mut u32, start: u32) -> *const u8;
+    pub fn efanoenc256v32(inp: *const u32, n: u32, out: *mut u8, start: u32) -> *const u8;
+    pub fn efanodec256v32(inp: *const u8, n: u32, out: *mut u32, start: u32) -> *const u8;
+    pub fn efano1enc32(inp: *const u32, n: u32, out: *mut u8, start: u32) -> *const u8;
+    pub fn efano1dec32(inp: *const u8, n: u32, out: *mut u32, start: u32) -> *const u8;
+    pub fn efano1enc64(inp: *const u64, n: u32, out: *mut u8, start: u64) -> *const u8;
+    pub fn efano1dec64(inp: *const u8, n: u32, out: *mut u64, start: u64) -> *const u8;
+    pub fn efano1enc128v32(inp: *const u32, n: u32, out: *mut u8, start: u32) -> *const u8;
+    pub fn efano1dec128v32(inp: *const u8, n: u32, out: *mut u32, start: u32) -> *const u8;
+    pub fn efano1enc256v32(inp: *const u32, n: u32, out: *mut u8, start: u32) -> *const u8;
+    pub fn efano1dec256v32(inp: *const u8, n: u32, out: *mut u32, start: u32) -> *const u8;
+    pub fn fpgenc32(inp: *const u32, n: usize, out: *mut u8, start: u32) -> usize;
+    pub fn fpgdec32(inp: *const u8, n: usize, out: *mut u32, start: u32) -> usize;
+    pub fn fpgenc64(inp: *const u64, n: usize, out: *mut u8, start: u64) -> usize;
+    pub fn fpgdec64(inp: *const u8, n: usize, out: *mut u64, start: u64) -> usize;
+    pub fn fphenc32(inp: *const u32, n: usize, out: *mut u8, start: u32) -> usize;
+    pub fn fphdec32(inp: *const u8, n: usize, out: *mut u32, start: u32) -> usize;
+    pub fn fphenc64(inp: *const u64, n: usize, out: *mut u8, start: u64) -> usize;
+    pub fn fphdec64(inp: *const u8, n: usize, out: *mut u64, start: u64) -> usize;
+    pub fn fpcenc32(inp: *const u32, n: usize, out: *mut u8, start: u32) -> usize;
+    pub fn fpcdec32(inp: *const u8, n: usize, out: *mut u32, start: u32) -> usize;
+    pub fn fpcenc64(inp: *const u64, n: usize, out: *mut u8, start: u64) -> usize;
+    pub fn fpcdec64(inp: *const u8, n: usize, out: *mut u64, start: u64) -> usize;
+    pub fn fpfcmenc32(inp: *const u32, n: usize, out: *mut u8, start: u32) -> usize;
+    pub fn fpfcmdec32(inp: *const u8, n: usize, out: *mut u32, start: u32) -> usize;
+    pub fn fpfcmenc64(inp: *const u64, n: usize, out: *mut u8, start: u64) -> usize;
+    pub fn fpfcmdec64(inp: *const u8, n: usize, out: *mut u64, start: u64) -> usize;
+    pub fn fpdfcmenc32(inp: *const u32, n: usize, out: *mut u8, start: u32) -> usize;
+    pub fn fpdfcmdec32(inp: *const u8, n: usize, out: *mut u32, start: u32) -> usize;
+    pub fn fpdfcmenc64(inp: *const u64, n: usize, out: *mut u8, start: u64) -> usize;
+    pub fn fpdfcmdec64(inp: *const u8, n: usize, out: *mut u64, start: u64) -> usize;
+    pub fn fpxenc32(inp: *const u32, n: usize, out: *mut u8, start: u32) -> usize;
+    pub fn fpxdec32(inp: *const u8, n: usize, out: *mut u32, start: u32) -> usize;
+    pub fn fpxenc64(inp: *const u64, n: usize, out: *mut u8, start: u64) -> usize;
+    pub fn fpxdec64(inp: *const u8, n: usize, out: *mut u64, start: u64) -> usize;
+    pub fn fpc0enc32(inp: *const u32, n: usize, out: *mut u8, start: u32) -> usize;
+    pub fn fpc0enc64(inp: *const u64, n: usize, out: *mut u8, start: u64) -> usize;
+    pub fn p4nzzenc128v8(inp: *const u8, n: usize, out: *mut u8, start: u8) -> usize;
+    pub fn p4nzzdec128v8(inp: *const u8, n: usize, out: *mut u8, start: u8) -> usize;
+    pub fn p4nzzenc128v16(inp: *const u16, n: usize, out: *mut u8, start: u16) -> usize;
+    pub fn p4nzzdec128v16(inp: *const u8, n: usize, out: *mut u16, start: u16) -> usize;
+    pub fn p4nzzenc128v32(inp: *const u32, n: usize, out: *mut u8, start: u32) -> usize;
+    pub fn p4nzzdec128v32(inp: *const u8, n: usize, out: *mut u32, start: u32) -> usize;
+    pub fn p4nzzenc128v64(inp: *const u64, n: usize, out: *mut u8, start: u64) -> usize;
+    pub fn p4nzzdec128v64(inp: *const u8, n: usize, out: *mut u64, start: u64) -> usize;
+    pub fn bvzenc8(inp: *const u8, n: usize, out: *mut u8, start: u8) -> usize;
+    pub fn bvzdec8(inp: *const u8, n: usize, out: *mut u8, start: u8) -> usize;
+    pub fn bvzenc16(inp: *const u16, n: usize, out: *mut u8, start: u16) -> usize;
+    pub fn bvzdec16(inp: *const u8, n: usize, out: *mut u16, start: u16) -> usize;
+    pub fn bvzenc32(inp: *const u32, n: usize, out: *mut u8, start: u32) -> usize;
+    pub fn bvzdec32(inp: *const u8, n: usize, out: *mut u32, start: u32) -> usize;
+    pub fn bvzenc64(inp: *const u64, n: usize, out: *mut u8, start: u64) -> usize;
+    pub fn bvzdec64(inp: *const u8, n: usize, out: *mut u64, start: u64) -> usize;
+    pub fn bvzzenc8(inp: *const u8, n: usize, out: *mut u8, start: u8) -> usize;
+    pub fn bvzzdec8(inp: *const u8, n: usize, out: *mut u8, start: u8) -> usize;
+    pub fn bvzzenc16(inp: *const u16, n: usize, out: *mut u8, start: u16) -> usize;
+    pub fn bvzzdec16(inp: *const u8, n: usize, out: *mut u16, start: u16) -> usize;
+    pub fn bvzzenc32(inp: *const u32, n: usize, out: *mut u8, start: u32) -> usize;
+    pub fn bvzzdec32(inp: *const u8, n: usize, out: *mut u32, start: u32) -> usize;
+    pub fn bvzzenc64(inp: *const u64, n: usize, out: *mut u8, start: u64) -> usize;
+    pub fn bvzzdec64(inp: *const u8, n: usize, out: *mut u64, start: u64) -> usize;
+    pub fn p4nbound8(n: usize) -> usize;
+    pub fn p4nbound16(n: usize) -> usize;
+    pub fn p4nbound32(n: usize) -> usize;
+    pub fn p4nbound64(n: usize) -> usize;
+    pub fn p4nbound128v8(n: usize) -> usize;
+    pub fn p4nbound128v16(n: usize) -> usize;
+    pub fn p4nbound128v32(n: usize) -> usize;
+    pub fn p4nbound128v64(n: usize) -> usize;
+    pub fn p4nbound256v8(n: usize) -> usize;
+    pub fn p4nbound256v16(n: usize) -> usize;
+    pub fn p4nbound256v32(n: usize) -> usize;
+    pub fn p4nbound256v64(n: usize) -> usize;
+    pub fn bitnbound8(n: usize) -> usize;
+    pub fn bitnbound16(n: usize) -> usize;
+    pub fn bitnbound32(n: usize) -> usize;
+    pub fn bitnbound64(n: usize) -> usize;
+    pub fn bitnbound128v8(n: usize) -> usize;
+    pub fn bitnbound128v16(n: usize) -> usize;
+    pub fn bitnbound128v32(n: usize) -> usize;
+    pub fn bitnbound128v64(n: usize) -> usize;
+    pub fn bitnbound256v8(n: usize) -> usize;
+    pub fn bitnbound256v16(n: usize) -> usize;
+    pub fn bitnbound256v32(n: usize) -> usize;
+    pub fn bitnbound256v64(n: usize) -> usize;
+    pub fn vbbound8(n: usize) -> usize;
+    pub fn vbbound16(n: usize) -> usize;
+    pub fn vbbound32(n: usize) -> usize;
+    pub fn vbbound64(n: usize) -> usize;
+    pub fn vsbound8(n: usize) -> usize;
+    pub fn vsbound16(n: usize) -> usize;
+    pub fn vsbound32(n: usize) -> usize;
+    pub fn vsbound64(n: usize) -> usize;
+    pub fn v8bound16(n: usize) -> usize;
+    pub fn v8bound32(n: usize) -> usize;
 }
@@ -0,0 +1,243 @@
+/// Mutable bit-packed arrays.
+///
+/// `PackedArray` stores `len` integers with a fixed bit width `b` in the layout of the low-level
+/// `bitpack*` functions (`bit::pack_bits*`): integer `i` goes to bits `b*i..b*(i+1)` of a little endian bit stream.
+/// Single entries are read and written in place, which makes it a compact store for counters, flags or
+/// dictionary codes whose range is known up front.
+///
+/// The C access functions `bitgetx*` and `bitsetx*` are `static inline` in bitpack.h and aren't exported
+/// by the library, so they are ported here. The bulk conversions `from_slice` and `to_vec` use the C functions.
+use crate::bit;
+use crate::codec::*;
+use crate::error::*;
+
+/// Integer types supported by `PackedArray`
+pub trait PackedInt: Copy + Default {
+    /// Number of bits of the type
+    const BITS: u32;
+    /// Bit pack `input` with bit width `b`, see `bit::pack_bits32`
+    fn pack(input: &[Self], output: &mut [u8], b: u32) -> usize;
+    /// Unpack `n` integers of bit width `b`, see `bit::unpack_bits32`
+    fn unpack(input: &[u8], n: usize, output: &mut [Self], b: u32) -> usize;
+    /// Upper bound of the bitpacked size of `n` integers
+    fn bound(n: usize) -> usize;
+    /// Zero extend to `u64`
+    fn to_u64(self) -> u64;
+    /// Truncate an extracted value to `Self`
+    fn from_u64(v: u64) -> Self;
+}
+
+impl PackedInt for u8 {
+    const BITS: u32 = 8;
+    fn pack(input: &[u8], output: &mut [u8], b: u32) -> usize { bit::pack_bits8(input, output, b) }
+    fn unpack(input: &[u8], n: usize, output: &mut [u8], b: u32) -> usize { bit::unpack_bits8(input, n, output, b) }
+    fn bound(n: usize) -> usize { bit::bound8(n) }
+    fn to_u64(self) -> u64 { self as u64 }
+    fn from_u64(v: u64) -> u8 { v as u8 }
+}
+
+impl PackedInt for u16 {
+    const BITS: u32 = 16;
+    fn pack(input: &[u16], output: &mut [u8], b: u32) -> usize { bit::pack_bits16(input, output, b) }
+    fn unpack(input: &[u8], n: usize, output: &mut [u16], b: u32) -> usize { bit::unpack_bits16(input, n, output, b) }
+    fn bound(n: usize) -> usize { bit::bound16(n) }
+    fn to_u64(self) -> u64 { self as u64 }
+    fn from_u64(v: u64) -> u16 { v as u16 }
+}
+
+impl PackedInt for u32 {
+    const BITS: u32 = 32;
+    fn pack(input: &[u32], output: &mut [u8], b: u32) -> usize { bit::pack_bits32(input, output, b) }
+    fn unpack(input: &[u8], n: usize, output: &mut [u32], b: u32) -> usize { bit::unpack_bits32(input, n, output, b) }
+    fn bound(n: usize) -> usize { bit::bound32(n) }
+    fn to_u64(self) -> u64 { self as u64 }
+    fn from_u64(v: u64) -> u32 { v as u32 }
+}
+
+/// Number of zero bytes after the data, so that `bitget` and `bitset` can always access 8 bytes
+const PADDING: usize = 8;
+
+/// Read `b` bits starting at bit `bidx` of the little endian bit stream `data` (port of `bitgetx32`)
+#[inline]
+fn bitget(data: &[u8], bidx: usize, b: u32) -> u64 {
+    let i = bidx / 8;
+    let word = u64::from_le_bytes(data[i..i + 8].try_into().unwrap());
+    (word >> (bidx % 8)) & ((1u64 << b) - 1)
+}
+
+/// Overwrite `b` bits starting at bit `bidx` of the little endian bit stream `data` with `v` (port of `bitsetx32`)
+#[inline]
+fn bitset(data: &mut [u8], bidx: usize, b: u32, v: u64) {
+    let i = bidx / 8;
+    let mask = ((1u64 << b) - 1) << (bidx % 8);
+    let word = u64::from_le_bytes(data[i..i + 8].try_into().unwrap());
+    let word = (word & !mask) | (v << (bidx % 8));
+    data[i..i + 8].copy_from_slice(&word.to_le_bytes());
+}
+
+/// A bit-packed array of integers with a fixed bit width and in-place reads and writes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackedArray<T: PackedInt> {
+    data: Vec<u8>,
+    b: u32,
+    len: usize,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<T: PackedInt> PackedArray<T> {
+    /// Pack `input` with the bit width of its largest integer
+    pub fn from_slice(input: &[T]) -> Self {
+        let max = input.iter().map(|&x| x.to_u64()).max().unwrap_or(0);
+        let b = 64 - max.leading_zeros();
+        let mut data = vec![0u8; T::bound(input.len())];
+        let size = T::pack(input, &mut data, b);
+        Self::from_data(data, size, input.len(), b)
+    }
+
+    /// Array of `len` zeros with bit width `b`
+    /// # Returns
+    /// The array, or `InvalidBitWidth` if `b` is larger than the bits of `T`
+    pub fn with_bits(len: usize, b: u32) -> Result<Self> {
+        check_bit_width(b, T::BITS)?;
+        Ok(Self::from_data(Vec::new(), 0, len, b))
+    }
+
+    /// Take over `len` integers of bit width `b` packed by `bit::pack_bits*` into `input`
+    /// # Returns
+    /// The array, or `InvalidBitWidth` or `InputTruncated` if `input` is shorter than `(len * b).div_ceil(8)` bytes
+    pub fn from_packed(input: &[u8], len: usize, b: u32) -> Result<Self> {
+        check_bit_width(b, T::BITS)?;
+        let size = check_consumed((len * b as usize).div_ceil(8), input.len())?;
+        Ok(Self::from_data(input[..size].to_vec(), size, len, b))
+    }
+
+    /// Keep the first `size` bytes of `data` as the packed integers and zero the rest
+    fn from_data(mut data: Vec<u8>, size: usize, len: usize, b: u32) -> Self {
+        data.truncate(size);
+        data.resize((len * b as usize).div_ceil(8) + PADDING, 0);
+        PackedArray { data, b, len, _marker: std::marker::PhantomData }
+    }
+
+    /// Number of integers
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// `true` if the array holds no integers
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Bit width of the integers
+    pub fn bits(&self) -> u32 {
+        self.b
+    }
+
+    /// The packed integers, in the layout of `bit::pack_bits*`
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data[..self.data.len() - PADDING]
+    }
+
+    /// Integer at index `idx`, or `None` if `idx` is out of bounds
+    pub fn get(&self, idx: usize) -> Option<T> {
+        if idx >= self.len {
+            return None;
+        }
+        Some(T::from_u64(bitget(&self.data, idx * self.b as usize, self.b)))
+    }
+
+    /// Overwrite the integer at index `idx` with `value`
+    /// # Returns
+    /// `IndexOutOfBounds` if `idx` is out of bounds or `ValueTooWide` if `value` needs more than `bits()` bits
+    pub fn set(&mut self, idx: usize, value: T) -> Result<()> {
+        if idx >= self.len {
+            return Err(TurboPforError::IndexOutOfBounds { idx, len: self.len });
+        }
+        let v = value.to_u64();
+        if v >> self.b != 0 {
+            return Err(TurboPforError::ValueTooWide { value: v, b: self.b });
+        }
+        bitset(&mut self.data, idx * self.b as usize, self.b, v);
+        Ok(())
+    }
+
+    /// Iterator over the integers
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = T> + '_ {
+        (0..self.len).map(|idx| T::from_u64(bitget(&self.data, idx * self.b as usize, self.b)))
+    }
+
+    /// Unpack all integers
+    pub fn to_vec(&self) -> Vec<T> {
+        let mut output = vec![T::default(); W::dec_buf_len::<T>(self.len)];
+        T::unpack(&self.data, self.len, &mut output, self.b);
+        output.truncate(self.len);
+        output
+    }
+}
+
+#[cfg(test)]
+fn test_packed_generic<T>(input: &[T])
+    where T: PackedInt + PartialEq + std::fmt::Debug
+{
+    let array = PackedArray::from_slice(input);
+    assert_eq!(array.len(), input.len());
+    for (idx, &x) in input.iter().enumerate() {
+        assert_eq!(array.get(idx), Some(x));
+    }
+    assert_eq!(array.get(input.len()), None);
+    assert_eq!(array.iter().collect::<Vec<T>>(), input);
+    assert_eq!(array.to_vec(), input);
+    // writes in place keep the neighbours intact
+    let mut copy = PackedArray::<T>::with_bits(input.len(), array.bits()).unwrap();
+    for (idx, &x) in input.iter().enumerate().rev() {
+        copy.set(idx, x).unwrap();
+    }
+    assert_eq!(copy, array);
+}
+
+#[test]
+fn test_packed() {
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for len in [0, 1, 7, 100, 1000] {
+        for b in [0, 1, 5, 13, 16, 27, 32] {
+            let input: Vec<u32> = (0..len).map(|_| if b == 0 { 0 } else { rng.gen::<u32>() >> (32 - b) }).collect();
+            test_packed_generic(&input);
+            let input: Vec<u16> = input.iter().map(|&x| x as u16).collect();
+            test_packed_generic(&input);
+            let input: Vec<u8> = input.iter().map(|&x| x as u8).collect();
+            test_packed_generic(&input);
+        }
+    }
+}
+
+#[test]
+fn test_packed_set() {
+    let mut array = PackedArray::<u32>::with_bits(100, 5).unwrap();
+    assert_eq!(array.set(10, 31), Ok(()));
+    assert_eq!(array.set(11, 32), Err(TurboPforError::ValueTooWide { value: 32, b: 5 }));
+    assert_eq!(array.set(100, 1), Err(TurboPforError::IndexOutOfBounds { idx: 100, len: 100 }));
+    assert_eq!(array.get(10), Some(31));
+    assert_eq!(array.iter().map(|x| x as usize).sum::<usize>(), 31);
+    array.set(10, 0).unwrap();
+    assert!(array.as_bytes().iter().all(|&x| x == 0));
+    assert_eq!(PackedArray::<u8>::with_bits(10, 9), Err(TurboPforError::InvalidBitWidth { b: 9, max: 8 }));
+}
+
+#[test]
+fn test_packed_compatibility() {
+    // from_packed reads the output of the C bitpack32, the C bitunpack32 reads as_bytes
+    let input: Vec<u32> = (0..1000).map(|i| (i * 7919) % 1024).collect();
+    let mut buf = vec![0u8; bit::bound32(input.len())];
+    let size = bit::pack_bits32(&input, &mut buf, 10);
+    assert_eq!(size, 1250);
+    let mut array = PackedArray::<u32>::from_packed(&buf[..size], input.len(), 10).unwrap();
+    assert_eq!(array.iter().collect::<Vec<u32>>(), input);
+    array.set(500, 1023).unwrap();
+    let mut output = vec![0u32; W::dec_buf_len::<u32>(input.len())];
+    assert_eq!(bit::unpack_bits32(array.as_bytes(), input.len(), &mut output, 10), size);
+    assert_eq!(output[500], 1023);
+    assert_eq!(output[..500], input[..500]);
+    assert_eq!(output[501..1000], input[501..]);
+    assert!(matches!(PackedArray::<u32>::from_packed(&buf[..size - 1], input.len(), 10), Err(TurboPforError::InputTruncated { .. })));
+}
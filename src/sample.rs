@@ -5,18 +5,18 @@ use rand::{
     prelude::Distribution, 
     distributions::{uniform::SampleUniform, Standard}
 };
-use num_traits::Bounded;
+use num_traits::{Bounded, FromPrimitive, Zero};
 
 /// Helper trait for the integer arithmetic we need
 pub trait Arithmetic:
     Add<Output = Self> + Sub<Output = Self> 
-    + Sized + Copy + Bounded + PartialOrd + From<u8> + SampleUniform
+    + Sized + Copy + Bounded + PartialOrd + Zero + FromPrimitive + SampleUniform
     {}
 
 /// Implement for all possible types that satisfy the constraints
 impl<T> Arithmetic for T where
     T: Add<Output = T> + Sub<Output = T> 
-    + Sized + Copy + Bounded + PartialOrd + From<u8> + SampleUniform
+    + Sized + Copy + Bounded + PartialOrd + Zero + FromPrimitive + SampleUniform
     {}
 
 /// Sample random number from `T`.
@@ -38,7 +38,7 @@ where T: Arithmetic, Standard: Distribution<T>
     // Define a reasonable delta range to ensure values increase without overflow
     for filled_len in 0..(len-1) {
         // Generate a small delta and ensure it doesn't overflow
-        let delta: T = rng.gen_range(T::from(delta_min)..=T::from(delta_max));
+        let delta: T = rng.gen_range(T::from_u8(delta_min).unwrap()..=T::from_u8(delta_max).unwrap());
         if current > T::max_value() - delta {
             result.truncate(filled_len);
             break;
@@ -48,3 +48,17 @@ where T: Arithmetic, Standard: Distribution<T>
     }
     result
 }
+
+/// Sample runs of equal random numbers, each between 1 and `max_run` long
+pub fn sample_runs<T>(len: usize, max_run: usize) -> Vec<T>
+where T: Arithmetic, Standard: Distribution<T>
+{
+    let mut rng = rand::thread_rng();
+    let mut result = Vec::with_capacity(len);
+    while result.len() < len {
+        let run = rng.gen_range(1..=max_run).min(len - result.len());
+        let value: T = rng.gen();
+        result.resize(result.len() + run, value);
+    }
+    result
+}
@@ -0,0 +1,380 @@
+/// Streaming encoder and decoder on top of `std::io::Write` and `std::io::Read`.
+///
+/// `StreamEncoder` takes integers incrementally, buffers them into chunks of `chunk_len` integers,
+/// encodes each chunk with the `Encoding` `E` and writes it as
+///
+/// | size | field                                                       |
+/// |------|-------------------------------------------------------------|
+/// | 4    | number of integers `n`, u32 LE, or'ed with `CHUNK_CHECKSUM` |
+/// | 4    | payload length in bytes, u32 LE                             |
+/// | ...  | payload, `E::encode` of the chunk                           |
+/// | 4    | with `CHUNK_CHECKSUM`: CRC32C of the previous fields        |
+///
+/// The stream ends at the end of the reader. `StreamDecoder` reads the chunks back and yields the integers.
+/// `StreamEncoder::with_checksum` turns on the checksums, `StreamDecoder` verifies them wherever the flag is set.
+///
+/// The encodings of sorted lists (`Encoding::ORDER` other than `Order::Any`) store the first value of their input
+/// in full. To keep that cost to the first chunk, the running start is carried across the chunks: every chunk after
+/// the first is encoded relative to the last value of the previous chunk, i.e. as the differences `x - start`,
+/// and the decoder adds the start back. These chunks can only be decoded in order. The encoder rejects integers
+/// that break the order across the chunk boundary, so a stream decodes to the same integers no matter where the
+/// chunk boundaries fall. The other encodings, including the zigzag and xor ones, encode every chunk on its own.
+use crate::checksum::*;
+use crate::generic::*;
+use num_traits::{WrappingAdd, WrappingSub, Zero};
+use std::io::{self, Read, Write};
+use std::vec;
+
+/// Default number of integers per chunk
+pub const DEFAULT_CHUNK_LEN: usize = 64 * 1024;
+/// Largest number of integers per chunk accepted by `StreamDecoder`
+pub const MAX_CHUNK_LEN: usize = 1 << 24;
+/// Flag in the integer count of a chunk: the payload is followed by a CRC32C of chunk header and payload
+pub const CHUNK_CHECKSUM: u32 = 1 << 31;
+/// Length of the chunk header
+const CHUNK_HEADER_LEN: usize = 8;
+
+/// Buffers integers and writes them as encoded chunks to `W`
+pub struct StreamEncoder<W: Write, E: Encoding>
+    where E::T: Copy + PartialOrd + WrappingSub + Zero
+{
+    inner: Option<W>,
+    chunk_len: usize,
+    buffer: Vec<E::T>,
+    rebased: Vec<E::T>,
+    output: Vec<u8>,
+    last: Option<E::T>,
+    start: E::T,
+    checksum: bool,
+}
+
+impl<W: Write, E: Encoding> StreamEncoder<W, E>
+    where E::T: Copy + PartialOrd + WrappingSub + Zero
+{
+    /// New encoder with chunks of `DEFAULT_CHUNK_LEN` integers
+    pub fn new(inner: W) -> Self {
+        Self::with_chunk_len(inner, DEFAULT_CHUNK_LEN)
+    }
+
+    /// New encoder with chunks of `chunk_len` integers
+    /// # Panics
+    /// If `chunk_len` is 0 or larger than `MAX_CHUNK_LEN`
+    pub fn with_chunk_len(inner: W, chunk_len: usize) -> Self {
+        assert!(chunk_len > 0 && chunk_len <= MAX_CHUNK_LEN, "chunk_len must be in 1..={}", MAX_CHUNK_LEN);
+        StreamEncoder {
+            inner: Some(inner),
+            chunk_len,
+            buffer: Vec::with_capacity(chunk_len),
+            rebased: Vec::new(),
+            output: vec![0u8; CHUNK_HEADER_LEN + E::enc_buf_size(chunk_len) + CHECKSUM_LEN],
+            last: None,
+            start: E::T::zero(),
+            checksum: false,
+        }
+    }
+
+    /// Append a CRC32C to every chunk, which `StreamDecoder` verifies
+    pub fn with_checksum(mut self) -> Self {
+        self.checksum = true;
+        self
+    }
+
+    /// Append a single integer, writing the buffered chunk first if it is full. If the write fails, `value` isn't
+    /// taken and the chunk stays buffered for the next `push`, `flush` or `finish`.
+    pub fn push(&mut self, value: E::T) -> io::Result<()> {
+        self.check_order(value)?;
+        if self.buffer.len() == self.chunk_len {
+            self.write_chunk()?;
+        }
+        self.buffer.push(value);
+        self.last = Some(value);
+        Ok(())
+    }
+
+    /// Append all integers in `values`, up to the first error
+    pub fn extend_from_slice(&mut self, values: &[E::T]) -> io::Result<()> {
+        for &value in values {
+            self.push(value)?;
+        }
+        Ok(())
+    }
+
+    /// Write the buffered integers and return the inner writer
+    pub fn finish(mut self) -> io::Result<W> {
+        self.flush()?;
+        Ok(self.inner.take().unwrap())
+    }
+
+    /// Write the buffered integers as a (short) chunk and flush the inner writer
+    pub fn flush(&mut self) -> io::Result<()> {
+        if !self.buffer.is_empty() {
+            self.write_chunk()?;
+        }
+        self.inner.as_mut().unwrap().flush()
+    }
+
+    fn check_order(&self, value: E::T) -> io::Result<()> {
+        let ok = match (self.last, E::ORDER) {
+            (None, _) | (_, Order::Any) => true,
+            (Some(last), Order::Increasing) => value >= last,
+            (Some(last), Order::StrictlyIncreasing) => value > last,
+        };
+        if !ok {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "integers are not increasing"));
+        }
+        Ok(())
+    }
+
+    /// Encode and write the buffered integers. The buffer and the running start only change once the chunk is written.
+    fn write_chunk(&mut self) -> io::Result<()> {
+        let chunk = if E::ORDER != Order::Any {
+            let start = self.start;
+            self.rebased.clear();
+            self.rebased.extend(self.buffer.iter().map(|x| x.wrapping_sub(&start)));
+            &self.rebased
+        } else {
+            &self.buffer
+        };
+        let size = E::encode(chunk, &mut self.output[CHUNK_HEADER_LEN..]);
+        let flags = if self.checksum { CHUNK_CHECKSUM } else { 0 };
+        self.output[0..4].copy_from_slice(&(self.buffer.len() as u32 | flags).to_le_bytes());
+        self.output[4..8].copy_from_slice(&(size as u32).to_le_bytes());
+        let mut len = CHUNK_HEADER_LEN + size;
+        if self.checksum {
+            len = put_checksum(&mut self.output, len);
+        }
+        self.inner.as_mut().unwrap().write_all(&self.output[..len])?;
+        if E::ORDER != Order::Any {
+            self.start = *self.buffer.last().unwrap();
+        }
+        self.buffer.clear();
+        Ok(())
+    }
+}
+
+impl<W: Write, E: Encoding> Drop for StreamEncoder<W, E>
+    where E::T: Copy + PartialOrd + WrappingSub + Zero
+{
+    /// Like `BufWriter`, write the buffered integers on drop and ignore errors; call `finish` to see them
+    fn drop(&mut self) {
+        if self.inner.is_some() && !self.buffer.is_empty() {
+            let _ = self.write_chunk();
+        }
+    }
+}
+
+/// Reads chunks written by `StreamEncoder<_, E>` from `R` and yields the integers
+pub struct StreamDecoder<R: Read, E: Encoding>
+    where E::T: Copy + Default + WrappingAdd + Zero
+{
+    inner: R,
+    input: Vec<u8>,
+    chunk: vec::IntoIter<E::T>,
+    start: E::T,
+}
+
+impl<R: Read, E: Encoding> StreamDecoder<R, E>
+    where E::T: Copy + Default + WrappingAdd + Zero
+{
+    pub fn new(inner: R) -> Self {
+        StreamDecoder { inner, input: Vec::new(), chunk: Vec::new().into_iter(), start: E::T::zero() }
+    }
+
+    /// Read and decode the next chunk
+    /// # Returns
+    /// The integers of the chunk, `None` at the end of the stream, or an `InvalidData` error for corrupted chunks
+    /// and checksum mismatches
+    pub fn next_chunk(&mut self) -> io::Result<Option<Vec<E::T>>> {
+        self.input.resize(CHUNK_HEADER_LEN, 0);
+        // like `read_exact`, retry the first byte on `Interrupted`
+        let first = loop {
+            match self.inner.read(&mut self.input[..1]) {
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                result => break result?,
+            }
+        };
+        if first == 0 {
+            return Ok(None);
+        }
+        self.inner.read_exact(&mut self.input[1..])?;
+        let count = u32::from_le_bytes(self.input[0..4].try_into().unwrap());
+        let n = (count & !CHUNK_CHECKSUM) as usize;
+        let size = u32::from_le_bytes(self.input[4..8].try_into().unwrap()) as usize;
+        if n > MAX_CHUNK_LEN || size > E::enc_buf_size(n) {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "invalid chunk length"));
+        }
+        let checksum_len = if count & CHUNK_CHECKSUM != 0 { CHECKSUM_LEN } else { 0 };
+        self.input.resize(CHUNK_HEADER_LEN + size + checksum_len, 0);
+        self.inner.read_exact(&mut self.input[CHUNK_HEADER_LEN..])?;
+        let mut chunk = &self.input[..];
+        if checksum_len > 0 {
+            chunk = check_checksum(chunk).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        }
+        let mut output = vec![E::T::default(); E::dec_buf_len(n)];
+        E::try_decode(&chunk[CHUNK_HEADER_LEN..], n, &mut output)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        output.truncate(n);
+        if E::ORDER != Order::Any {
+            let start = self.start;
+            output.iter_mut().for_each(|x| *x = x.wrapping_add(&start));
+            self.start = output.last().copied().unwrap_or(start);
+        }
+        Ok(Some(output))
+    }
+
+    /// Return the inner reader
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read, E: Encoding> Iterator for StreamDecoder<R, E>
+    where E::T: Copy + Default + WrappingAdd + Zero
+{
+    type Item = io::Result<E::T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(value) = self.chunk.next() {
+                return Some(Ok(value));
+            }
+            match self.next_chunk() {
+                Ok(Some(chunk)) => self.chunk = chunk.into_iter(),
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+fn test_stream_generic<E>(len: usize, chunk_len: usize)
+where
+    E: Encoding,
+    E::T: Copy + Default + PartialOrd + WrappingAdd + WrappingSub + Zero + std::fmt::Debug,
+{
+    let input: Vec<E::T> = E::sample(len);
+    let mut encoder = StreamEncoder::<_, E>::with_chunk_len(Vec::new(), chunk_len);
+    encoder.extend_from_slice(&input[..len / 3]).unwrap();
+    for &x in &input[len / 3..] {
+        encoder.push(x).unwrap();
+    }
+    let bytes = encoder.finish().unwrap();
+    let output: Vec<E::T> = StreamDecoder::<_, E>::new(&bytes[..]).collect::<io::Result<_>>().unwrap();
+    assert_eq!(input, output);
+}
+
+#[test]
+fn test_stream() {
+    use crate::codec::*;
+    test_stream_generic::<StandardEncoding<W, u32>>(10_000, 1000);
+    test_stream_generic::<IncreasingEncoding<W, u64>>(10_000, 333);
+    test_stream_generic::<StrictlyIncreasingEncoding<W128v, u32>>(10_000, 128);
+    test_stream_generic::<ZigZagEncoding<W256v, u32>>(10_000, DEFAULT_CHUNK_LEN);
+    test_stream_generic::<IncreasingEncoding<W, u16>>(1, 1);
+    test_stream_generic::<IncreasingEncoding<W, i32>>(10_000, 1000);
+    test_stream_generic::<VByteDelta1Encoding<u32>>(10_000, 777);
+    test_stream_generic::<EliasFanoEncoding<W, u64>>(10_000, 1000);
+    test_stream_generic::<BitPackStrictDeltaEncoding<W128v, u16>>(10_000, 100);
+    test_stream_generic::<SimpleVEncoding<u8>>(10_000, 1000);
+}
+
+#[test]
+fn test_stream_running_start() {
+    use crate::codec::*;
+    type E = IncreasingEncoding<W, u32>;
+    let input: Vec<u32> = (0..256).map(|i| 3_000_000_000 + i).collect();
+    let mut encoder = StreamEncoder::<_, E>::with_chunk_len(Vec::new(), 128);
+    encoder.extend_from_slice(&input).unwrap();
+    let bytes = encoder.finish().unwrap();
+    // the second chunk starts at 1 instead of 3_000_000_128
+    let size1 = u32::from_le_bytes(bytes[4..8].try_into().unwrap()) as usize;
+    let size2 = u32::from_le_bytes(bytes[CHUNK_HEADER_LEN + size1 + 4..][..4].try_into().unwrap()) as usize;
+    assert!(size2 < size1);
+    let output: Vec<u32> = StreamDecoder::<_, E>::new(&bytes[..]).collect::<io::Result<_>>().unwrap();
+    assert_eq!(input, output);
+}
+
+#[test]
+fn test_stream_errors() {
+    use crate::codec::*;
+    type E = StrictlyIncreasingEncoding<W, u32>;
+    let mut encoder = StreamEncoder::<_, E>::with_chunk_len(Vec::new(), 2);
+    encoder.extend_from_slice(&[1, 2]).unwrap();
+    // 2 is the last value of the previous chunk
+    assert_eq!(encoder.push(2).unwrap_err().kind(), io::ErrorKind::InvalidInput);
+    encoder.extend_from_slice(&[3, 4, 5]).unwrap();
+    let bytes = encoder.finish().unwrap();
+    let mut decoder = StreamDecoder::<_, E>::new(&bytes[..bytes.len() - 1]);
+    assert_eq!(decoder.next_chunk().unwrap(), Some(vec![1, 2]));
+    assert_eq!(decoder.next_chunk().unwrap(), Some(vec![3, 4]));
+    assert_eq!(decoder.next_chunk().unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn test_stream_checksum() {
+    use crate::codec::*;
+    type E = IncreasingEncoding<W, u32>;
+    let input: Vec<u32> = E::sample(1000);
+    let mut encoder = StreamEncoder::<_, E>::with_chunk_len(Vec::new(), 300).with_checksum();
+    encoder.extend_from_slice(&input).unwrap();
+    let mut bytes = encoder.finish().unwrap();
+    assert_eq!(bytes[3] & 0x80, 0x80);
+    let output: Vec<u32> = StreamDecoder::<_, E>::new(&bytes[..]).collect::<io::Result<_>>().unwrap();
+    assert_eq!(input, output);
+    bytes[CHUNK_HEADER_LEN + 5] ^= 0x01;
+    let mut decoder = StreamDecoder::<_, E>::new(&bytes[..]);
+    assert_eq!(decoder.next_chunk().unwrap_err().kind(), io::ErrorKind::InvalidData);
+}
+
+/// Writer failing every other write, and reader interrupted before every read
+#[cfg(test)]
+struct Flaky<T> {
+    inner: T,
+    fail: bool,
+}
+
+#[cfg(test)]
+impl<T: Write> Write for Flaky<T> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.fail = !self.fail;
+        if self.fail {
+            return Err(io::Error::other("flaky"));
+        }
+        self.inner.write(buf)
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+impl<T: Read> Read for Flaky<T> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.fail = !self.fail;
+        if self.fail {
+            return Err(io::Error::new(io::ErrorKind::Interrupted, "flaky"));
+        }
+        self.inner.read(buf)
+    }
+}
+
+#[test]
+fn test_stream_retry() {
+    use crate::codec::*;
+    type E = IncreasingEncoding<W, u32>;
+    let input: Vec<u32> = E::sample(1000);
+    let mut encoder = StreamEncoder::<_, E>::with_chunk_len(Flaky { inner: Vec::new(), fail: false }, 100);
+    let mut pushed = 0;
+    while pushed < input.len() {
+        // a failed write keeps the chunk and doesn't take the integer
+        match encoder.push(input[pushed]) {
+            Ok(()) => pushed += 1,
+            Err(e) => assert_eq!(e.kind(), io::ErrorKind::Other),
+        }
+    }
+    while encoder.flush().is_err() {}
+    let bytes = encoder.finish().unwrap().inner;
+    let decoder = StreamDecoder::<_, E>::new(Flaky { inner: &bytes[..], fail: false });
+    let output: Vec<u32> = decoder.collect::<io::Result<_>>().unwrap();
+    assert_eq!(input, output);
+}
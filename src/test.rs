@@ -1,4 +1,6 @@
 use super::codec::*;
+use super::error::TurboPforError;
+use super::{bit, ef, fp, p4, raw, vb};
 use std::{
     fmt::Debug,
     collections::HashSet, hash::Hash,
@@ -81,6 +83,30 @@ impl Num for u64 {
     fn rand(rng: &mut ThreadRng) -> u64 { rng.gen() }
 }
 
+impl Num for i8 {
+    fn zero() -> i8 { 0 }
+    fn upper_bound() -> usize { 127 }
+    fn rand(rng: &mut ThreadRng) -> i8 { rng.gen() }
+}
+
+impl Num for i16 {
+    fn zero() -> i16 { 0 }
+    fn upper_bound() -> usize { 32767 }
+    fn rand(rng: &mut ThreadRng) -> i16 { rng.gen() }
+}
+
+impl Num for i32 {
+    fn zero() -> i32 { 0 }
+    fn upper_bound() -> usize { 2147483647 }
+    fn rand(rng: &mut ThreadRng) -> i32 { rng.gen() }
+}
+
+impl Num for i64 {
+    fn zero() -> i64 { 0 }
+    fn upper_bound() -> usize { 9223372036854775807 }
+    fn rand(rng: &mut ThreadRng) -> i64 { rng.gen() }
+}
+
 /// Make increasing data of maximum length `max_len`. If `strictly` is true, make strictly increasing data.
 fn mk_data_inc<T: Num+Copy+std::ops::AddAssign+Hash+Eq+Ord>(
     max_len: usize,
@@ -233,74 +259,358 @@ fn test_enc8() {
 #[test]
 fn test_enc16() {
     test_generic::<W,     u16>(MAX_TEST_LEN, Codec::<W>::enc, Codec::<W>::dec, DataType::Unsorted);
-    test_generic::<W128v, u16>(MAX_TEST_LEN, Codec::<W>::enc, Codec::<W>::dec, DataType::Unsorted);
+    test_generic::<W128v, u16>(MAX_TEST_LEN, Codec::<W128v>::enc, Codec::<W128v>::dec, DataType::Unsorted);
 }
 
 #[test]
 fn test_enc32() {
     test_generic::<W,     u32>(MAX_TEST_LEN, Codec::<W>::enc, Codec::<W>::dec, DataType::Unsorted);
-    test_generic::<W128v, u32>(MAX_TEST_LEN, Codec::<W>::enc, Codec::<W>::dec, DataType::Unsorted);
+    test_generic::<W128v, u32>(MAX_TEST_LEN, Codec::<W128v>::enc, Codec::<W128v>::dec, DataType::Unsorted);
+    test_generic::<W256v, u32>(MAX_TEST_LEN, Codec::<W256v>::enc, Codec::<W256v>::dec, DataType::Unsorted);
 }
 
 #[test]
 fn test_enc64() {
-    test_generic::<W, u64>(MAX_TEST_LEN, Codec::<W>::enc, Codec::<W>::dec, DataType::Unsorted);
+    test_generic::<W,     u64>(MAX_TEST_LEN, Codec::<W>::enc,     Codec::<W>::dec,     DataType::Unsorted);
+    test_generic::<W128v, u64>(MAX_TEST_LEN, Codec::<W128v>::enc, Codec::<W128v>::dec, DataType::Unsorted);
 }
 
 //////////////////////////////////////// denc ////////////////////////////////////////
 
+#[test]
+fn test_denc8() {
+    test_generic::<W, u8>(MAX_TEST_LEN, DeltaCodec::<W>::denc, DeltaCodec::<W>::ddec, DataType::Increasing);
+}
+
 #[test]
 fn test_denc16() {
-    test_generic::<W,     u16>(MAX_TEST_LEN, Codec::<W>::denc,     Codec::<W>::ddec    , DataType::Increasing);
-    test_generic::<W128v, u16>(MAX_TEST_LEN, Codec::<W128v>::denc, Codec::<W128v>::ddec, DataType::Increasing);
+    test_generic::<W,     u16>(MAX_TEST_LEN, DeltaCodec::<W>::denc,     DeltaCodec::<W>::ddec    , DataType::Increasing);
+    test_generic::<W128v, u16>(MAX_TEST_LEN, DeltaCodec::<W128v>::denc, DeltaCodec::<W128v>::ddec, DataType::Increasing);
 }
 
 #[test]
 fn test_denc32() {
-    test_generic::<W,     u32>(MAX_TEST_LEN, Codec::<W>::denc,     Codec::<W>::ddec    , DataType::Increasing);
-    test_generic::<W128v, u32>(MAX_TEST_LEN, Codec::<W128v>::denc, Codec::<W128v>::ddec, DataType::Increasing);
-    test_generic::<W256v, u32>(MAX_TEST_LEN, Codec::<W256v>::denc, Codec::<W256v>::ddec, DataType::Increasing);
+    test_generic::<W,     u32>(MAX_TEST_LEN, DeltaCodec::<W>::denc,     DeltaCodec::<W>::ddec    , DataType::Increasing);
+    test_generic::<W128v, u32>(MAX_TEST_LEN, DeltaCodec::<W128v>::denc, DeltaCodec::<W128v>::ddec, DataType::Increasing);
+    test_generic::<W256v, u32>(MAX_TEST_LEN, DeltaCodec::<W256v>::denc, DeltaCodec::<W256v>::ddec, DataType::Increasing);
 }
 
 #[test]
 fn test_denc64() {
-    test_generic::<W, u64>(MAX_TEST_LEN, Codec::<W>::denc, Codec::<W>::ddec, DataType::Increasing);
+    test_generic::<W, u64>(MAX_TEST_LEN, DeltaCodec::<W>::denc, DeltaCodec::<W>::ddec, DataType::Increasing);
 }
 
 //////////////////////////////////////// d1enc ////////////////////////////////////////
 
+#[test]
+fn test_d1enc8() {
+    test_generic::<W, u8>(MAX_TEST_LEN, DeltaCodec::<W>::d1enc, DeltaCodec::<W>::d1dec, DataType::StrictlyIncreasing);
+}
+
 #[test]
 fn test_d1enc16() {
-    test_generic::<W,     u16>(MAX_TEST_LEN, Codec::<W>::d1enc,     Codec::<W>::d1dec,     DataType::StrictlyIncreasing);
-    test_generic::<W128v, u16>(MAX_TEST_LEN, Codec::<W128v>::d1enc, Codec::<W128v>::d1dec, DataType::StrictlyIncreasing);
+    test_generic::<W,     u16>(MAX_TEST_LEN, DeltaCodec::<W>::d1enc,     DeltaCodec::<W>::d1dec,     DataType::StrictlyIncreasing);
+    test_generic::<W128v, u16>(MAX_TEST_LEN, DeltaCodec::<W128v>::d1enc, DeltaCodec::<W128v>::d1dec, DataType::StrictlyIncreasing);
 }
 
 #[test]
 fn test_d1enc32() {
-    test_generic::<W, u32>(MAX_TEST_LEN, Codec::<W>::d1enc, Codec::<W>::d1dec, DataType::StrictlyIncreasing);
+    test_generic::<W,     u32>(MAX_TEST_LEN, DeltaCodec::<W>::d1enc,     DeltaCodec::<W>::d1dec,     DataType::StrictlyIncreasing);
+    test_generic::<W128v, u32>(MAX_TEST_LEN, DeltaCodec::<W128v>::d1enc, DeltaCodec::<W128v>::d1dec, DataType::StrictlyIncreasing);
+    test_generic::<W256v, u32>(MAX_TEST_LEN, DeltaCodec::<W256v>::d1enc, DeltaCodec::<W256v>::d1dec, DataType::StrictlyIncreasing);
 }
 
 #[test]
 fn test_d1enc64() {
-    test_generic::<W, u64>(MAX_TEST_LEN, Codec::<W>::d1enc, Codec::<W>::d1dec, DataType::StrictlyIncreasing);
+    test_generic::<W, u64>(MAX_TEST_LEN, DeltaCodec::<W>::d1enc, DeltaCodec::<W>::d1dec, DataType::StrictlyIncreasing);
 }
 
 //////////////////////////////////////// zenc ////////////////////////////////////////
 
+#[test]
+fn test_zenc8() {
+    test_generic::<W, u8>(MAX_TEST_LEN, DeltaCodec::<W>::zenc, DeltaCodec::<W>::zdec, DataType::Unsorted);
+}
+
 #[test]
 fn test_zenc16() {
-    test_generic::<W,     u16>(MAX_TEST_LEN, Codec::<W>::zenc,     Codec::<W>::zdec    , DataType::Unsorted);
-    test_generic::<W128v, u16>(MAX_TEST_LEN, Codec::<W128v>::zenc, Codec::<W128v>::zdec, DataType::Unsorted);
+    test_generic::<W,     u16>(MAX_TEST_LEN, DeltaCodec::<W>::zenc,     DeltaCodec::<W>::zdec    , DataType::Unsorted);
+    test_generic::<W128v, u16>(MAX_TEST_LEN, DeltaCodec::<W128v>::zenc, DeltaCodec::<W128v>::zdec, DataType::Unsorted);
 }
 
 #[test]
 fn test_zenc32() {
-    test_generic::<W,     u32>(MAX_TEST_LEN, Codec::<W>::zenc,     Codec::<W>::zdec    , DataType::Unsorted);
-    test_generic::<W128v, u32>(MAX_TEST_LEN, Codec::<W128v>::zenc, Codec::<W128v>::zdec, DataType::Unsorted);
-    test_generic::<W256v, u32>(MAX_TEST_LEN, Codec::<W256v>::zenc, Codec::<W256v>::zdec, DataType::Unsorted);
+    test_generic::<W,     u32>(MAX_TEST_LEN, DeltaCodec::<W>::zenc,     DeltaCodec::<W>::zdec    , DataType::Unsorted);
+    test_generic::<W128v, u32>(MAX_TEST_LEN, DeltaCodec::<W128v>::zenc, DeltaCodec::<W128v>::zdec, DataType::Unsorted);
+    test_generic::<W256v, u32>(MAX_TEST_LEN, DeltaCodec::<W256v>::zenc, DeltaCodec::<W256v>::zdec, DataType::Unsorted);
 }
 
 #[test]
 fn test_zenc64() {
-    test_generic::<W, u64>(MAX_TEST_LEN, Codec::<W>::zenc, Codec::<W>::zdec, DataType::Unsorted);
+    test_generic::<W, u64>(MAX_TEST_LEN, DeltaCodec::<W>::zenc, DeltaCodec::<W>::zdec, DataType::Unsorted);
+}
+
+//////////////////////////////////////// signed ////////////////////////////////////////
+
+#[test]
+fn test_enc_signed() {
+    test_generic::<W,     i8 >(MAX_TEST_LEN, Codec::<W>::enc,     Codec::<W>::dec,     DataType::Unsorted);
+    test_generic::<W,     i16>(MAX_TEST_LEN, Codec::<W>::enc,     Codec::<W>::dec,     DataType::Unsorted);
+    test_generic::<W128v, i16>(MAX_TEST_LEN, Codec::<W128v>::enc, Codec::<W128v>::dec, DataType::Unsorted);
+    test_generic::<W,     i32>(MAX_TEST_LEN, Codec::<W>::enc,     Codec::<W>::dec,     DataType::Unsorted);
+    test_generic::<W256v, i32>(MAX_TEST_LEN, Codec::<W256v>::enc, Codec::<W256v>::dec, DataType::Unsorted);
+    test_generic::<W,     i64>(MAX_TEST_LEN, Codec::<W>::enc,     Codec::<W>::dec,     DataType::Unsorted);
+    test_generic::<W128v, i64>(MAX_TEST_LEN, Codec::<W128v>::enc, Codec::<W128v>::dec, DataType::Unsorted);
+}
+
+#[test]
+fn test_denc_signed() {
+    test_generic::<W,     i16>(MAX_TEST_LEN, DeltaCodec::<W>::denc,     DeltaCodec::<W>::ddec,     DataType::Increasing);
+    test_generic::<W128v, i32>(MAX_TEST_LEN, DeltaCodec::<W128v>::denc, DeltaCodec::<W128v>::ddec, DataType::Increasing);
+    test_generic::<W,     i64>(MAX_TEST_LEN, DeltaCodec::<W>::denc,     DeltaCodec::<W>::ddec,     DataType::Increasing);
+}
+
+#[test]
+fn test_d1enc_signed() {
+    test_generic::<W,     i8 >(MAX_TEST_LEN, DeltaCodec::<W>::d1enc,     DeltaCodec::<W>::d1dec,     DataType::StrictlyIncreasing);
+    test_generic::<W256v, i32>(MAX_TEST_LEN, DeltaCodec::<W256v>::d1enc, DeltaCodec::<W256v>::d1dec, DataType::StrictlyIncreasing);
+    test_generic::<W,     i64>(MAX_TEST_LEN, DeltaCodec::<W>::d1enc,     DeltaCodec::<W>::d1dec,     DataType::StrictlyIncreasing);
+}
+
+#[test]
+fn test_zenc_signed() {
+    test_generic::<W,     i16>(MAX_TEST_LEN, DeltaCodec::<W>::zenc,     DeltaCodec::<W>::zdec,     DataType::Unsorted);
+    test_generic::<W128v, i32>(MAX_TEST_LEN, DeltaCodec::<W128v>::zenc, DeltaCodec::<W128v>::zdec, DataType::Unsorted);
+    test_generic::<W,     i64>(MAX_TEST_LEN, DeltaCodec::<W>::zenc,     DeltaCodec::<W>::zdec,     DataType::Unsorted);
+}
+
+#[test]
+fn test_zigzag_signed() {
+    assert_eq!([0i32, -1, 1, -2, i32::MAX, i32::MIN].map(SignedInteger::zigzag), [0u32, 1, 2, 3, u32::MAX - 1, u32::MAX]);
+    for x in [0i8, -1, 1, i8::MIN, i8::MAX] {
+        assert_eq!(i8::unzigzag(x.zigzag()), x);
+    }
+}
+
+//////////////////////////////////////// checked ////////////////////////////////////////
+
+#[test]
+fn test_try_enc_dec() {
+    let input: Vec<u32> = (0..1000).map(|i| i * 7 % 113).collect();
+    // output too small for encoding
+    let mut small = vec![0u8; 10];
+    let needed = <u32 as Codec<W>>::max_compressed_size(input.len());
+    assert_eq!(Codec::<W>::try_enc(&input, &mut small), Err(TurboPforError::OutputTooSmall { needed, got: 10 }));
+    assert_eq!(p4::try_enc32(&input, &mut small), Err(TurboPforError::OutputTooSmall { needed, got: 10 }));
+    // encode
+    let mut buf = vec![0u8; needed];
+    let size = Codec::<W>::try_enc(&input, &mut buf).unwrap();
+    // output too small for decoding: it lacks the 32 extra integers
+    let mut output = vec![0u32; input.len()];
+    let needed = W::dec_buf_len::<u32>(input.len());
+    assert_eq!(Codec::<W>::try_dec(&buf[..size], input.len(), &mut output), Err(TurboPforError::OutputTooSmall { needed, got: input.len() }));
+    // empty input
+    let mut output = vec![0u32; needed];
+    assert_eq!(Codec::<W>::try_dec(&[], input.len(), &mut output), Err(TurboPforError::InputTruncated { needed: 1, got: 0 }));
+    // decode
+    assert_eq!(Codec::<W>::try_dec(&buf[..size], input.len(), &mut output), Ok(size));
+    assert!(compare(&input, &output[..input.len()]));
+}
+
+#[test]
+fn test_try_dec_truncated() {
+    // every prefix is copied into a buffer of its own length, so a decoder reading past it would touch unallocated memory
+    let input: Vec<u32> = (0..1000).map(|i| i * 7 % 113 + (i % 50 == 0) as u32 * 100_000).collect();
+    let mut output = vec![0u32; W::dec_buf_len::<u32>(input.len())];
+    let mut buf = vec![0u8; 8 * input.len() + 1024];
+    let size = p4::enc32(&input, &mut buf);
+    for len in 0..size {
+        let truncated = buf[..len].to_vec();
+        assert!(p4::try_dec32(&truncated, input.len(), &mut output).is_err());
+        assert!(Codec::<W>::try_dec(&truncated, input.len(), &mut output).is_err());
+    }
+    // the header walk rejects the truncated stream before the decoder runs
+    assert!(matches!(p4::try_dec32(&buf[..size / 2], input.len(), &mut output), Err(TurboPforError::BlockTruncated { .. })));
+    let mut corrupt = buf[..size].to_vec();
+    corrupt[0] = 0x3f;
+    assert_eq!(p4::try_dec32(&corrupt, input.len(), &mut output), Err(TurboPforError::InvalidBlockHeader { offset: 0, header: 0x3f }));
+
+    let size = p4::d1enc32(&input.iter().scan(0, |s, &x| { *s += x + 1; Some(*s) }).collect::<Vec<_>>(), &mut buf);
+    for len in 0..size {
+        let truncated = buf[..len].to_vec();
+        assert!(p4::try_d1dec32(&truncated, input.len(), &mut output).is_err());
+    }
+    // the decoders without header walk decode from a zero padded copy and report the overrun
+    let size = vb::enc32(&input, &mut buf);
+    for len in 0..size {
+        let truncated = buf[..len].to_vec();
+        assert!(matches!(vb::try_dec32(&truncated, input.len(), &mut output), Err(TurboPforError::InputTruncated { .. })));
+    }
+    let size = bit::pack32(&input, &mut buf);
+    for len in 0..size {
+        let truncated = buf[..len].to_vec();
+        assert!(bit::try_unpack32(&truncated, input.len(), &mut output).is_err());
+    }
+}
+
+#[test]
+#[should_panic(expected = "output buffer too small")]
+fn test_enc_short_output_panics() {
+    let input = vec![1u32; 100];
+    let mut buf = vec![0u8; 10];
+    Codec::<W>::enc(&input, &mut buf);
+}
+
+#[test]
+#[should_panic(expected = "output buffer too small")]
+fn test_dec_short_output_panics() {
+    let input = vec![1u32; 100];
+    let mut buf = vec![0u8; W::enc_buf_size::<u32>(input.len())];
+    let size = Codec::<W>::enc(&input, &mut buf);
+    let mut output = vec![0u32; input.len()];
+    Codec::<W>::dec(&buf[..size], input.len(), &mut output);
+}
+
+#[test]
+fn test_raw_enc_dec() {
+    let input: Vec<u16> = (0..1000).map(|i| i * 13 % 1021).collect();
+    let mut buf = vec![0u8; W::enc_buf_size::<u16>(input.len())];
+    let mut output = vec![0u16; W::dec_buf_len::<u16>(input.len())];
+    // SAFETY: the buffers have the sizes required by enc_buf_size and dec_buf_len
+    let size_enc = unsafe { raw::p4::enc16(&input, &mut buf) };
+    let size_dec = unsafe { raw::p4::dec16(&buf[..size_enc], input.len(), &mut output) };
+    assert_eq!(size_enc, size_dec);
+    assert!(compare(&input, &output[..input.len()]));
+}
+
+#[test]
+fn test_max_compressed_size() {
+    assert_eq!(<u8 as Codec<W>>::max_compressed_size(1000), p4::bound8(1000));
+    assert_eq!(<u64 as Codec<W>>::max_compressed_size(1000), p4::bound64(1000));
+    assert_eq!(<u16 as Codec<W128v>>::max_compressed_size(1000), p4::bound128v16(1000));
+    assert_eq!(<u32 as Codec<W256v>>::max_compressed_size(1000), p4::bound256v32(1000));
+    // the bound holds for incompressible input
+    let input: Vec<u32> = (0..1000u32).map(|i| i.wrapping_mul(2654435761)).collect();
+    let mut buf = vec![0u8; <u32 as Codec<W256v>>::max_compressed_size(input.len())];
+    assert!(Codec::<W256v>::try_enc(&input, &mut buf).unwrap() <= buf.len());
+    assert!(bit::bound32(1000) >= 4 * 1000);
+}
+
+#[test]
+fn test_dec_block() {
+    let input: Vec<u32> = (0..300u32).map(|i| i * 3).collect();
+    let mut buf = vec![0u8; 8 * input.len() + 1024];
+    let size = p4::enc32(&input, &mut buf);
+    // the stream consists of blocks of 128, 128 and 44 integers
+    let mut output = vec![0u32; W::dec_buf_len::<u32>(128)];
+    let mut pos = 0;
+    for (i, n) in [128, 128, 44].into_iter().enumerate() {
+        pos += p4::dec_block32(&buf[pos..size], n, &mut output);
+        assert_eq!(output[..n], input[128 * i..128 * i + n]);
+    }
+    assert_eq!(pos, size);
+    assert_eq!(p4::try_dec_block32(&buf[..size], 257, &mut output), Err(TurboPforError::BlockTooLong { n: 257, max: 256 }));
+}
+
+/// Largest size `BitIoCodec` encodes `input` to with either codec
+fn bitio_enc_len<T: BitIoCodec + Copy>(input: &[T], start: T) -> usize {
+    let mut buf = vec![0u8; T::max_compressed_size(input.len())];
+    T::zenc(input, &mut buf, start).max(T::zzenc(input, &mut buf, start))
+}
+
+/// Random input and alternating extremes, the worst case of both zigzag codecs
+fn test_bitio_bound_generic<T: BitIoCodec + Num + Copy + num_traits::Bounded>() {
+    let mut rng = rand::thread_rng();
+    for len in [1, 2, 31, 32, 33, 127, 128, 129, 1000, 10_000] {
+        let input: Vec<T> = (0..len).map(|_| T::rand(&mut rng)).collect();
+        assert!(bitio_enc_len(&input, T::zero()) <= T::max_compressed_size(len));
+        let input: Vec<T> = (0..len).map(|i| if i % 2 == 0 { T::max_value() } else { T::zero() }).collect();
+        assert!(bitio_enc_len(&input, T::zero()) <= T::max_compressed_size(len));
+    }
+}
+
+#[test]
+fn test_bitio_bound() {
+    test_bitio_bound_generic::<u8>();
+    test_bitio_bound_generic::<u16>();
+    test_bitio_bound_generic::<u32>();
+    test_bitio_bound_generic::<u64>();
+}
+
+/// Encoder taking the predecessor or lower bound of the first integer
+type StartEnc<T> = unsafe fn(&[T], &mut [u8], T) -> usize;
+
+/// Increasing inputs of `len` integers up to `max` and whether they are strictly increasing from 0: all at `max`, an
+/// even spread, a run ending at `max` and random values
+fn ef_inputs(len: usize, max: u64, rng: &mut ThreadRng) -> Vec<(Vec<u64>, bool)> {
+    let step = max / len as u64;
+    let mut random: Vec<u64> = (0..len).map(|_| rng.gen_range(0..=max)).collect();
+    random.sort_unstable();
+    vec![
+        (vec![max; len], len == 1),
+        ((1..=len as u64).map(|i| i * step).collect(), true),
+        ((0..len as u64).map(|i| max - (len as u64 - 1 - i)).collect(), true),
+        (random, false),
+    ]
+}
+
+/// Largest size the `encoders` write for `input`, into a buffer far beyond the bound
+fn start_enc_len<T: Copy + Default>(input: &[T], encoders: &[StartEnc<T>]) -> usize {
+    let mut buf = vec![0u8; 16 * input.len() + 4096];
+    encoders.iter().map(|enc| unsafe { enc(input, &mut buf, T::default()) }).max().unwrap()
+}
+
+/// One integer at the maximum and lengths around the block sizes of the SIMD encoders, the worst cases of `ef::bound*`
+#[test]
+fn test_ef_bound() {
+    let mut rng = rand::thread_rng();
+    for len in [1, 2, 127, 128, 129, 255, 256, 257, 1000] {
+        for (input, strict) in ef_inputs(len, u32::MAX as u64, &mut rng) {
+            let input: Vec<u32> = input.into_iter().map(|x| x as u32).collect();
+            let mut encoders: Vec<StartEnc<u32>> = vec![raw::ef::enc32, raw::ef::enc128v32, raw::ef::enc256v32];
+            if strict {
+                encoders.extend([raw::ef::d1enc32 as StartEnc<u32>, raw::ef::d1enc128v32, raw::ef::d1enc256v32]);
+            }
+            assert!(start_enc_len(&input, &encoders) <= ef::bound32(len), "{} u32s", len);
+        }
+        for (input, strict) in ef_inputs(len, u64::MAX, &mut rng) {
+            let mut encoders: Vec<StartEnc<u64>> = vec![raw::ef::enc64];
+            if strict {
+                encoders.push(raw::ef::d1enc64);
+            }
+            assert!(start_enc_len(&input, &encoders) <= ef::bound64(len), "{} u64s", len);
+        }
+    }
+}
+
+/// Random bit patterns, alternating extremes, alternating complementary bits and a counter, the worst cases of the
+/// predictors of `fp`
+fn fp_inputs<T>(len: usize, stripes: T, rng: &mut ThreadRng) -> Vec<Vec<T>>
+    where T: Num + Copy + num_traits::Bounded + num_traits::FromPrimitive + std::ops::Not<Output = T>
+{
+    vec![
+        (0..len).map(|_| T::rand(rng)).collect(),
+        (0..len).map(|i| if i % 2 == 0 { T::max_value() } else { T::zero() }).collect(),
+        (0..len).map(|i| if i % 2 == 0 { stripes } else { !stripes }).collect(),
+        (0..len).map(|i| T::from_usize(i).unwrap()).collect(),
+    ]
+}
+
+/// The fp.h bounds are derived from the bit/io codecs, every other encoder writing into a buffer of that size
+/// (TurboGorilla, Chimp, TurboFloatLz, FCM, DFCM, XOR) must stay below them
+#[test]
+fn test_fp_bound() {
+    let mut rng = rand::thread_rng();
+    let encoders32: [StartEnc<u32>; 7] = [raw::fp::genc32, raw::fp::henc32, raw::fp::cenc32, raw::fp::c0enc32,
+        raw::fp::fcmenc32, raw::fp::dfcmenc32, raw::fp::xenc32];
+    let encoders64: [StartEnc<u64>; 7] = [raw::fp::genc64, raw::fp::henc64, raw::fp::cenc64, raw::fp::c0enc64,
+        raw::fp::fcmenc64, raw::fp::dfcmenc64, raw::fp::xenc64];
+    for len in [1, 2, 31, 32, 33, 127, 128, 129, 1000, 10_000] {
+        for input in fp_inputs(len, 0x5555_5555u32, &mut rng) {
+            assert!(start_enc_len(&input, &encoders32) <= fp::bound32(len), "{} u32s", len);
+        }
+        for input in fp_inputs(len, 0x5555_5555_5555_5555u64, &mut rng) {
+            assert!(start_enc_len(&input, &encoders64) <= fp::bound64(len), "{} u64s", len);
+        }
+    }
 }
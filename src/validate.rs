@@ -0,0 +1,402 @@
+/// Validation of untrusted TurboPFor streams produced by the `p4n*enc*` functions.
+///
+/// The C decoders trust their input completely: a truncated or corrupted buffer makes them read
+/// beyond the end of `input`. `validate` walks the block headers of a stream before anything is
+/// decoded and checks that every block fits into the given slice. `dec_checked` validates and then decodes.
+///
+/// A `p4n*enc*` stream consists of
+/// - for the delta and zigzag encodings: the first value, variable byte encoded,
+/// - blocks of `Width::BLOCK_LEN` integers (the last one may be shorter), each starting with a header byte
+///   `f|b` where `b` is the bit width and the flag `f` selects the layout:
+///   - `0x00`: `n` integers bitpacked with `b` bits
+///   - `0x80`: followed by the exception bit width `bx`, an exception bitmap of `n` bits,
+///     the exceptions bitpacked with `bx` bits and the `n` integers bitpacked with `b` bits
+///   - `0x40`: followed by the number of exceptions `xn`, the `n` integers bitpacked with `b` bits, the exceptions
+///     variable byte encoded (`vbenc*`) and their `xn` positions
+///   - `0xc0`: all integers equal, followed by the integer in `b` bits
+///
+/// The size of every block follows from its header, the exception bitmap and the first bytes of the variable bytes.
+/// Only the first value is measured by the decoder, from a zero padded copy of the first `START_WINDOW` bytes.
+use crate::codec::*;
+use crate::error::*;
+use crate::generic::*;
+use crate::sample::*;
+use std::borrow::Cow;
+use std::cmp::min;
+use std::mem::size_of;
+use rand::{
+    prelude::Distribution,
+    distributions::Standard,
+};
+
+/// Encodings producing `p4n*enc*` streams
+pub trait Validate: Encoding {
+    /// `true` if the stream starts with the variable byte encoded first value
+    const HAS_START: bool;
+
+    /// Decode the first value into `output[0]`
+    /// # Returns
+    /// Number of input bytes used, 0 if the stream has no first value in front of the blocks
+    fn decode_start(input: &[u8], output: &mut [Self::T]) -> usize;
+}
+
+impl<WT: Width, T: Codec<WT>> Validate for StandardEncoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    const HAS_START: bool = false;
+    fn decode_start(_input: &[u8], _output: &mut [T]) -> usize {
+        0
+    }
+}
+
+impl<WT: Width, T: DeltaCodec<WT>> Validate for IncreasingEncoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    const HAS_START: bool = true;
+    fn decode_start(input: &[u8], output: &mut [T]) -> usize {
+        T::ddec(input, 1, output)
+    }
+}
+
+impl<WT: Width, T: DeltaCodec<WT>> Validate for StrictlyIncreasingEncoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    const HAS_START: bool = true;
+    fn decode_start(input: &[u8], output: &mut [T]) -> usize {
+        T::ddec(input, 1, output)
+    }
+}
+
+impl<WT: Width, T: DeltaCodec<WT>> Validate for ZigZagEncoding<WT, T>
+    where Standard: Distribution<T>, T: Arithmetic
+{
+    const HAS_START: bool = true;
+    fn decode_start(input: &[u8], output: &mut [T]) -> usize {
+        T::ddec(input, 1, output)
+    }
+}
+
+/// Size of the window holding the variable byte encoded first value
+const START_WINDOW: usize = 16;
+
+/// Bytes the C decoders may read behind the end of a valid stream: the SIMD unpackers load whole 128/256 bit words
+/// and the 256v decoders run up to 32 integers ahead (issue #59)
+pub(crate) const INPUT_PADDING: usize = 256;
+
+/// Bytes per integer above any `bound*` of the decoders without a header walk (`bit`, `vb`, `v8`, `vs`, `ef` and
+/// `fp`): none of these bounds can overflow for an `n` with `n * MAX_INPUT_PER_INT` not overflowing
+const MAX_INPUT_PER_INT: usize = 24;
+
+/// Copy the first `size` bytes of `input` into a buffer padded with `fill`
+fn padded(input: &[u8], size: usize, fill: u8) -> Vec<u8> {
+    let mut window = vec![fill; size];
+    let end = min(size, input.len());
+    window[..end].copy_from_slice(&input[..end]);
+    window
+}
+
+/// `input` if `INPUT_PADDING` bytes follow its first `size` bytes, otherwise a copy of them padded with `fill`,
+/// so that a C decoder reading at most `size` bytes plus its lookahead stays inside the buffer
+pub(crate) fn padded_input(input: &[u8], size: usize, fill: u8) -> Cow<'_, [u8]> {
+    if input.len().saturating_sub(INPUT_PADDING) >= size {
+        Cow::Borrowed(input)
+    } else {
+        Cow::Owned(padded(input, size + INPUT_PADDING, fill))
+    }
+}
+
+/// `bound(n)`, the most a decoder without header walk reads for `n` integers even from corrupt input,
+/// or `InputTruncated` if it overflows: no `input` of `len` bytes can hold that many integers
+pub(crate) fn checked_bound(n: usize, bound: fn(usize) -> usize, len: usize) -> Result<usize> {
+    n.checked_mul(MAX_INPUT_PER_INT)
+        .and_then(|_| bound(n).checked_add(INPUT_PADDING))
+        .map(|size| size - INPUT_PADDING)
+        .ok_or(TurboPforError::InputTruncated { needed: usize::MAX, got: len })
+}
+
+/// `input` padded for decoding `n` integers with a decoder without header walk sized by `bound`, borrowed if the
+/// slice has that much slack behind it, see `checked_bound`
+pub(crate) fn worst_case_input(input: &[u8], n: usize, bound: fn(usize) -> usize) -> Result<Cow<'_, [u8]>> {
+    Ok(padded_input(input, checked_bound(n, bound, input.len())?, 0))
+}
+
+/// Fail with `BlockTruncated` if the block of `size` bytes starting at `offset` doesn't fit into `len` bytes
+fn check_block(offset: usize, size: usize, len: usize) -> Result<()> {
+    if offset + size > len {
+        return Err(TurboPforError::BlockTruncated { offset, needed: size, got: len - offset });
+    }
+    Ok(())
+}
+
+fn invalid_block<T>(input: &[u8], pos: usize) -> Result<T> {
+    Err(TurboPforError::InvalidBlockHeader { offset: pos, header: input[pos] })
+}
+
+/// Size of the `xn` exceptions of `bits` bits written by `vbenc*` at `pos + start` of the block at `pos`: `0xff`
+/// followed by the exceptions uncompressed, or every exception with its length in the first byte, 1 byte below 177,
+/// 2 below 241, 3 below 249 and 4 to 9 bytes from 249 on (`_vbvlen*` of vint.h)
+fn vbyte_size(input: &[u8], pos: usize, start: usize, xn: usize, bits: usize) -> Result<usize> {
+    if xn == 0 {
+        return Ok(0);
+    }
+    check_block(pos, start + 1, input.len())?;
+    if input[pos + start] == 0xff {
+        return Ok(1 + xn * bits / 8);
+    }
+    let mut size = 0;
+    for _ in 0..xn {
+        check_block(pos, start + size + 1, input.len())?;
+        let len = match input[pos + start + size] {
+            0..=176 => 1,
+            177..=240 => 2,
+            241..=248 => 3,
+            first => first as usize - 245,
+        };
+        if len > bits / 8 + 1 {
+            return invalid_block(input, pos);
+        }
+        size += len;
+    }
+    Ok(size)
+}
+
+/// Size of the `n` integers of `bits` bits of the block at `pos` behind its header of `len` bytes, encoded with the
+/// bit widths `b` and `bx` (see `BlockBits`), i.e. the block the `_p4enc*` functions write without header
+pub(crate) fn payload_size(input: &[u8], pos: usize, len: usize, n: usize, bits: usize, b: usize, bx: usize) -> Result<usize> {
+    if n == 0 {
+        return Ok(0);
+    }
+    let packed = (n * b).div_ceil(8);
+    let size = match bx {
+        0 => packed,
+        bx if bx <= bits => {
+            // exception bitmap, exceptions and integers
+            let bitmap = n.div_ceil(8);
+            check_block(pos, len + bitmap, input.len())?;
+            let xn: usize = input[pos + len..pos + len + bitmap].iter().map(|x| x.count_ones() as usize).sum();
+            bitmap + (xn * bx).div_ceil(8) + packed
+        }
+        bx if bx == bits + 1 => {
+            // number of exceptions, integers, variable byte exceptions and their positions
+            check_block(pos, len + 1, input.len())?;
+            let xn = input[pos + len] as usize;
+            if xn > n {
+                return invalid_block(input, pos);
+            }
+            1 + packed + vbyte_size(input, pos, len + 1 + packed, xn, bits)? + xn
+        }
+        // constant
+        _ => b.div_ceil(8),
+    };
+    check_block(pos, len + size, input.len())?;
+    Ok(size)
+}
+
+/// Size of the block of `n` integers of `bits` bits starting at `pos`, derived from its header
+pub(crate) fn block_size(input: &[u8], pos: usize, n: usize, bits: usize) -> Result<usize> {
+    if n == 0 {
+        return Ok(0);
+    }
+    check_block(pos, 1, input.len())?;
+    let header = input[pos];
+    let mut b = (header & 0x3f) as usize;
+    if bits == 64 && b == 63 { b = 64; }  // the 64 bit encoders store b=64 as 63
+    if b > bits {
+        return invalid_block(input, pos);
+    }
+    let (len, bx) = match header & 0xc0 {
+        0x00 => (1, 0),
+        0x80 => {
+            check_block(pos, 2, input.len())?;
+            let bx = input[pos + 1] as usize;
+            if bx == 0 || bx > bits {
+                return invalid_block(input, pos);
+            }
+            (2, bx)
+        }
+        0x40 => (1, bits + 1),
+        _ => (1, bits + 2),
+    };
+    Ok(len + payload_size(input, pos, len, n, bits, b, bx)?)
+}
+
+/// Size of the variable byte encoded first value in front of the blocks of a stream of `n` integers, measured by
+/// decoding it with `dec` from a zero padded copy of the first `START_WINDOW` bytes
+pub(crate) fn start_size<T: Copy + Default>(input: &[u8], n: usize, dec: impl FnOnce(&[u8], &mut [T]) -> usize) -> Result<usize> {
+    if n == 0 {
+        return Ok(0);
+    }
+    let window = padded(input, START_WINDOW, 0);
+    let mut scratch = [T::default(); 2 * START_WINDOW + 32];
+    let size = dec(&window, &mut scratch);
+    check_block(0, size, input.len())?;
+    Ok(size)
+}
+
+/// Size of a `p4n*enc*` stream of `n` integers of `bits` bits in blocks of `block_len`, with the first value
+/// of `start` bytes in front of the blocks (0 if there is none)
+pub(crate) fn stream_size(input: &[u8], n: usize, block_len: usize, bits: usize, start: usize) -> Result<usize> {
+    let (mut pos, mut remaining) = (0, n);
+    if start > 0 && n > 0 {
+        (pos, remaining) = (start, n - 1);
+    }
+    while remaining > 0 {
+        let block_n = min(remaining, block_len);
+        pos += block_size(input, pos, block_n, bits)?;
+        remaining -= block_n;
+    }
+    Ok(pos)
+}
+
+/// Validate a stream of `n` integers encoded with `E`
+/// # Arguments
+/// * `input` - `&[u8]` containing the compressed input data
+/// * `n` - Number of encoded integers
+/// # Returns
+/// Number of bytes the stream occupies, or `BlockTruncated`/`InvalidBlockHeader` naming the offending block offset
+pub fn validate<E>(input: &[u8], n: usize) -> Result<usize>
+    where E: Validate, E::T: Codec<E::W> + Copy + Default
+{
+    let start = if E::HAS_START { start_size::<E::T>(input, n, E::decode_start)? } else { 0 };
+    stream_size(input, n, E::W::BLOCK_LEN, 8 * size_of::<E::T>(), start)
+}
+
+/// Validate a stream of `n` integers encoded with `E`, then decode it
+/// # Arguments
+/// * `input` - `&[u8]` containing the compressed input data
+/// * `n` - Length of decompressed data to be written to output
+/// * `output` - Output `&[T]` containing the decompressed data, at least `E::dec_buf_len(n)` long
+/// # Returns
+/// Number of input bytes used
+pub fn dec_checked<E>(input: &[u8], n: usize, output: &mut [E::T]) -> Result<usize>
+    where E: Validate, E::T: Codec<E::W> + Copy + Default
+{
+    validate::<E>(input, n)?;
+    E::try_decode(input, n, output)
+}
+
+#[cfg(test)]
+fn test_validate_generic<E>()
+where
+    E: Validate,
+    E::T: Codec<E::W> + Copy + Default + std::fmt::Debug + PartialEq,
+{
+    for len in [1, 2, 127, 128, 129, 255, 256, 257, 1000, 4097] {
+        let input: Vec<E::T> = E::sample(len);
+        let mut buf = vec![0u8; E::enc_buf_size(input.len())];
+        let size = E::encode(&input, &mut buf);
+        assert_eq!(validate::<E>(&buf[..size], input.len()), Ok(size));
+        assert!(matches!(validate::<E>(&buf[..size - 1], input.len()), Err(TurboPforError::BlockTruncated { .. })));
+        let mut output = vec![E::T::default(); E::dec_buf_len(input.len())];
+        assert_eq!(dec_checked::<E>(&buf[..size], input.len(), &mut output), Ok(size));
+        assert_eq!(input, output[..input.len()]);
+    }
+}
+
+#[test]
+fn test_validate_standard() {
+    test_validate_generic::<StandardEncoding<W, u8>>();
+    test_validate_generic::<StandardEncoding<W, u16>>();
+    test_validate_generic::<StandardEncoding<W, u32>>();
+    test_validate_generic::<StandardEncoding<W, u64>>();
+    test_validate_generic::<StandardEncoding<W128v, u16>>();
+    test_validate_generic::<StandardEncoding<W128v, u32>>();
+    test_validate_generic::<StandardEncoding<W256v, u32>>();
+}
+
+#[test]
+fn test_validate_delta() {
+    test_validate_generic::<IncreasingEncoding<W, u32>>();
+    test_validate_generic::<IncreasingEncoding<W128v, u16>>();
+    test_validate_generic::<IncreasingEncoding<W256v, u32>>();
+    test_validate_generic::<StrictlyIncreasingEncoding<W, u64>>();
+    test_validate_generic::<StrictlyIncreasingEncoding<W128v, u32>>();
+    test_validate_generic::<ZigZagEncoding<W, u16>>();
+    test_validate_generic::<ZigZagEncoding<W256v, u32>>();
+}
+
+#[test]
+fn test_validate_corrupt_header() {
+    type E = StandardEncoding<W, u32>;
+    let input: Vec<u32> = E::sample(300);
+    let mut buf = vec![0u8; E::enc_buf_size(input.len())];
+    let size = E::encode(&input, &mut buf);
+    buf[0] = 0x3f;  // b = 63 for u32
+    assert_eq!(validate::<E>(&buf[..size], input.len()), Err(TurboPforError::InvalidBlockHeader { offset: 0, header: 0x3f }));
+    let mut output = vec![0u32; E::dec_buf_len(input.len())];
+    assert!(dec_checked::<E>(&buf[..size], input.len(), &mut output).is_err());
+}
+
+#[test]
+fn test_block_size() {
+    // plain: 128 integers with 5 bits
+    let mut block = vec![0u8; 1 + 80];
+    block[0] = 5;
+    assert_eq!(block_size(&block, 0, 128, 32), Ok(1 + 80));
+    assert_eq!(block_size(&block[..1], 0, 128, 32), Err(TurboPforError::BlockTruncated { offset: 0, needed: 81, got: 1 }));
+    // bitmap: 10 integers with b=3, two exceptions with bx=4
+    let block = [0x80 | 3, 4, 0b0000_0101, 0b0000_0000, 0, 0, 0, 0, 0];
+    assert_eq!(block_size(&block, 0, 10, 16), Ok(2 + 2 + 1 + 4));
+    assert_eq!(block_size(&block[..3], 0, 10, 16), Err(TurboPforError::BlockTruncated { offset: 0, needed: 4, got: 3 }));
+    // variable bytes: 8 integers with b=3, the exceptions 5 in 1 byte and 200 in 2 bytes, then their 2 positions
+    let block = [0x40 | 3, 2, 0, 0, 0, 5, 200, 0, 1, 6];
+    assert_eq!(block_size(&block, 0, 8, 32), Ok(10));
+    assert_eq!(block_size(&block[..9], 0, 8, 32), Err(TurboPforError::BlockTruncated { offset: 0, needed: 10, got: 9 }));
+    // the exceptions uncompressed behind 0xff
+    let block = [0x40 | 3, 2, 0, 0, 0, 0xff, 1, 0, 0, 0, 2, 0, 0, 0, 1, 6];
+    assert_eq!(block_size(&block, 0, 8, 32), Ok(16));
+    // a 5 byte variable byte can't hold an u16, more exceptions than integers
+    assert_eq!(block_size(&[0x40 | 3, 1, 0, 0, 0, 250, 0, 0, 0, 0, 0], 0, 8, 16), Err(TurboPforError::InvalidBlockHeader { offset: 0, header: 0x43 }));
+    assert_eq!(block_size(&[0x40 | 3, 9, 0, 0, 0], 0, 8, 16), Err(TurboPforError::InvalidBlockHeader { offset: 0, header: 0x43 }));
+    // constant: the integer in b bits, b=63 meaning 64 for u64
+    assert_eq!(block_size(&[0xc0 | 7, 100], 0, 128, 32), Ok(2));
+    assert_eq!(block_size(&[0xc0 | 20, 0, 0, 8], 0, 128, 32), Ok(4));
+    assert_eq!(block_size(&[0xc0 | 63, 0, 0, 0, 0, 0, 0, 0, 0], 0, 128, 64), Ok(9));
+    // bit width too large for u8
+    assert_eq!(block_size(&[9], 0, 128, 8), Err(TurboPforError::InvalidBlockHeader { offset: 0, header: 9 }));
+    assert_eq!(block_size(&[], 0, 128, 8), Err(TurboPforError::BlockTruncated { offset: 0, needed: 1, got: 0 }));
+}
+
+/// Blocks with all four layouts: constant blocks and blocks with 0 to 64 exceptions of up to `max`
+#[cfg(test)]
+fn block_inputs(max: u64) -> Vec<Vec<u64>> {
+    let mut inputs = vec![];
+    for n in [1, 5, 100, 128, 256] {
+        inputs.push(vec![max / 3; n]);
+        for xn in [0, 1, 2, 5, 20, 64] {
+            for outlier in [1 << 7, 1 << 15, 1 << 23, 1 << 40, u64::MAX] {
+                let mut input: Vec<u64> = (0..n as u64).map(|i| i % 8).collect();
+                for i in (0..n).step_by(n.div_ceil(xn.max(1))).take(xn) {
+                    input[i] = outlier.min(max) ^ i as u64;
+                }
+                inputs.push(input);
+            }
+        }
+    }
+    inputs
+}
+
+/// Check `block_size` against the size of every block `enc_block` writes for `block_inputs`
+/// # Returns
+/// The layouts seen, indexed by the flag of the block header
+#[cfg(test)]
+fn encoded_layouts<T: Codec<W> + Copy + TryFrom<u64>>() -> [bool; 4] {
+    let bits = 8 * size_of::<T>();
+    let mut layouts = [false; 4];
+    for input in block_inputs(u64::MAX >> (64 - bits)) {
+        let input: Vec<T> = input.into_iter().map(|x| T::try_from(x).ok().unwrap()).collect();
+        let mut buf = vec![0u8; 2 * input.len() * size_of::<T>() + 64];
+        let size = T::enc_block(&input, &mut buf);
+        assert_eq!(block_size(&buf[..size], 0, input.len(), bits), Ok(size));
+        layouts[(buf[0] >> 6) as usize] = true;
+    }
+    layouts
+}
+
+#[test]
+fn test_block_size_encoder() {
+    encoded_layouts::<u8>();
+    assert_eq!(encoded_layouts::<u16>(), [true; 4]);
+    assert_eq!(encoded_layouts::<u32>(), [true; 4]);
+    assert_eq!(encoded_layouts::<u64>(), [true; 4]);
+}
@@ -0,0 +1,366 @@
+/// Random access and search in variable byte encoded lists without decoding them.
+///
+/// `VByteView` wraps a stream written by `vb::enc*`, `vb::denc*` or `vb::d1enc*` (`VByteEncoding`,
+/// `VByteDeltaEncoding`, `VByteDelta1Encoding`). `get(idx)` uses `vbgetx*`, `vbdgetx*` and `vbd1getx*`,
+/// which scan the stream from its start. `find_eq` and `seek_geq` move a forward-only cursor
+/// with `vbgeteq*`, `vbdgetgeq*` and `vbd1getgeq*`. This suits short lists, e.g. the entries of a term dictionary.
+///
+/// The C search functions take the stream as `unsigned char **in` and leave it pointing behind the integer found.
+/// The view keeps that position as a byte offset together with the index and value of the integer found,
+/// which is what the next search starts from. `new` measures the stream first, so the C functions never
+/// get to read beyond the integers, and keeps `validate::INPUT_PADDING` bytes behind them for the lookahead of
+/// the C functions: the slack of the slice if it has that much, a padded copy otherwise.
+use crate::codec::*;
+use crate::error::*;
+use crate::raw::vb as raw;
+use crate::validate::padded_input;
+use std::borrow::Cow;
+use std::cmp::min;
+use std::mem::size_of;
+
+/// Integer types with variable byte random access and search
+pub trait VByteSearch: VByteCodec + Copy + Default + PartialOrd {
+    /// Integer at index `idx` of a `vb::enc*` stream (`vbgetx*`)
+    /// # Safety
+    /// `input` must contain more than `idx` integers
+    unsafe fn getx(input: &[u8], idx: usize) -> Self;
+    /// Integer at index `idx` of a `vb::denc*` stream (`vbdgetx*`)
+    /// # Safety
+    /// `input` must contain more than `idx` integers
+    unsafe fn dgetx(input: &[u8], idx: usize, start: Self) -> Self;
+    /// Integer at index `idx` of a `vb::d1enc*` stream (`vbd1getx*`)
+    /// # Safety
+    /// `input` must contain more than `idx` integers
+    unsafe fn d1getx(input: &[u8], idx: usize, start: Self) -> Self;
+    /// First integer equal to `key` in a `vb::enc*` stream (`vbgeteq*`), see `raw::vb::geteq32`
+    /// # Safety
+    /// `input` must contain the integers `idx..n`
+    unsafe fn geteq(input: &[u8], n: usize, idx: usize, key: Self) -> (usize, usize);
+    /// First integer `>= key` in a `vb::denc*` stream (`vbdgetgeq*`), see `raw::vb::dgetgeq32`
+    /// # Safety
+    /// `input` must contain the integers `idx..n`
+    unsafe fn dgetgeq(input: &[u8], n: usize, idx: usize, key: Self, start: Self) -> (usize, Self, usize);
+    /// First integer `>= key` in a `vb::d1enc*` stream (`vbd1getgeq*`), see `raw::vb::d1getgeq32`
+    /// # Safety
+    /// `input` must contain the integers `idx..n`
+    unsafe fn d1getgeq(input: &[u8], n: usize, idx: usize, key: Self, start: Self) -> (usize, Self, usize);
+}
+
+impl VByteSearch for u16 {
+    unsafe fn getx(input: &[u8], idx: usize) -> u16 { raw::getx16(input, idx) }
+    unsafe fn dgetx(input: &[u8], idx: usize, start: u16) -> u16 { raw::dgetx16(input, idx, start) }
+    unsafe fn d1getx(input: &[u8], idx: usize, start: u16) -> u16 { raw::d1getx16(input, idx, start) }
+    unsafe fn geteq(input: &[u8], n: usize, idx: usize, key: u16) -> (usize, usize) { raw::geteq16(input, n, idx, key) }
+    unsafe fn dgetgeq(input: &[u8], n: usize, idx: usize, key: u16, start: u16) -> (usize, u16, usize) { raw::dgetgeq16(input, n, idx, key, start) }
+    unsafe fn d1getgeq(input: &[u8], n: usize, idx: usize, key: u16, start: u16) -> (usize, u16, usize) { raw::d1getgeq16(input, n, idx, key, start) }
+}
+
+impl VByteSearch for u32 {
+    unsafe fn getx(input: &[u8], idx: usize) -> u32 { raw::getx32(input, idx) }
+    unsafe fn dgetx(input: &[u8], idx: usize, start: u32) -> u32 { raw::dgetx32(input, idx, start) }
+    unsafe fn d1getx(input: &[u8], idx: usize, start: u32) -> u32 { raw::d1getx32(input, idx, start) }
+    unsafe fn geteq(input: &[u8], n: usize, idx: usize, key: u32) -> (usize, usize) { raw::geteq32(input, n, idx, key) }
+    unsafe fn dgetgeq(input: &[u8], n: usize, idx: usize, key: u32, start: u32) -> (usize, u32, usize) { raw::dgetgeq32(input, n, idx, key, start) }
+    unsafe fn d1getgeq(input: &[u8], n: usize, idx: usize, key: u32, start: u32) -> (usize, u32, usize) { raw::d1getgeq32(input, n, idx, key, start) }
+}
+
+impl VByteSearch for u64 {
+    unsafe fn getx(input: &[u8], idx: usize) -> u64 { raw::getx64(input, idx) }
+    unsafe fn dgetx(input: &[u8], idx: usize, start: u64) -> u64 { raw::dgetx64(input, idx, start) }
+    unsafe fn d1getx(input: &[u8], idx: usize, start: u64) -> u64 { raw::d1getx64(input, idx, start) }
+    unsafe fn geteq(input: &[u8], n: usize, idx: usize, key: u64) -> (usize, usize) { raw::geteq64(input, n, idx, key) }
+    unsafe fn dgetgeq(input: &[u8], n: usize, idx: usize, key: u64, start: u64) -> (usize, u64, usize) { raw::dgetgeq64(input, n, idx, key, start) }
+    unsafe fn d1getgeq(input: &[u8], n: usize, idx: usize, key: u64, start: u64) -> (usize, u64, usize) { raw::d1getgeq64(input, n, idx, key, start) }
+}
+
+/// Which encoder wrote the stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Plain,
+    Delta,
+    Delta1,
+}
+
+/// Number of integers measured at a time by `measure`
+const CHUNK_LEN: usize = 64;
+
+/// Number of bytes the `n` variable byte encoded integers at the start of `input` occupy.
+/// The byte lengths don't depend on the kind of the stream, so all are measured with `vb::dec*`,
+/// chunk by chunk from a zero padded copy.
+fn measure<T: VByteSearch>(input: &[u8], n: usize) -> Result<usize> {
+    let window_size = CHUNK_LEN * (size_of::<T>() + 2) + 16;
+    let mut scratch = [T::default(); CHUNK_LEN];
+    let mut window = vec![0u8; window_size];
+    let (mut pos, mut remaining) = (0, n);
+    while remaining > 0 {
+        let chunk_n = min(remaining, CHUNK_LEN);
+        let end = min(pos + window_size, input.len());
+        window.fill(0);
+        window[..end - pos].copy_from_slice(&input[pos..end]);
+        pos += T::dec(&window, chunk_n, &mut scratch);
+        check_consumed(pos, input.len())?;
+        remaining -= chunk_n;
+    }
+    Ok(pos)
+}
+
+/// Read-only view of a variable byte encoded list with random access and a forward-only search cursor
+pub struct VByteView<'a, T: VByteSearch> {
+    // the `size` bytes of the integers followed by the padding
+    input: Cow<'a, [u8]>,
+    size: usize,
+    n: usize,
+    kind: Kind,
+    start: T,
+    // the cursor: the next integer has index `idx` and starts at byte `pos`, `prev` is the integer before it
+    pos: usize,
+    idx: usize,
+    prev: T,
+    current: Option<T>,
+}
+
+impl<'a, T: VByteSearch> VByteView<'a, T> {
+    fn with_kind(input: &'a [u8], n: usize, kind: Kind, start: T) -> Result<Self> {
+        check_unsigned(n)?;
+        let size = measure::<T>(input, n)?;
+        let input = padded_input(input, size, 0);
+        Ok(VByteView { input, size, n, kind, start, pos: 0, idx: 0, prev: start, current: None })
+    }
+
+    /// View of the `n` integers written by `vb::enc*` (`VByteEncoding`) into `input`
+    /// # Returns
+    /// The view, or `InputTruncated` if the integers extend beyond `input`
+    pub fn new(input: &'a [u8], n: usize) -> Result<Self> {
+        Self::with_kind(input, n, Kind::Plain, T::default())
+    }
+
+    /// View of the `n` integers written by `vb::denc*` with `start` into `input`; `VByteDeltaEncoding` uses `start` 0
+    /// # Returns
+    /// The view, or `InputTruncated` if the integers extend beyond `input`
+    pub fn delta(input: &'a [u8], n: usize, start: T) -> Result<Self> {
+        Self::with_kind(input, n, Kind::Delta, start)
+    }
+
+    /// View of the `n` integers written by `vb::d1enc*` with `start` into `input`; `VByteDelta1Encoding` uses `start` 0
+    /// # Returns
+    /// The view, or `InputTruncated` if the integers extend beyond `input`
+    pub fn delta1(input: &'a [u8], n: usize, start: T) -> Result<Self> {
+        Self::with_kind(input, n, Kind::Delta1, start)
+    }
+
+    /// Number of integers
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// `true` if the view holds no integers
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Number of bytes the encoded integers occupy
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Integer at index `idx`, or `None` if `idx` is out of bounds. Doesn't move the cursor.
+    pub fn get(&self, idx: usize) -> Option<T> {
+        if idx >= self.n {
+            return None;
+        }
+        // `new` checked that the n integers lie within input
+        Some(unsafe {
+            match self.kind {
+                Kind::Plain => T::getx(&self.input, idx),
+                Kind::Delta => T::dgetx(&self.input, idx, self.start),
+                Kind::Delta1 => T::d1getx(&self.input, idx, self.start),
+            }
+        })
+    }
+
+    /// The integer the cursor points to, `None` before the first search and after a failed one
+    pub fn current(&self) -> Option<T> {
+        self.current
+    }
+
+    /// Index of the integer the cursor points to, `len()` after a failed search
+    pub fn position(&self) -> usize {
+        if self.current.is_some() { self.idx - 1 } else { self.idx }
+    }
+
+    /// Move the cursor back before the first integer
+    pub fn reset(&mut self) {
+        (self.pos, self.idx, self.prev, self.current) = (0, 0, self.start, None);
+    }
+
+    /// Move the cursor behind the integer with index `idx` and value `value`, read up to byte `pos`
+    fn found(&mut self, idx: usize, value: T, pos: usize) -> Option<(usize, T)> {
+        assert!(pos <= self.size, "vbview: search read {} bytes of {}", pos, self.size);
+        (self.pos, self.idx, self.prev, self.current) = (pos, idx + 1, value, Some(value));
+        Some((idx, value))
+    }
+
+    /// Move the cursor to the end of the list
+    fn not_found(&mut self) -> Option<(usize, T)> {
+        (self.pos, self.idx, self.current) = (self.size, self.n, None);
+        None
+    }
+
+    /// Move to the first integer equal to `key` at or after the cursor.
+    /// The views of sorted lists stop at the first integer `>= key` like `seek_geq`.
+    /// # Returns
+    /// The index of the integer found, or `None` if there is none
+    pub fn find_eq(&mut self, key: T) -> Option<usize> {
+        if self.kind != Kind::Plain {
+            return self.seek_geq(key).filter(|&(_, x)| x == key).map(|(idx, _)| idx);
+        }
+        if self.current == Some(key) {
+            return Some(self.idx - 1);
+        }
+        let (idx, size) = if self.idx < self.n {
+            unsafe { T::geteq(&self.input[self.pos..], self.n, self.idx, key) }
+        } else {
+            (self.n, 0)
+        };
+        if idx >= self.n {
+            self.not_found();
+            return None;
+        }
+        self.found(idx, key, self.pos + size).map(|(idx, _)| idx)
+    }
+
+    /// Move to the first integer `>= key` at or after the cursor.
+    /// The views of unsorted lists step through the integers one by one with `vb::dec*`.
+    /// # Returns
+    /// The index and value of the integer found, or `None` if there is none
+    pub fn seek_geq(&mut self, key: T) -> Option<(usize, T)> {
+        if let Some(x) = self.current.filter(|&x| x >= key) {
+            return Some((self.idx - 1, x));
+        }
+        if self.idx >= self.n {
+            return self.not_found();
+        }
+        let input = &self.input[self.pos..];
+        let (idx, value, size) = match self.kind {
+            Kind::Delta => unsafe { T::dgetgeq(input, self.n, self.idx, key, self.prev) },
+            Kind::Delta1 => unsafe { T::d1getgeq(input, self.n, self.idx, key, self.prev) },
+            Kind::Plain => {
+                let mut x = [T::default()];
+                let mut size = 0;
+                let mut idx = self.idx;
+                while idx < self.n {
+                    size += T::dec(&input[size..], 1, &mut x);
+                    if x[0] >= key {
+                        break;
+                    }
+                    idx += 1;
+                }
+                (idx, x[0], size)
+            }
+        };
+        if idx >= self.n {
+            return self.not_found();
+        }
+        self.found(idx, value, self.pos + size)
+    }
+}
+
+#[cfg(test)]
+fn test_vbview_generic<T>(input: &[T])
+    where T: VByteSearch + std::fmt::Debug + Ord
+{
+    let mut buf = vec![0u8; T::max_compressed_size(input.len())];
+    let size = T::enc(input, &mut buf);
+    // ending at the end of its allocation, the view reads from a padded copy
+    let exact = buf[..size].to_vec().into_boxed_slice();
+    let mut view = VByteView::<T>::new(&exact, input.len()).unwrap();
+    assert_eq!(view.size(), size);
+    for (idx, &x) in input.iter().enumerate() {
+        assert_eq!(view.get(idx), Some(x));
+    }
+    assert_eq!(view.get(input.len()), None);
+    for &x in input {
+        view.reset();
+        assert_eq!(view.find_eq(x), input.iter().position(|&y| y == x));
+        assert_eq!(view.current(), Some(x));
+    }
+    let max = *input.iter().max().unwrap();
+    view.reset();
+    assert_eq!(view.seek_geq(max), input.iter().position(|&x| x == max).map(|idx| (idx, max)));
+
+    let mut sorted = input.to_vec();
+    sorted.sort();
+    let size = T::denc(&sorted, &mut buf, T::default());
+    let exact = buf[..size].to_vec().into_boxed_slice();
+    let mut view = VByteView::<T>::delta(&exact, sorted.len(), T::default()).unwrap();
+    for (idx, &x) in sorted.iter().enumerate() {
+        assert_eq!(view.get(idx), Some(x));
+    }
+    for (idx, &x) in sorted.iter().enumerate() {
+        if idx == 0 || sorted[idx - 1] < x {
+            assert_eq!(view.seek_geq(x), Some((idx, x)));
+            assert_eq!(view.position(), idx);
+        }
+    }
+    assert_eq!(view.seek_geq(max), Some((sorted.len() - 1, max)));
+
+    sorted.dedup();
+    if sorted.len() < 2 {
+        return;
+    }
+    let size = T::d1enc(&sorted[1..], &mut buf, sorted[0]);
+    let exact = buf[..size].to_vec().into_boxed_slice();
+    let mut view = VByteView::<T>::delta1(&exact, sorted.len() - 1, sorted[0]).unwrap();
+    for (idx, &x) in sorted[1..].iter().enumerate() {
+        assert_eq!(view.get(idx), Some(x));
+        assert_eq!(view.find_eq(x), Some(idx));
+    }
+    assert_eq!(view.position(), sorted.len() - 2);
+}
+
+#[test]
+fn test_vbview() {
+    use rand::Rng;
+    let mut rng = rand::thread_rng();
+    for len in [2, 10, 100, 1000] {
+        let input: Vec<u32> = (0..len).map(|_| rng.gen::<u32>() >> rng.gen_range(0..32)).collect();
+        test_vbview_generic(&input);
+        let input: Vec<u16> = input.iter().map(|&x| x as u16).collect();
+        test_vbview_generic(&input);
+        let input: Vec<u64> = input.iter().map(|&x| (x as u64) << 40 | x as u64).collect();
+        test_vbview_generic(&input);
+    }
+}
+
+#[test]
+fn test_vbview_search() {
+    let input: Vec<u32> = (0..1000).map(|i| 5 * i + 3).collect();
+    let mut buf = vec![0u8; <u32 as VByteCodec>::max_compressed_size(input.len())];
+    let size = <u32 as VByteCodec>::denc(&input, &mut buf, 0);
+    let mut view = VByteView::<u32>::delta(&buf[..size], input.len(), 0).unwrap();
+    assert_eq!(view.current(), None);
+    assert_eq!(view.seek_geq(0), Some((0, 3)));
+    assert_eq!(view.seek_geq(3), Some((0, 3)));
+    assert_eq!(view.seek_geq(4), Some((1, 8)));
+    assert_eq!(view.find_eq(503), Some(100));
+    // the cursor only moves forward
+    assert_eq!(view.seek_geq(10), Some((100, 503)));
+    assert_eq!(view.find_eq(504), None);
+    assert_eq!(view.current(), Some(508));
+    assert_eq!(view.seek_geq(5000), None);
+    assert_eq!(view.position(), 1000);
+    assert_eq!(view.seek_geq(0), None);
+    view.reset();
+    assert_eq!(view.seek_geq(4998), Some((999, 4998)));
+
+    let input: Vec<u32> = vec![7, 1, 7, 300, 2, 70_000];
+    let size = <u32 as VByteCodec>::enc(&input, &mut buf);
+    let mut view = VByteView::<u32>::new(&buf[..size], input.len()).unwrap();
+    assert_eq!(view.find_eq(7), Some(0));
+    assert_eq!(view.find_eq(7), Some(0));
+    assert_eq!(view.seek_geq(8), Some((3, 300)));
+    assert_eq!(view.find_eq(7), None);
+    view.reset();
+    assert_eq!(view.seek_geq(1000), Some((5, 70_000)));
+    assert!(matches!(VByteView::<u32>::new(&buf[..size - 1], input.len()), Err(TurboPforError::InputTruncated { .. })));
+}